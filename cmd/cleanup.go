@@ -9,9 +9,9 @@ import (
 
 	"github.com/pterm/pterm"
 	"github.com/spf13/cobra"
-	"github.com/tedkulp/tix/internal/config"
 	"github.com/tedkulp/tix/internal/git"
 	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/utils"
 )
 
 var cleanupForce bool
@@ -26,9 +26,9 @@ the worktree directly. If a branch name is given as an argument,
 that branch's worktree is removed. Otherwise, the branch is auto-detected
 from the current working directory.`,
 	RunE: func(cmd *cobra.Command, args []string) error {
-		cfg, err := config.Load()
+		cfg, err := utils.LoadConfig()
 		if err != nil {
-			return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
+			return configLoadError(err)
 		}
 
 		wd, err := os.Getwd()
@@ -36,42 +36,21 @@ from the current working directory.`,
 			return fmt.Errorf("failed to get current directory: %w", err)
 		}
 
-		// Find the code repo whose worktree path contains cwd
-		var codeRepo *config.Repository
+		// Find the code repo the current directory belongs to, and the
+		// branch when it's one of the repo's worktrees
+		codeRepo, _ := utils.MatchRepository(cfg, wd)
 		var detectedBranch string
 		var worktreeBase string
 
-		for i := range cfg.Repositories {
-			repo := &cfg.Repositories[i]
-			if !repo.IsCodeRepo() {
-				continue
-			}
-			base := cfg.ResolveWorktreePath(repo)
-			branch := detectWorktreeBranch(wd, base)
-			if branch != "" {
-				codeRepo = repo
-				detectedBranch = branch
-				worktreeBase = base
-				break
-			}
-		}
-
-		// If we couldn't detect from cwd, try to match by repo directory
-		if codeRepo == nil {
-			for i := range cfg.Repositories {
-				repo := &cfg.Repositories[i]
-				if !repo.IsCodeRepo() {
-					continue
-				}
-				repoDir := repo.Directory
-				if !strings.HasSuffix(repoDir, string(filepath.Separator)) {
-					repoDir += string(filepath.Separator)
-				}
-				if wd == repo.Directory || strings.HasPrefix(wd, repoDir) {
-					codeRepo = repo
-					worktreeBase = cfg.ResolveWorktreePath(repo)
-					break
+		if codeRepo != nil {
+			worktreeBase = cfg.ResolveWorktreePath(codeRepo)
+			if branch := detectWorktreeBranch(wd, worktreeBase); branch != "" {
+				// The directory name only matches the branch with the default
+				// path template; ask git for the branch actually checked out.
+				if current, err := git.GetBranchFromDir(wd); err == nil && current != "" {
+					branch = current
 				}
+				detectedBranch = branch
 			}
 		}
 
@@ -141,18 +120,23 @@ from the current working directory.`,
 			}
 		}
 
-		worktreeDir := filepath.Join(worktreeBase, branchName)
+		gitRepo, err := git.Open(codeRepo.Directory)
+		if err != nil {
+			return fmt.Errorf("failed to open git repository: %w", err)
+		}
+
+		// Look up where the branch is checked out; fall back to the directory
+		// name under the worktree base (e.g. when picked from the list)
+		worktreeDir, err := gitRepo.WorktreePathForBranch(branchName)
+		if err != nil || worktreeDir == "" {
+			worktreeDir = filepath.Join(worktreeBase, branchName)
+		}
 
 		logger.Info("Removing worktree", map[string]interface{}{
 			"branch":    branchName,
 			"directory": worktreeDir,
 		})
 
-		gitRepo, err := git.Open(codeRepo.Directory)
-		if err != nil {
-			return fmt.Errorf("failed to open git repository: %w", err)
-		}
-
 		if err := gitRepo.RemoveWorktree(worktreeDir); err != nil {
 			fmt.Printf("Error: %v\n", err)
 			forceIt, promptErr := pterm.DefaultInteractiveConfirm.
@@ -0,0 +1,115 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/pterm/pterm"
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/utils"
+	"gopkg.in/yaml.v3"
+)
+
+// configCmd groups commands for inspecting the tix configuration
+var configCmd = &cobra.Command{
+	Use:   "config",
+	Short: "Inspect and edit the tix configuration",
+	Long: `Inspect or edit the configuration tix reads.
+
+The config file is chosen in this order: --config, the TIX_CONFIG
+environment variable, then ~/.tix.yml.`,
+}
+
+var configPathCmd = &cobra.Command{
+	Use:   "path",
+	Short: "Print the path of the config file",
+	RunE: func(cmd *cobra.Command, args []string) error {
+		path, err := config.ConfigPath()
+		if err != nil {
+			return err
+		}
+
+		fmt.Println(path)
+		if _, err := os.Stat(path); os.IsNotExist(err) {
+			printWarning("this file does not exist")
+		}
+		return nil
+	},
+}
+
+var configShowCmd = &cobra.Command{
+	Use:   "show",
+	Short: "Print the parsed configuration with tokens redacted",
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting config show command")
+
+		// LoadConfig also resolves token files, so unreadable ones are reported here
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		out, err := yaml.Marshal(cfg.Redacted())
+		if err != nil {
+			return fmt.Errorf("failed to format config: %w", err)
+		}
+
+		fmt.Print(string(out))
+		return nil
+	},
+}
+
+var configEditCmd = &cobra.Command{
+	Use:   "edit",
+	Short: "Open the config file in $VISUAL or $EDITOR and validate it",
+	Long: `Open the config file in $VISUAL, $EDITOR or vi. A missing config file is
+created first from a minimal example. Once the editor exits the config is
+loaded again, and if it has problems you're offered to fix them straight away.`,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		path, err := config.ConfigPath()
+		if err != nil {
+			return err
+		}
+
+		if _, err := os.Stat(path); os.IsNotExist(err) {
+			if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+				return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+			}
+			if err := os.WriteFile(path, []byte(exampleConfig+"\n"), 0600); err != nil {
+				return fmt.Errorf("failed to create config file: %w", err)
+			}
+			printSuccess("Created %s from an example config", path)
+		}
+
+		for {
+			if err := utils.EditFile(path); err != nil {
+				return err
+			}
+
+			_, err := utils.LoadConfig()
+			if err == nil {
+				printSuccess("%s is valid", path)
+				return nil
+			}
+			printWarning("%s has problems: %v", path, err)
+
+			again, promptErr := pterm.DefaultInteractiveConfirm.
+				WithDefaultText("Edit the config again?").
+				WithDefaultValue(true).
+				Show()
+			if promptErr != nil || !again {
+				return fmt.Errorf("config file %s is invalid: %w", path, err)
+			}
+		}
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(configCmd)
+	configCmd.AddCommand(configPathCmd)
+	configCmd.AddCommand(configShowCmd)
+	configCmd.AddCommand(configEditCmd)
+}
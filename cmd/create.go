@@ -1,9 +1,15 @@
 package cmd
 
 import (
+	"errors"
 	"fmt"
+	"io"
+	"math/rand"
+	"net"
+	"net/mail"
 	"os"
 	"path/filepath"
+	"strconv"
 	"strings"
 	"time"
 
@@ -13,6 +19,7 @@ import (
 	"github.com/tedkulp/tix/internal/git"
 	"github.com/tedkulp/tix/internal/logger"
 	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/state"
 	"github.com/tedkulp/tix/internal/utils"
 )
 
@@ -20,20 +27,100 @@ var (
 	title          string
 	selfAssign     bool
 	useWorktree    bool
+	reuseBranch    bool
 	noAutoStash    bool
 	nonInteractive bool
+	branchType     string
+	noCheckout     bool
+	offline        bool
+	confidential   bool
+	issueTemplate  string
+	copyURL        bool
+	titleFromClip  bool
+	baseBranch     string
+	baseRemote     string
+	baseTag        string
+	adHocGithub    string
+	adHocGitlab    string
+	adHocDir       string
+	issueBody      string
+	quickActions   []string
+	assignees      []string
+	projectBoard   string
+	trackUpstream  bool
+	checkRemote    bool
+	checkProtect   bool
+	createLabels   bool
+	estimate       int
+	printBranch    bool
+	fromFile       string
+	iKnow          bool
+	groupMilestone string
+	labelFlags     []string
+	noLabels       []string
+	labelsAppend   bool
+	labelsReplace  bool
+	templateVars   []string
+	noBranch       bool
+	suggestLabels  bool
+	attachments    []string
+	forceWorktree  bool
+	labelsFrom     int
+	branchOnly     bool
+	branchOnlyName string
+	batchFile      string
+	closeIssue     bool
+	lockIssue      bool
+	worktreeDirArg string
+	fromCurrent    bool
+	dryRun         bool
+	participants   []string
+	presets        []string
+	rotation       string
+)
+
+// branchOnlyConflicts are the issue flags --branch-only can't be combined with
+var branchOnlyConflicts = []string{
+	"no-branch", "offline", "check-remote", "title", "body", "from-file", "template", "template-var", "closed", "lock",
+	"label", "labels-append", "labels-replace", "labels-from-issue", "interactive-labels", "assignee", "assign",
+	"quick-action", "attach", "project", "group-milestone", "estimate", "confidential", "type", "copy-url", "i-know",
+	"dry-run", "external-participant", "preset", "rotation", "title-from-clipboard",
+}
+
+// batchConflicts are the single-issue flags --batch can't be combined with
+var batchConflicts = []string{
+	"title", "body", "from-file", "template", "template-var", "labels-from-issue", "estimate", "attach",
+	"offline", "check-remote", "no-branch", "branch-only", "print-branch", "copy-url", "reuse", "worktree-dir",
+	"dry-run", "title-from-clipboard",
+}
+
+// Environment variables that stand in for a missing title, labels prompt or
+// issue repository argument, for CI jobs
+const (
+	titleEnvVar  = "TIX_TITLE"
+	labelsEnvVar = "TIX_LABELS"
+	repoEnvVar   = "TIX_REPO"
 )
 
 // RepoSettings represents repository settings and configuration
 type RepoSettings struct {
-	Repo         *config.Repository
-	Name         string
-	Directory    string
-	Labels       string
-	Milestone    string
-	Provider     services.SCMProvider
-	CodeRepo     *config.Repository // For cross-repo: code repo where branch is created
-	CodeRepoName string             // Name of code repo
+	Repo           *config.Repository
+	Name           string
+	Directory      string
+	Labels         string
+	PresetLabels   []string // Labels of the --preset presets
+	Milestone      string
+	GroupMilestone bool // Milestone is a GitLab group milestone (--group-milestone)
+	Body           string
+	QuickActions   []string
+	Confidential   bool
+	Weight         *int     // GitLab issue weight from --estimate
+	Attachments    []string // Absolute paths of --attach files
+	BaseBranch     string   // Resolved base for the new branch; "" branches from HEAD
+	TrackUpstream  bool     // Set the new branch's upstream to the remote base branch
+	Provider       services.SCMProvider
+	CodeRepo       *config.Repository // For cross-repo: code repo where branch is created
+	CodeRepoName   string             // Name of code repo
 }
 
 var createCmd = &cobra.Command{
@@ -47,8 +134,157 @@ Usage:
   tix create issues                # Create issue in issues repo, branch in current/matching repo
   tix create issues code           # Create issue in issues repo, branch in code`,
 	RunE: func(cmd *cobra.Command, args []string) error {
-		if nonInteractive && title == "" {
-			return fmt.Errorf("--non-interactive requires -t/--title")
+		if branchOnly {
+			if branchOnlyName == "" {
+				return fmt.Errorf("--branch-only requires --name")
+			}
+			for _, name := range branchOnlyConflicts {
+				if cmd.Flags().Changed(name) {
+					return fmt.Errorf("--branch-only cannot be used with --%s", name)
+				}
+			}
+			if err := git.ValidateBranchName(branchOnlyName); err != nil {
+				return err
+			}
+		} else if branchOnlyName != "" {
+			return fmt.Errorf("--name requires --branch-only")
+		}
+
+		// A batch file is read up front so a typo doesn't fail halfway
+		var batchIssues []utils.BatchIssue
+		if batchFile != "" {
+			for _, name := range batchConflicts {
+				if cmd.Flags().Changed(name) {
+					return fmt.Errorf("--batch cannot be used with --%s", name)
+				}
+			}
+			var err error
+			batchIssues, err = utils.LoadBatchFile(batchFile)
+			if err != nil {
+				return err
+			}
+		}
+
+		// --title-from-clipboard files a line copied from a log or chat
+		if titleFromClip {
+			if cmd.Flags().Changed("title") {
+				return fmt.Errorf("--title-from-clipboard cannot be used with --title")
+			}
+			var err error
+			if title, err = clipboardTitle(); err != nil {
+				return err
+			}
+		}
+
+		// A drafted issue file fills in whatever the flags don't set
+		var fileLabels []string
+		if fromFile != "" {
+			issueFile, err := utils.LoadIssueFile(fromFile)
+			if err != nil {
+				return err
+			}
+			if title == "" {
+				title = issueFile.Title
+			}
+			if issueBody == "" {
+				issueBody = issueFile.Body
+			}
+			assignees = append(assignees, issueFile.Assignees...)
+			fileLabels = issueFile.Labels
+		}
+
+		// CI jobs can pass the title and labels through the environment;
+		// flags (and --from-file) take precedence
+		if title == "" {
+			title = os.Getenv(titleEnvVar)
+		}
+		envLabels := utils.SplitOnCommaAndWhitespace(os.Getenv(labelsEnvVar))
+
+		if labelsAppend && labelsReplace {
+			return fmt.Errorf("--labels-append and --labels-replace cannot be used together")
+		}
+
+		if nonInteractive && title == "" && !branchOnly && batchFile == "" {
+			return fmt.Errorf("--non-interactive requires -t/--title (or %s)", titleEnvVar)
+		}
+
+		// --print-branch is for scripts, so every input must come from flags
+		if printBranch {
+			if title == "" && !branchOnly {
+				return fmt.Errorf("--print-branch requires -t/--title (or %s)", titleEnvVar)
+			}
+			nonInteractive = true
+		}
+
+		// Explicit assignees replace the default self-assignment unless --assign is also given
+		if len(assignees) > 0 && !cmd.Flags().Changed("assign") {
+			selfAssign = false
+		}
+
+		if noCheckout && useWorktree {
+			return fmt.Errorf("--no-checkout cannot be used with --worktree")
+		}
+		if checkRemote && offline {
+			return fmt.Errorf("--check-remote cannot be used with --offline")
+		}
+		if dryRun && (offline || checkRemote) {
+			return fmt.Errorf("--dry-run cannot be used with --offline or --check-remote")
+		}
+		if err := checkHeadBase(cmd, fromCurrent, baseBranch, baseRemote); err != nil {
+			return err
+		}
+		if err := checkBaseTag(cmd, baseTag, fromCurrent, baseBranch, baseRemote); err != nil {
+			return err
+		}
+		if cmd.Flags().Changed("labels-from-issue") {
+			if labelsFrom <= 0 {
+				return fmt.Errorf("--labels-from-issue must be an issue number")
+			}
+			if offline {
+				return fmt.Errorf("--labels-from-issue cannot be used with --offline")
+			}
+		}
+		if noBranch {
+			for _, name := range []string{"worktree", "no-checkout", "offline", "base", "base-remote", "base-tag", "from-current", "track", "print-branch"} {
+				if cmd.Flags().Changed(name) {
+					return fmt.Errorf("--no-branch cannot be used with --%s", name)
+				}
+			}
+		}
+
+		if cmd.Flags().Changed("estimate") && estimate < 0 {
+			return fmt.Errorf("--estimate must be 0 or more")
+		}
+
+		templateValues, err := utils.ParseTemplateVars(templateVars)
+		if err != nil {
+			return err
+		}
+
+		if err := validateParticipants(participants); err != nil {
+			return err
+		}
+
+		// Check attachments before anything is created
+		attachmentPaths, err := utils.ValidateAttachments(attachments)
+		if err != nil {
+			return err
+		}
+
+		// With --print-branch, stdout carries only the branch name
+		var branchOut *os.File
+		if printBranch {
+			var restore func()
+			branchOut, restore = stdoutForScripting()
+			defer restore()
+		}
+
+		if branchType != "" {
+			sanitized := utils.TruncateAndDashCase(branchType, 50)
+			if sanitized == "" {
+				return fmt.Errorf("invalid --type '%s' - use a name like 'feature' or 'bug'", branchType)
+			}
+			branchType = sanitized
 		}
 
 		logger.Debug("Starting create command")
@@ -57,6 +293,8 @@ Usage:
 		var issueRepoArg, codeRepoArg string
 		if len(args) >= 1 {
 			issueRepoArg = args[0]
+		} else {
+			issueRepoArg = os.Getenv(repoEnvVar)
 		}
 		if len(args) >= 2 {
 			codeRepoArg = args[1]
@@ -65,12 +303,28 @@ Usage:
 			return fmt.Errorf("too many arguments. Usage: tix create [issue-repo] [code-repo]")
 		}
 
-		cfg, err := config.Load()
+		// --github-repo/--gitlab-repo describe a repository that isn't in
+		// the config file, which then isn't needed at all
+		adHoc := adHocGithub != "" || adHocGitlab != "" || adHocDir != ""
+		var cfg *config.Settings
+		if adHoc {
+			cfg, err = utils.LoadOptionalConfig()
+		} else {
+			cfg, err = utils.LoadConfig()
+		}
 		if err != nil {
-			if strings.Contains(err.Error(), "failed to load config") || strings.Contains(err.Error(), "failed to read config") {
-				return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
+			return configLoadError(err)
+		}
+		if adHoc {
+			if len(args) > 0 {
+				return fmt.Errorf("--github-repo, --gitlab-repo and --dir cannot be used with repository arguments")
+			}
+			if offline {
+				return fmt.Errorf("--offline needs a configured repository, since 'tix sync' files the queued issue later")
+			}
+			if issueRepoArg, err = addAdHocRepository(cfg, adHocGithub, adHocGitlab, adHocDir); err != nil {
+				return err
 			}
-			return err
 		}
 
 		// Setup repository and configuration
@@ -79,13 +333,44 @@ Usage:
 			return err
 		}
 
-		// Open Git repository BEFORE any user interaction
-		gitRepo, err := git.Open(repoSettings.Directory)
-		if err != nil {
-			return fmt.Errorf("couldn't open git repository at %s", repoSettings.Directory)
+		// worktree.enabled stands in for --worktree, except where a worktree
+		// makes no sense (--no-checkout, --no-branch)
+		if !cmd.Flags().Changed("worktree") && !noCheckout && !noBranch {
+			useWorktree = cfg.ResolveWorktreeEnabled(repoSettings.CodeRepo)
+		}
+		if reuseBranch && !useWorktree {
+			return fmt.Errorf("--reuse requires --worktree")
+		}
+		if worktreeDirArg != "" {
+			if !useWorktree {
+				return fmt.Errorf("--worktree-dir requires --worktree")
+			}
+			if worktreeDirArg, err = validateWorktreeDir(worktreeDirArg, repoSettings.Directory); err != nil {
+				return err
+			}
+		}
+		// A batch creates several branches, so it never checks one out
+		if batchFile != "" && !useWorktree {
+			noCheckout = true
+		}
+
+		// Open Git repository BEFORE any user interaction or API call, so a
+		// misconfigured directory doesn't leave behind an issue with no branch.
+		// --no-branch never touches git.
+		var gitRepo git.Backend
+		if !noBranch {
+			gitRepo, err = openCodeRepo(repoSettings.CodeRepoName, repoSettings.Directory)
+			if err != nil {
+				return err
+			}
+			if _, err := cfg.ResolveGitlabBranchID(repoSettings.Repo); err != nil {
+				return err
+			}
 		}
 
-		if !useWorktree {
+		// The working tree is only touched when checking out in place, and
+		// never by a dry run
+		if !useWorktree && !noCheckout && !checkRemote && !noBranch && !dryRun {
 			isClean, err := gitRepo.IsClean()
 			if err != nil {
 				return fmt.Errorf("failed to check repository status: %w", err)
@@ -100,13 +385,91 @@ Usage:
 				fmt.Println("Stashed changes, will restore after branch creation.")
 				defer func() {
 					if popErr := gitRepo.StashPop(); popErr != nil {
-						fmt.Fprintf(os.Stderr, "Warning: failed to restore stashed changes: %v\n", popErr)
+						printWarning("failed to restore stashed changes: %v", popErr)
 						fmt.Fprintf(os.Stderr, "Your changes are still in the stash — run `git stash pop` manually.\n")
 					}
 				}()
 			}
 		}
 
+		repoSettings.TrackUpstream = cfg.ResolveTrackUpstream(repoSettings.CodeRepo)
+		if cmd.Flags().Changed("track") {
+			repoSettings.TrackUpstream = trackUpstream
+		}
+
+		// Worktrees and tracked branches always need a base; other in-place
+		// branches only when --base or --base-remote is given
+		if !noBranch && baseTag != "" {
+			// A tag has no remote branch to track
+			repoSettings.TrackUpstream = false
+			repoSettings.BaseBranch, err = resolveBaseTag(gitRepo, baseTag)
+			if err != nil {
+				return err
+			}
+		} else if !noBranch && (fromCurrent || baseBranch == headBase) {
+			// A commit has no remote branch to track
+			repoSettings.TrackUpstream = false
+			repoSettings.BaseBranch, err = resolveHeadBase(gitRepo)
+			if err != nil {
+				return err
+			}
+		} else if !noBranch && baseRemote != "" {
+			repoSettings.BaseBranch, err = resolveRemoteBaseBranch(gitRepo, cfg, repoSettings.CodeRepo, baseBranch, baseRemote)
+			if err != nil {
+				return err
+			}
+		} else if !noBranch && (useWorktree || baseBranch != "" || repoSettings.TrackUpstream) {
+			repoSettings.BaseBranch, err = resolveBaseBranch(gitRepo, cfg, repoSettings.CodeRepo, baseBranch, nonInteractive)
+			if err != nil {
+				return err
+			}
+			if cfg.ResolveFFBaseBeforeCreate(repoSettings.CodeRepo) && !checkRemote && !dryRun {
+				fastForwardBase(gitRepo, repoSettings.BaseBranch)
+			}
+		}
+
+		// --branch-only is done once the branch exists; there's no issue
+		if branchOnly {
+			branchName, err := createBranch(gitRepo, repoSettings.CodeRepo, cfg, branchOnlyName, branchOnlyName, branchOptions{
+				Name:        branchOnlyName,
+				BaseBranch:  repoSettings.BaseBranch,
+				BaseRemote:  baseRemote,
+				UseWorktree: useWorktree,
+				Reuse:       reuseBranch,
+				Force:       forceWorktree,
+				WorktreeDir: worktreeDirArg,
+				NoCheckout:  noCheckout,
+				Track:       repoSettings.TrackUpstream,
+			})
+			if err != nil {
+				return err
+			}
+			printBranchName(branchOut, branchName)
+			logger.Debug("Create command completed without an issue (--branch-only)")
+			return nil
+		}
+
+		for _, name := range presets {
+			labels, err := cfg.ResolveLabelPreset(repoSettings.Repo, name)
+			if err != nil {
+				return err
+			}
+			repoSettings.PresetLabels = append(repoSettings.PresetLabels, labels...)
+		}
+
+		// External participants are added with GitLab's /add_email quick action
+		if len(participants) > 0 {
+			if repoSettings.Repo.GitlabRepo == "" {
+				printWarning("GitHub has no external participants - ignoring --external-participant")
+			} else {
+				quickActions = append(quickActions, externalParticipantAction(participants))
+			}
+		}
+
+		if batchFile != "" {
+			return createBatch(cmd, cfg, repoSettings, gitRepo, batchIssues, envLabels)
+		}
+
 		// Prompt for and validate title if not provided
 		if title == "" {
 			title, err = promptForTitle()
@@ -114,23 +477,119 @@ Usage:
 				return fmt.Errorf("issue creation cancelled")
 			}
 		}
+		title = cfg.ResolveTitleTransform(repoSettings.Repo).Apply(utils.SanitizeTitle(title))
+		if strings.TrimSpace(title) == "" {
+			return fmt.Errorf("title cannot be empty")
+		}
 
 		logger.Info("Issue title set", map[string]interface{}{
 			"title": title,
 		})
 
-		// Get labels
-		if nonInteractive {
-			repoSettings.Labels = repoSettings.Repo.DefaultLabels
+		// Include the label mapped to the branch type, if any
+		defaultLabels := baseIssueLabels(repoSettings.Repo.DefaultLabelList(), fileLabels, labelsReplace)
+		if branchType != "" {
+			if typeLabel := cfg.ResolveBranchTypeLabel(repoSettings.Repo, branchType); typeLabel != "" {
+				defaultLabels = utils.MergeLabels(defaultLabels, typeLabel)
+			}
+		}
+		if labelsFrom > 0 {
+			copied, err := copiedIssueLabels(cfg, repoSettings, labelsFrom)
+			if err != nil {
+				return err
+			}
+			defaultLabels = utils.MergeLabels(defaultLabels, copied...)
+		}
+		defaultLabels = utils.MergeLabels(defaultLabels, repoSettings.PresetLabels...)
+		defaultLabels = utils.RemoveLabels(defaultLabels, noLabels...)
+
+		// Get labels; --label (or TIX_LABELS) replaces the prompt, and with
+		// prompt_labels off only the default labels are used
+		if explicit := explicitLabels(labelFlags, envLabels); len(explicit) > 0 {
+			repoSettings.Labels = utils.MergeLabels(defaultLabels, explicit...)
+		} else if nonInteractive || !cfg.ResolvePromptLabels(repoSettings.Repo) {
+			repoSettings.Labels = defaultLabels
 		} else {
-			repoSettings.Labels, err = promptForLabels(repoSettings.Repo.DefaultLabels)
+			// Pre-fill the prompt with labels suggested by title keywords
+			promptLabels := defaultLabels
+			suggest := cfg.ResolveInteractiveLabels(repoSettings.Repo)
+			if cmd.Flags().Changed("interactive-labels") {
+				suggest = suggestLabels
+			}
+			if suggest {
+				suggested := utils.SuggestLabels(title, cfg.ResolveKeywordLabels(repoSettings.Repo))
+				promptLabels = utils.MergeLabels(defaultLabels, suggested...)
+			}
+
+			repoSettings.Labels, err = promptForLabels(promptLabels)
 			if err != nil {
 				return fmt.Errorf("issue creation cancelled")
 			}
 		}
+		repoSettings.Labels = utils.MergeLabels(repoSettings.Labels)
 
-		// Get milestone if needed
+		// GitLab records estimates as the issue weight; GitHub has no weights,
+		// so the estimate becomes a label rendered from estimate_label_template
+		if cmd.Flags().Changed("estimate") {
+			if repoSettings.Repo.GitlabRepo != "" {
+				repoSettings.Weight = &estimate
+			} else {
+				estimateLabel, err := cfg.ResolveEstimateLabel(repoSettings.Repo, estimate)
+				if err != nil {
+					return err
+				}
+				repoSettings.Labels = utils.MergeLabels(repoSettings.Labels, estimateLabel)
+			}
+		}
+
+		// --no-label wins over every source, the prompt included
+		repoSettings.Labels = utils.RemoveLabels(repoSettings.Labels, noLabels...)
+		if err := checkRequiredLabels(cfg, repoSettings.Repo, repoSettings.Name, repoSettings.Labels); err != nil {
+			return err
+		}
+
+		// GitLab keeps only one label per scope, so colliding scoped labels
+		// would silently drop all but one of them
 		if repoSettings.Repo.GitlabRepo != "" {
+			if err := checkScopedLabels(cfg, repoSettings.Repo, repoSettings.Labels); err != nil {
+				return err
+			}
+		}
+
+		// --rotation assigns the member whose turn it is, replacing
+		// self-assignment like --assignee does
+		if rotation != "" {
+			member, err := rotationAssignee(cfg, repoSettings.Repo, rotation)
+			if err != nil {
+				return err
+			}
+			assignees = append(assignees, member)
+			if !cmd.Flags().Changed("assign") {
+				selfAssign = false
+			}
+			fmt.Printf("Assigning the issue to %s (rotation %s)\n", member, rotation)
+		}
+
+		// Without --assignee, assignee_rules pick the assignees from the
+		// labels and the directory tix runs in
+		if len(assignees) == 0 {
+			if ruled := ruleAssignees(cfg, repoSettings, repoSettings.Labels); len(ruled) > 0 {
+				assignees = ruled
+				if !cmd.Flags().Changed("assign") {
+					selfAssign = false
+				}
+				fmt.Printf("Assigning the issue to %s (assignee_rules)\n", strings.Join(ruled, ", "))
+			}
+		}
+
+		// Get milestone if needed
+		if groupMilestone != "" {
+			if repoSettings.Repo.GitlabRepo == "" {
+				return fmt.Errorf("--group-milestone is only supported for GitLab repositories")
+			}
+			repoSettings.Milestone = groupMilestone
+			repoSettings.GroupMilestone = true
+		} else if repoSettings.Repo.GitlabRepo != "" {
 			if nonInteractive {
 				repoSettings.Milestone = utils.GenerateMilestone(time.Now())
 			} else {
@@ -141,9 +600,88 @@ Usage:
 			}
 		}
 
+		// Per-repo defaults apply unless overridden by flags
+		repoSettings.Confidential = repoSettings.Repo.DefaultConfidential
+		if cmd.Flags().Changed("confidential") {
+			repoSettings.Confidential = confidential
+		}
+		templateName := repoSettings.Repo.IssueTemplate
+		if cmd.Flags().Changed("template") {
+			templateName = issueTemplate
+		}
+		if issueBody != "" {
+			repoSettings.Body = issueBody
+		} else if templateName != "" {
+			// Templates are read from the issue repo's checkout when it has one
+			templateDir := repoSettings.Repo.Directory
+			if templateDir == "" {
+				templateDir = repoSettings.Directory
+			}
+			repoSettings.Body, err = utils.LoadIssueTemplate(templateDir, templateName, repoSettings.Repo.GitlabRepo != "")
+			if err != nil {
+				return err
+			}
+		}
+
+		// Fill {{name}} placeholders in templated descriptions from --template-var
+		if len(templateValues) > 0 || (issueBody == "" && templateName != "") {
+			var missing []string
+			repoSettings.Body, missing = utils.RenderTemplateVars(repoSettings.Body, templateValues)
+			if len(missing) > 0 {
+				if cfg.ResolveStrictTemplates(repoSettings.Repo) {
+					return fmt.Errorf("issue description has unfilled placeholders: %s - pass them with --template-var name=value", strings.Join(missing, ", "))
+				}
+				printWarning("issue description has unfilled placeholders: %s", strings.Join(missing, ", "))
+			}
+		}
+
+		repoSettings.QuickActions = quickActions
+		repoSettings.Attachments = attachmentPaths
+
+		if !checkRemote && !dryRun {
+			if err := confirmProtectedRepo(repoSettings.Repo, repoSettings.Name); err != nil {
+				return err
+			}
+		}
+
+		if offline {
+			branchName, err := createOfflineBranch(gitRepo, cfg, repoSettings)
+			if err != nil {
+				return err
+			}
+			printBranchName(branchOut, branchName)
+			return nil
+		}
+
+		if checkRemote {
+			return checkRemoteIssue(repoSettings)
+		}
+
+		// A dry run prints the request that would create the issue and stops there
+		if dryRun {
+			services.SetDryRun(os.Stdout)
+			defer services.SetDryRun(nil)
+		}
+
 		// Create issue using the provider
-		issueResult, err := createIssue(repoSettings)
+		issueResult, err := createIssue(cfg, repoSettings)
+		if errors.Is(err, services.ErrDryRun) {
+			fmt.Println("\nDry run: the request above was not sent, and no issue or branch was created.")
+			if branchOut == nil || noBranch {
+				return nil
+			}
+			// There is no issue number yet, so the name carries the --offline placeholder
+			branchName, err := dryRunBranchName(gitRepo, cfg, repoSettings)
+			if err != nil {
+				return err
+			}
+			printBranchName(branchOut, branchName)
+			return nil
+		}
 		if err != nil {
+			if isNetworkError(err) {
+				return fmt.Errorf("couldn't reach %s - rerun with --offline to create the branch now and file the issue later with 'tix sync'", repoSettings.Provider.GetURL())
+			}
 			if strings.Contains(err.Error(), "failed to create GitHub") ||
 				strings.Contains(err.Error(), "failed to create GitLab") {
 				return fmt.Errorf("failed to create issue - check your API token and permissions")
@@ -151,13 +689,55 @@ Usage:
 			return err
 		}
 
+		if rotation != "" {
+			advanceRotation(cfg, repoSettings.Repo, rotation)
+		}
+		if projectBoard != "" {
+			addToProjectBoard(repoSettings.Provider, issueResult.Number, projectBoard)
+		}
+
+		entry := state.HistoryEntry{
+			CreatedAt:   time.Now(),
+			Repo:        repoSettings.Name,
+			CodeRepo:    repoSettings.CodeRepoName,
+			Provider:    providerName(repoSettings.Repo),
+			IssueNumber: issueResult.Number,
+			Title:       issueResult.Title,
+			URL:         issueResult.URL,
+		}
+
+		if noBranch {
+			issueCreated(cfg, repoSettings.Repo, entry)
+			logger.Debug("Create command completed without a branch (--no-branch)")
+			return nil
+		}
+
 		// Create and checkout branch
 		// Use project prefix if issue repo differs from code repo
 		projectPrefix := ""
 		if repoSettings.Name != repoSettings.CodeRepoName {
 			projectPrefix = repoSettings.Name
 		}
-		if err := createBranch(gitRepo, repoSettings.CodeRepo, cfg, issueResult.Number, issueResult.Title, projectPrefix, useWorktree); err != nil {
+		branchOpts := branchOptions{
+			ProjectPrefix: projectPrefix,
+			BranchType:    branchType,
+			BaseBranch:    repoSettings.BaseBranch,
+			BaseRemote:    baseRemote,
+			UseWorktree:   useWorktree,
+			Reuse:         reuseBranch,
+			Force:         forceWorktree,
+			WorktreeDir:   worktreeDirArg,
+			NoCheckout:    noCheckout,
+			Track:         repoSettings.TrackUpstream,
+		}
+		issueID, err := branchIssueID(cfg, repoSettings.Repo, issueResult)
+		if err != nil {
+			issueCreated(cfg, repoSettings.Repo, entry)
+			return err
+		}
+		branchName, err := createBranch(gitRepo, repoSettings.CodeRepo, cfg, issueID, issueResult.Title, branchOpts)
+		if err != nil {
+			issueCreated(cfg, repoSettings.Repo, entry)
 			if strings.Contains(err.Error(), "failed to create branch") {
 				return fmt.Errorf("branch creation failed - the issue was created but the branch couldn't be created")
 			}
@@ -166,12 +746,69 @@ Usage:
 			}
 			return err
 		}
+		recordBranchIssue(gitRepo, branchName, issueID, issueResult.Number)
+		printBranchName(branchOut, branchName)
+
+		checkProtection := cfg.ResolveCheckBranchProtection(repoSettings.CodeRepo)
+		if cmd.Flags().Changed("check-protection") {
+			checkProtection = checkProtect
+		}
+		if checkProtection {
+			checkBaseProtection(cfg, repoSettings)
+		}
+
+		entry.Branch = branchName
+		if worktreeDirArg != "" {
+			entry.Worktree = worktreeDirArg
+		} else if useWorktree {
+			entry.Worktree, _ = resolveWorktreeDir(cfg, repoSettings.CodeRepo, issueID, issueResult.Title, branchName)
+		}
+		issueCreated(cfg, repoSettings.Repo, entry)
 
 		logger.Debug("Create command completed successfully")
 		return nil
 	},
 }
 
+// addAdHocRepository adds the repository described by --github-repo or
+// --gitlab-repo and --dir (default: the current directory) to cfg for this
+// run, and returns its name, the project path
+func addAdHocRepository(cfg *config.Settings, githubRepo, gitlabRepo, dir string) (string, error) {
+	if githubRepo != "" && gitlabRepo != "" {
+		return "", fmt.Errorf("pass only one of --github-repo or --gitlab-repo")
+	}
+	slug := githubRepo + gitlabRepo
+	if slug == "" {
+		return "", fmt.Errorf("--dir requires --github-repo or --gitlab-repo")
+	}
+	if strings.Count(slug, "/") < 1 || strings.HasPrefix(slug, "/") || strings.HasSuffix(slug, "/") {
+		return "", fmt.Errorf("invalid repository %q: use owner/name (or group/project on GitLab)", slug)
+	}
+	if cfg.GetRepo(slug) != nil {
+		return "", fmt.Errorf("a repository named %s is already configured - run 'tix create %s' instead", slug, slug)
+	}
+
+	if dir == "" {
+		dir = "."
+	}
+	absDir, err := filepath.Abs(dir)
+	if err != nil {
+		return "", fmt.Errorf("invalid --dir %s: %w", dir, err)
+	}
+
+	cfg.Repositories = append(cfg.Repositories, config.Repository{
+		Name:       slug,
+		Directory:  absDir,
+		GithubRepo: githubRepo,
+		GitlabRepo: gitlabRepo,
+	})
+	logger.Debug("Using an ad-hoc repository", map[string]interface{}{
+		"repo":      slug,
+		"directory": absDir,
+	})
+	return slug, nil
+}
+
 // setupRepository handles repository selection and configuration
 func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*RepoSettings, error) {
 	logger.Debug("Config loaded successfully", map[string]interface{}{
@@ -188,32 +825,9 @@ func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*R
 		"directory": wd,
 	})
 
-	// Find repo that matches the current directory, or the best candidate
-	// Only consider repos with directories (code repos)
-	var matchingRepo *config.Repository
-	var repoName string
-	var bestMatchLength = 0 // Length of the best match so far
-
+	// Find the code repo the current directory belongs to, if any
 	repoNames := cfg.GetRepoNames()
-	for i, repo := range cfg.Repositories {
-		if !repo.IsCodeRepo() {
-			continue
-		}
-		absRepoDir, err := filepath.Abs(repo.Directory)
-		if err != nil {
-			continue
-		}
-
-		// Check if current directory is within the repo directory
-		if strings.HasPrefix(wd, absRepoDir) {
-			// If we found an exact match, use it
-			if len(absRepoDir) > bestMatchLength {
-				matchingRepo = &cfg.Repositories[i]
-				repoName = repoNames[i]
-				bestMatchLength = len(absRepoDir)
-			}
-		}
-	}
+	matchingRepo, repoName := utils.MatchRepository(cfg, wd)
 
 	// If we found a match, we'll offer it as the default option
 	if matchingRepo != nil {
@@ -230,11 +844,12 @@ func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*R
 
 	if issueRepoArg != "" {
 		// Issue repo specified as argument
-		selectedRepo = cfg.GetRepo(issueRepoArg)
-		if selectedRepo == nil {
-			return nil, fmt.Errorf("repository '%s' not found in config", issueRepoArg)
+		var err error
+		selectedRepo, err = cfg.FindRepo(issueRepoArg)
+		if err != nil {
+			return nil, err
 		}
-		selectedRepoName = issueRepoArg
+		selectedRepoName = selectedRepo.Name
 		logger.Info("Using issue repository from argument", map[string]interface{}{
 			"repo": selectedRepoName,
 		})
@@ -276,30 +891,11 @@ func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*R
 	})
 
 	// Validate repository configuration
-	if (selectedRepo.GithubRepo == "" && selectedRepo.GitlabRepo == "") ||
-		(selectedRepo.GithubRepo != "" && selectedRepo.GitlabRepo != "") {
+	if selectedRepo.GithubRepo == "" && selectedRepo.GitlabRepo == "" {
 		logger.Error("Invalid repository configuration", nil, map[string]interface{}{
-			"repo":        selectedRepoName,
-			"github_repo": selectedRepo.GithubRepo,
-			"gitlab_repo": selectedRepo.GitlabRepo,
+			"repo": selectedRepoName,
 		})
-		return nil, fmt.Errorf("repository must have exactly one of github_repo or gitlab_repo... %+v", selectedRepo)
-	}
-
-	// Create appropriate provider
-	var provider services.SCMProvider
-	if selectedRepo.GithubRepo != "" {
-		githubProvider, err := services.NewGitHubProvider(selectedRepo.GithubRepo)
-		if err != nil {
-			return nil, fmt.Errorf("failed to create GitHub provider: %w", err)
-		}
-		provider = githubProvider
-	} else {
-		gitlabProvider, err := services.NewGitLabProvider(selectedRepo.GitlabRepo)
-		if err != nil {
-			return nil, fmt.Errorf("failed to create GitLab provider: %w", err)
-		}
-		provider = gitlabProvider
+		return nil, fmt.Errorf("repository must have github_repo or gitlab_repo... %+v", selectedRepo)
 	}
 
 	// Determine code repo (where branch will be created)
@@ -308,23 +904,18 @@ func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*R
 
 	if codeRepoArg != "" {
 		// Code repo specified as argument
-		codeRepo = cfg.GetRepo(codeRepoArg)
-		if codeRepo == nil {
-			return nil, fmt.Errorf("repository '%s' not found in config", codeRepoArg)
+		var err error
+		codeRepo, err = cfg.FindRepo(codeRepoArg)
+		if err != nil {
+			return nil, err
 		}
 		if !codeRepo.IsCodeRepo() {
-			return nil, fmt.Errorf("repository '%s' is not a code repository (missing 'directory' field)", codeRepoArg)
+			return nil, fmt.Errorf("repository '%s' is not a code repository (missing 'directory' field)", codeRepo.Name)
 		}
-		codeRepoName = codeRepoArg
+		codeRepoName = codeRepo.Name
 		logger.Info("Using code repository from argument", map[string]interface{}{
 			"repo": codeRepoName,
 		})
-
-		// Validate providers match
-		if (selectedRepo.GithubRepo != "" && codeRepo.GitlabRepo != "") ||
-			(selectedRepo.GitlabRepo != "" && codeRepo.GithubRepo != "") {
-			return nil, fmt.Errorf("issue repo '%s' and code repo '%s' must use the same provider", selectedRepoName, codeRepoName)
-		}
 	} else if selectedRepo.IsCodeRepo() {
 		// Selected repo has directory, use it
 		codeRepo = selectedRepo
@@ -361,12 +952,34 @@ func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*R
 			codeRepo = cfg.GetRepo(selectedCodeName)
 			codeRepoName = selectedCodeName
 		}
+	}
+
+	// Validate providers match, now that the code repo is known
+	if err := utils.ChoosePairedProvider(selectedRepo, codeRepo, !nonInteractive); err != nil {
+		return nil, err
+	}
+	if (selectedRepo.GithubRepo != "" && codeRepo.GitlabRepo != "") ||
+		(selectedRepo.GitlabRepo != "" && codeRepo.GithubRepo != "") {
+		return nil, fmt.Errorf("issue repo '%s' and code repo '%s' must use the same provider", selectedRepoName, codeRepoName)
+	}
 
-		// Validate providers match
-		if (selectedRepo.GithubRepo != "" && codeRepo.GitlabRepo != "") ||
-			(selectedRepo.GitlabRepo != "" && codeRepo.GithubRepo != "") {
-			return nil, fmt.Errorf("issue repo '%s' and code repo '%s' must use the same provider", selectedRepoName, codeRepoName)
+	// Create appropriate provider. Offline and --branch-only runs never talk
+	// to the provider, so they don't need a token either.
+	var provider services.SCMProvider
+	if offline || branchOnly {
+		logger.Debug("Offline or branch only - skipping provider setup")
+	} else if selectedRepo.GithubRepo != "" {
+		githubProvider, err := services.NewGitHubProvider(selectedRepo.GithubRepo)
+		if err != nil {
+			return nil, fmt.Errorf("failed to create GitHub provider: %w", err)
+		}
+		provider = githubProvider
+	} else {
+		gitlabProvider, err := services.NewGitLabProvider(selectedRepo.GitlabRepo)
+		if err != nil {
+			return nil, fmt.Errorf("failed to create GitLab provider: %w", err)
 		}
+		provider = gitlabProvider
 	}
 
 	return &RepoSettings{
@@ -379,6 +992,20 @@ func setupRepository(cfg *config.Settings, issueRepoArg, codeRepoArg string) (*R
 	}, nil
 }
 
+// clipboardTitle returns the clipboard's text as an issue title, with line
+// breaks joined into one line
+func clipboardTitle() (string, error) {
+	text, err := utils.ReadClipboard()
+	if err != nil {
+		return "", fmt.Errorf("--title-from-clipboard: %w", err)
+	}
+	clipped := strings.TrimSpace(utils.SanitizeTitle(text))
+	if clipped == "" {
+		return "", fmt.Errorf("--title-from-clipboard: the clipboard has no text")
+	}
+	return clipped, nil
+}
+
 // promptForTitle prompts the user for a title for the issue
 func promptForTitle() (string, error) {
 	result, err := pterm.DefaultInteractiveTextInput.
@@ -396,6 +1023,99 @@ func promptForTitle() (string, error) {
 	return result, nil
 }
 
+// baseIssueLabels returns the labels a new issue starts with before any given
+// with --label: the repo's default_labels, unless --labels-replace drops
+// them, plus the labels from --from-file
+func baseIssueLabels(repoDefaults, fileLabels []string, replace bool) string {
+	if replace {
+		repoDefaults = nil
+	}
+	return utils.MergeLabels(strings.Join(repoDefaults, ","), fileLabels...)
+}
+
+// explicitLabels returns the labels given with --label, or from TIX_LABELS
+// when there are none; flags take precedence over the environment
+func explicitLabels(flagLabels, envLabels []string) []string {
+	var labels []string
+	for _, label := range flagLabels {
+		labels = append(labels, config.SplitLabels(label)...)
+	}
+	if len(labels) > 0 {
+		return labels
+	}
+	return envLabels
+}
+
+// copiedIssueLabels returns the labels of an existing issue in the issue repo
+// for --labels-from-issue. The ready and unready labels are left out, since a
+// new issue shouldn't inherit the other issue's workflow state.
+func copiedIssueLabels(cfg *config.Settings, settings *RepoSettings, issueNumber int) ([]string, error) {
+	issue, err := settings.Provider.GetIssue(issueNumber)
+	if err != nil {
+		return nil, fmt.Errorf("couldn't fetch issue #%d to copy its labels: %w", issueNumber, err)
+	}
+
+	workflow := map[string]bool{
+		utils.GetReadyLabel(cfg, settings.Repo, ""):   true,
+		utils.GetUnreadyLabel(cfg, settings.Repo, ""): true,
+	}
+	var labels []string
+	for _, label := range issue.Labels {
+		if !workflow[label] {
+			labels = append(labels, label)
+		}
+	}
+	return labels, nil
+}
+
+// validateParticipants checks that each --external-participant is a bare
+// email address such as reporter@example.com
+func validateParticipants(emails []string) error {
+	for _, email := range emails {
+		addr, err := mail.ParseAddress(email)
+		if err != nil || addr.Address != email {
+			return fmt.Errorf("invalid --external-participant '%s' - use an email address like reporter@example.com", email)
+		}
+	}
+	return nil
+}
+
+// externalParticipantAction returns the GitLab quick action that adds emails
+// to an issue as external participants, who get its notifications by email
+// like the reporter of a service desk issue
+func externalParticipantAction(emails []string) string {
+	return "/add_email " + strings.Join(emails, " ")
+}
+
+// checkScopedLabels warns about GitLab scoped labels that collide on a scope,
+// or rejects them when strict_scoped_labels is set
+func checkScopedLabels(cfg *config.Settings, repo *config.Repository, labels string) error {
+	conflicts := config.ScopedLabelConflicts(utils.SplitOnCommaAndWhitespace(labels))
+	if len(conflicts) == 0 {
+		return nil
+	}
+
+	groups := make([]string, len(conflicts))
+	for i, group := range conflicts {
+		groups[i] = strings.Join(group, ", ")
+	}
+	msg := fmt.Sprintf("conflicting scoped labels, GitLab keeps only one label per scope: %s", strings.Join(groups, "; "))
+	if cfg.ResolveStrictScopedLabels(repo) {
+		return fmt.Errorf("%s", msg)
+	}
+	printWarning("%s", msg)
+	return nil
+}
+
+// checkRequiredLabels fails when require_labels is set for repo and the
+// issue's labels, from every source merged, are empty
+func checkRequiredLabels(cfg *config.Settings, repo *config.Repository, repoName, labels string) error {
+	if !cfg.ResolveRequireLabels(repo) || len(config.SplitLabels(labels)) > 0 {
+		return nil
+	}
+	return fmt.Errorf("%s requires issues to have at least one label (require_labels) - add one with --label, a --preset or default_labels", repoName)
+}
+
 // promptForLabels prompts the user for labels for the issue
 func promptForLabels(defaultLabels string) (string, error) {
 	result, err := pterm.DefaultInteractiveTextInput.
@@ -426,22 +1146,325 @@ func promptForMilestone() (string, error) {
 	return result, nil
 }
 
-// createIssue creates a new issue using the provider
-func createIssue(settings *RepoSettings) (*services.IssueResult, error) {
-	logger.Info("Creating issue", map[string]interface{}{
-		"repo":        settings.Name,
-		"self_assign": selfAssign,
-		"milestone":   settings.Milestone,
-	})
-
-	params := services.IssueParams{
+// issueParams builds the provider parameters for the issue being created
+func issueParams(settings *RepoSettings) services.IssueParams {
+	return services.IssueParams{
 		Title:          title,
 		Labels:         settings.Labels,
 		SelfAssign:     selfAssign,
+		Assignees:      assignees,
 		MilestoneTitle: settings.Milestone,
+		GroupMilestone: settings.GroupMilestone,
+		Body:           settings.Body,
+		QuickActions:   settings.QuickActions,
+		Confidential:   settings.Confidential,
+		Weight:         settings.Weight,
+		Attachments:    settings.Attachments,
+		Closed:         closeIssue,
+		Lock:           lockIssue,
+	}
+}
+
+// ruleAssignees returns the assignees assignee_rules give an issue with
+// labels, matching path rules against the working directory's place in the
+// code repository
+func ruleAssignees(cfg *config.Settings, settings *RepoSettings, labels string) []string {
+	dir := ""
+	if wd, err := os.Getwd(); err == nil && settings.Directory != "" {
+		rel, err := filepath.Rel(settings.Directory, wd)
+		if err == nil && rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+			dir = rel
+		}
+	}
+	return cfg.ResolveAssignees(settings.Repo, config.SplitLabels(labels), dir)
+}
+
+// rotationAssignee returns the member of the configured rotation name who
+// gets the next issue, without using up their turn; see advanceRotation
+func rotationAssignee(cfg *config.Settings, repo *config.Repository, name string) (string, error) {
+	key, rot, err := cfg.ResolveRotation(repo, name)
+	if err != nil {
+		return "", err
+	}
+	if rot.Strategy == config.RotationRandom {
+		return rot.Members[rand.Intn(len(rot.Members))], nil
+	}
+	index, err := state.NextInRotation(key, len(rot.Members), false)
+	if err != nil {
+		return "", fmt.Errorf("failed to read rotation '%s': %w", name, err)
+	}
+	return rot.Members[index], nil
+}
+
+// advanceRotation records in the state directory that the member whose turn
+// it is in a round-robin rotation got an issue, so the next one goes to the
+// following member. It runs once the issue is filed, so a run that fails
+// doesn't use up anyone's turn; failing to record it is only a warning.
+func advanceRotation(cfg *config.Settings, repo *config.Repository, name string) {
+	key, rot, err := cfg.ResolveRotation(repo, name)
+	if err != nil || rot.Strategy == config.RotationRandom {
+		return
+	}
+	if _, err := state.NextInRotation(key, len(rot.Members), true); err != nil {
+		printWarning("couldn't record the turn in rotation '%s': %v", name, err)
+	}
+}
+
+// checkRemoteIssue validates the issue against the provider without creating
+// it or touching the repository, and reports what would be created. Any
+// problem makes the command fail so it can gate CI.
+func checkRemoteIssue(settings *RepoSettings) error {
+	checker, ok := settings.Provider.(services.IssueChecker)
+	if !ok {
+		return fmt.Errorf("--check-remote isn't supported for %s", settings.Provider.GetURL())
+	}
+
+	params := issueParams(settings)
+	problems, err := checker.CheckIssue(params)
+	if err != nil {
+		return err
+	}
+
+	fmt.Printf("Would create issue in %s: %s\n", settings.Provider.GetURL(), params.Title)
+	if params.Labels != "" {
+		fmt.Printf("  Labels:    %s\n", params.Labels)
 	}
+	if params.MilestoneTitle != "" && params.GroupMilestone {
+		fmt.Printf("  Milestone: %s (group)\n", params.MilestoneTitle)
+	} else if params.MilestoneTitle != "" {
+		fmt.Printf("  Milestone: %s\n", params.MilestoneTitle)
+	}
+	if params.Weight != nil {
+		fmt.Printf("  Weight:    %d\n", *params.Weight)
+	}
+	if who := assigneesForDisplay(params); who != "" {
+		fmt.Printf("  Assignees: %s\n", who)
+	}
+	if params.Confidential {
+		fmt.Println("  Confidential")
+	}
+	if params.Closed {
+		fmt.Println("  Closed")
+	}
+	if params.Lock {
+		fmt.Println("  Locked")
+	}
+	if len(params.Attachments) > 0 {
+		fmt.Printf("  Attach:    %s\n", strings.Join(params.Attachments, ", "))
+	}
+
+	if len(problems) > 0 {
+		fmt.Fprintln(os.Stderr, "Problems:")
+		for _, problem := range problems {
+			fmt.Fprintf(os.Stderr, "  - %s\n", problem)
+		}
+		return fmt.Errorf("remote check found %d problem(s) - nothing was created", len(problems))
+	}
+
+	printSuccess("Remote check passed - nothing was created.")
+	return nil
+}
+
+// idempotencyMarker is the hidden comment that carries an idempotency key in
+// the description of issues filed with idempotent_create
+func idempotencyMarker(key string) string {
+	return fmt.Sprintf("<!-- tix-idempotency-key: %s -->", key)
+}
 
-	issueResult, err := settings.Provider.CreateIssue(params)
+// fileIssue creates the issue described by params in repo. With
+// idempotent_create it records an idempotency key before sending the request
+// and hides it in the description; when an earlier attempt to file the same
+// title never got an answer, the issue that attempt created is returned
+// instead of filing a duplicate.
+func fileIssue(cfg *config.Settings, repo *config.Repository, repoName string, provider services.SCMProvider, params services.IssueParams) (*services.IssueResult, error) {
+	params.LabelAliases = cfg.ResolveLabelAliases(repo, repo.Provider())
+	createColoredLabels(cfg, repo, provider, params.Labels, params.LabelAliases)
+	// A dry run creates nothing, so there is no attempt to record
+	if !cfg.ResolveIdempotentCreate(repo) || dryRun {
+		return provider.CreateIssue(params)
+	}
+
+	attempts, err := state.LoadAttempts()
+	if err != nil {
+		printWarning("filing the issue without an idempotency key: %v", err)
+		return provider.CreateIssue(params)
+	}
+
+	var key string
+	if attempt := state.FindAttempt(attempts, repoName, params.Title); attempt != nil {
+		key = attempt.Key
+		if existing := findAttemptIssue(provider, key); existing != nil {
+			printSuccess("Found issue #%d from an earlier attempt that never got a response - not filing it again.", existing.Number)
+			forgetAttempt(key)
+			return existing, nil
+		}
+	} else {
+		if key, err = state.NewAttemptKey(); err != nil {
+			return nil, err
+		}
+		attempts = append(attempts, state.CreateAttempt{Key: key, Repo: repoName, Title: params.Title, CreatedAt: time.Now()})
+		if err := state.SaveAttempts(attempts); err != nil {
+			printWarning("filing the issue without an idempotency key: %v", err)
+			return provider.CreateIssue(params)
+		}
+	}
+
+	if params.Body != "" {
+		params.Body += "\n\n"
+	}
+	params.Body += idempotencyMarker(key)
+
+	result, err := provider.CreateIssue(params)
+	// Only a dropped connection leaves it unclear whether the issue was
+	// created; any other answer means the attempt is settled
+	if err == nil || !isNetworkError(err) {
+		forgetAttempt(key)
+	}
+	return result, err
+}
+
+// createColoredLabels creates the labels of a new issue that the project
+// doesn't have yet in their label_color_map color, instead of leaving them to
+// be created in the provider's default color. Labels are created under their
+// label_aliases name, colored by their canonical one. Problems are only
+// warnings, as the provider still creates a missing label when the issue uses
+// it.
+func createColoredLabels(cfg *config.Settings, repo *config.Repository, provider services.SCMProvider, labels string, aliases map[string]string) {
+	creator, ok := provider.(services.LabelCreator)
+	if !ok || dryRun {
+		return
+	}
+	for _, canonical := range config.SplitLabels(labels) {
+		color, err := cfg.ResolveLabelColor(repo, canonical)
+		if err != nil {
+			printWarning("%v", err)
+			continue
+		}
+		if color == "" {
+			return
+		}
+		label := config.AliasLabels([]string{canonical}, aliases)[0]
+		exists, err := creator.LabelExists(label)
+		if err != nil {
+			printWarning("couldn't check label %s: %v", label, err)
+			continue
+		}
+		if exists {
+			continue
+		}
+		if err := creator.CreateLabel(label, color); err != nil {
+			printWarning("couldn't create label %s in %s: %v", label, color, err)
+			continue
+		}
+		fmt.Printf("Created label %s (%s)\n", label, color)
+	}
+}
+
+// newLabels returns the labels (under their label_aliases names) that the
+// project doesn't have yet, or nil when the provider can't tell
+func newLabels(provider services.SCMProvider, labels []string) ([]string, error) {
+	creator, ok := provider.(services.LabelCreator)
+	if !ok {
+		return nil, nil
+	}
+	var missing []string
+	for _, label := range labels {
+		exists, err := creator.LabelExists(label)
+		if err != nil {
+			return nil, fmt.Errorf("couldn't check label %s: %w", label, err)
+		}
+		if !exists {
+			missing = append(missing, label)
+		}
+	}
+	return missing, nil
+}
+
+// confirmNewLabels guards against labels created by accident, e.g. from a
+// typo: with confirm_new_labels it lists the labels filing the issue would
+// create and asks before going on, unless --create-labels is given. In
+// non-interactive mode --create-labels is required.
+func confirmNewLabels(cfg *config.Settings, repo *config.Repository, provider services.SCMProvider, labels string) error {
+	if !cfg.ResolveConfirmNewLabels(repo) || createLabels || dryRun {
+		return nil
+	}
+	aliases := cfg.ResolveLabelAliases(repo, repo.Provider())
+	missing, err := newLabels(provider, config.AliasLabels(config.SplitLabels(labels), aliases))
+	if err != nil || len(missing) == 0 {
+		return err
+	}
+
+	if nonInteractive {
+		return fmt.Errorf("label(s) %s don't exist in %s yet (confirm_new_labels) - fix the label names, or pass --create-labels to create them", strings.Join(missing, ", "), provider.GetURL())
+	}
+	fmt.Printf("These labels don't exist in %s yet and will be created:\n", provider.GetURL())
+	for _, label := range missing {
+		fmt.Printf("  - %s\n", label)
+	}
+	confirmed, err := pterm.DefaultInteractiveConfirm.
+		WithDefaultText("Create them?").
+		WithDefaultValue(false).
+		Show()
+	if err != nil || !confirmed {
+		return fmt.Errorf("issue creation cancelled - nothing was created")
+	}
+	return nil
+}
+
+// findAttemptIssue returns the issue whose description carries key, or nil
+// when there is none or the provider can't look for one
+func findAttemptIssue(provider services.SCMProvider, key string) *services.IssueResult {
+	finder, ok := provider.(services.IssueFinder)
+	if !ok {
+		return nil
+	}
+	issue, err := finder.FindIssueByBody(idempotencyMarker(key))
+	if err != nil {
+		printWarning("couldn't check for an issue from an earlier attempt: %v", err)
+		return nil
+	}
+	return issue
+}
+
+// forgetAttempt drops the recorded create attempt with key. A leftover
+// attempt only costs an extra lookup on the next retry, so failures are
+// logged rather than returned.
+func forgetAttempt(key string) {
+	attempts, err := state.LoadAttempts()
+	if err == nil {
+		err = state.SaveAttempts(state.RemoveAttempt(attempts, key))
+	}
+	if err != nil {
+		logger.Warn("Failed to clear create attempt", map[string]interface{}{
+			"key":   key,
+			"error": err.Error(),
+		})
+	}
+}
+
+// assigneesForDisplay lists the users an issue would be assigned to
+func assigneesForDisplay(params services.IssueParams) string {
+	users := params.Assignees
+	if params.SelfAssign {
+		users = append([]string{"me"}, users...)
+	}
+	return strings.Join(users, ", ")
+}
+
+// createIssue creates a new issue using the provider
+func createIssue(cfg *config.Settings, settings *RepoSettings) (*services.IssueResult, error) {
+	logger.Info("Creating issue", map[string]interface{}{
+		"repo":         settings.Name,
+		"self_assign":  selfAssign,
+		"assignees":    assignees,
+		"milestone":    settings.Milestone,
+		"confidential": settings.Confidential,
+	})
+
+	if err := confirmNewLabels(cfg, settings.Repo, settings.Provider, settings.Labels); err != nil {
+		return nil, err
+	}
+	issueResult, err := fileIssue(cfg, settings.Repo, settings.Name, settings.Provider, issueParams(settings))
 	if err != nil {
 		return nil, fmt.Errorf("failed to create issue: %w", err)
 	}
@@ -452,9 +1475,13 @@ func createIssue(settings *RepoSettings) (*services.IssueResult, error) {
 	})
 
 	// Get issue URL from the provider
-	var issueURL string
-	if _, err := settings.Provider.GetIssue(issueResult.Number); err == nil {
-		issueURL = fmt.Sprintf("%s/issues/%d", settings.Provider.GetURL(), issueResult.Number)
+	issueURL := issueResult.URL
+	if issueURL == "" {
+		if _, err := settings.Provider.GetIssue(issueResult.Number); err == nil {
+			issueURL = fmt.Sprintf("%s/issues/%d", settings.Provider.GetURL(), issueResult.Number)
+		}
+	}
+	if issueURL != "" {
 		logger.Info("Issue URL", map[string]interface{}{
 			"url": issueURL,
 		})
@@ -462,66 +1489,1059 @@ func createIssue(settings *RepoSettings) (*services.IssueResult, error) {
 
 	// Show URL in terminal
 	if issueURL != "" {
-		fmt.Printf("Created issue: %s\n", issueURL)
+		printSuccess("Created issue: %s", issueURL)
 	} else {
-		fmt.Printf("Created issue #%d: %s\n", issueResult.Number, issueResult.Title)
+		printSuccess("Created issue #%d: %s", issueResult.Number, issueResult.Title)
+	}
+
+	// Copying is a convenience; never fail the command over it
+	if copyURL && issueURL != "" {
+		if err := utils.CopyToClipboard(issueURL); err != nil {
+			printWarning("couldn't copy the issue URL to the clipboard: %v", err)
+		} else {
+			printSuccess("Copied issue URL to the clipboard.")
+		}
 	}
 
 	return issueResult, nil
 }
 
-// createBranch creates and checks out a new branch
-func createBranch(gitRepo *git.Repository, repo *config.Repository, cfg *config.Settings, issueNumber int, issueTitle string, projectPrefix string, useWorktree bool) error {
+// batchResult is the outcome of one issue in a --batch run
+type batchResult struct {
+	Title  string
+	Number int
+	URL    string
+	Branch string
+	Err    error
+}
+
+// createBatch files every issue of a --batch file in the issue repo, plus a
+// branch for each one that asks for it. A failed issue doesn't stop the
+// batch; the failures are listed in the summary and fail the command.
+func createBatch(cmd *cobra.Command, cfg *config.Settings, settings *RepoSettings, gitRepo git.Backend, issues []utils.BatchIssue, envLabels []string) error {
+	if err := confirmProtectedRepo(settings.Repo, settings.Name); err != nil {
+		return err
+	}
+
+	// The milestone and description defaults are shared by the whole batch
+	if groupMilestone != "" {
+		if settings.Repo.GitlabRepo == "" {
+			return fmt.Errorf("--group-milestone is only supported for GitLab repositories")
+		}
+		settings.Milestone = groupMilestone
+		settings.GroupMilestone = true
+	} else if settings.Repo.GitlabRepo != "" {
+		if nonInteractive {
+			settings.Milestone = utils.GenerateMilestone(time.Now())
+		} else {
+			var err error
+			settings.Milestone, err = promptForMilestone()
+			if err != nil {
+				return fmt.Errorf("issue creation cancelled")
+			}
+		}
+	}
+	settings.Confidential = settings.Repo.DefaultConfidential
+	if cmd.Flags().Changed("confidential") {
+		settings.Confidential = confidential
+	}
+
+	results := make([]batchResult, 0, len(issues))
+	for _, issue := range issues {
+		result := batchResult{Title: issue.Title}
+		result.Number, result.URL, result.Branch, result.Err = createBatchIssue(cmd, cfg, settings, gitRepo, issue, envLabels)
+		if result.Err != nil {
+			printWarning("%s: %v", issue.Title, result.Err)
+		}
+		results = append(results, result)
+	}
+
+	return batchSummary(os.Stdout, results)
+}
+
+// createBatchIssue files one issue of a --batch file and returns its number,
+// URL and branch (empty unless the item asked for one)
+func createBatchIssue(cmd *cobra.Command, cfg *config.Settings, settings *RepoSettings, gitRepo git.Backend, issue utils.BatchIssue, envLabels []string) (int, string, string, error) {
+	issue.Title = cfg.ResolveTitleTransform(settings.Repo).Apply(utils.SanitizeTitle(issue.Title))
+	labels := baseIssueLabels(settings.Repo.DefaultLabelList(), issue.Labels, labelsReplace)
+	if branchType != "" {
+		if typeLabel := cfg.ResolveBranchTypeLabel(settings.Repo, branchType); typeLabel != "" {
+			labels = utils.MergeLabels(labels, typeLabel)
+		}
+	}
+	labels = utils.MergeLabels(labels, settings.PresetLabels...)
+	labels = utils.MergeLabels(labels, explicitLabels(labelFlags, envLabels)...)
+	labels = utils.RemoveLabels(labels, noLabels...)
+	if err := checkRequiredLabels(cfg, settings.Repo, settings.Name, labels); err != nil {
+		return 0, "", "", err
+	}
+	if settings.Repo.GitlabRepo != "" {
+		if err := checkScopedLabels(cfg, settings.Repo, labels); err != nil {
+			return 0, "", "", err
+		}
+	}
+
+	// An item's assignees replace self-assignment, like --assignee does, and
+	// so do those from --rotation or assignee_rules when neither gives any
+	self := selfAssign
+	itemAssignees := append(append([]string{}, assignees...), issue.Assignees...)
+	fromRotation := len(itemAssignees) == 0 && rotation != ""
+	if fromRotation {
+		member, err := rotationAssignee(cfg, settings.Repo, rotation)
+		if err != nil {
+			return 0, "", "", err
+		}
+		itemAssignees = []string{member}
+	}
+	if len(itemAssignees) == 0 {
+		itemAssignees = ruleAssignees(cfg, settings, labels)
+	}
+	if len(itemAssignees) > len(assignees) && !cmd.Flags().Changed("assign") {
+		self = false
+	}
+
+	if err := confirmNewLabels(cfg, settings.Repo, settings.Provider, labels); err != nil {
+		return 0, "", "", err
+	}
+	result, err := fileIssue(cfg, settings.Repo, settings.Name, settings.Provider, services.IssueParams{
+		Title:          issue.Title,
+		Labels:         labels,
+		SelfAssign:     self,
+		Assignees:      itemAssignees,
+		MilestoneTitle: settings.Milestone,
+		GroupMilestone: settings.GroupMilestone,
+		Body:           issue.Body,
+		QuickActions:   quickActions,
+		Confidential:   settings.Confidential,
+		Closed:         closeIssue,
+		Lock:           lockIssue,
+	})
+	if err != nil {
+		if isNetworkError(err) {
+			return 0, "", "", fmt.Errorf("couldn't reach %s", settings.Provider.GetURL())
+		}
+		return 0, "", "", fmt.Errorf("failed to create issue: %w", err)
+	}
+	if fromRotation {
+		advanceRotation(cfg, settings.Repo, rotation)
+	}
+	issueURL := result.URL
+	if issueURL == "" {
+		issueURL = fmt.Sprintf("%s/issues/%d", settings.Provider.GetURL(), result.Number)
+	}
+	printSuccess("Created issue: %s", issueURL)
+
+	if projectBoard != "" {
+		addToProjectBoard(settings.Provider, result.Number, projectBoard)
+	}
+
+	entry := state.HistoryEntry{
+		CreatedAt:   time.Now(),
+		Repo:        settings.Name,
+		CodeRepo:    settings.CodeRepoName,
+		Provider:    providerName(settings.Repo),
+		IssueNumber: result.Number,
+		Title:       result.Title,
+		URL:         issueURL,
+	}
+	defer func() { issueCreated(cfg, settings.Repo, entry) }()
+
+	if !issue.Branch {
+		return result.Number, issueURL, "", nil
+	}
+
+	projectPrefix := ""
+	if settings.Name != settings.CodeRepoName {
+		projectPrefix = settings.Name
+	}
+	issueID, err := branchIssueID(cfg, settings.Repo, result)
+	if err != nil {
+		return result.Number, issueURL, "", err
+	}
+	branchName, err := createBranch(gitRepo, settings.CodeRepo, cfg, issueID, result.Title, branchOptions{
+		ProjectPrefix: projectPrefix,
+		BranchType:    branchType,
+		BaseBranch:    settings.BaseBranch,
+		BaseRemote:    baseRemote,
+		UseWorktree:   useWorktree,
+		Force:         forceWorktree,
+		NoCheckout:    noCheckout,
+		Track:         settings.TrackUpstream,
+	})
+	if err != nil {
+		return result.Number, issueURL, "", fmt.Errorf("issue #%d was created but its branch wasn't: %w", result.Number, err)
+	}
+	recordBranchIssue(gitRepo, branchName, issueID, result.Number)
+
+	entry.Branch = branchName
+	if useWorktree {
+		entry.Worktree, _ = resolveWorktreeDir(cfg, settings.CodeRepo, issueID, result.Title, branchName)
+	}
+	return result.Number, issueURL, branchName, nil
+}
+
+// batchSummary lists the issues a --batch run created and the ones that
+// failed, and returns an error if any failed
+func batchSummary(w io.Writer, results []batchResult) error {
+	var failed []batchResult
+	created := 0
+	for _, result := range results {
+		if result.Number > 0 {
+			created++
+		}
+		if result.Err != nil {
+			failed = append(failed, result)
+		}
+	}
+
+	fmt.Fprintf(w, "\nCreated %d of %d issues:\n", created, len(results))
+	for _, result := range results {
+		if result.Number == 0 {
+			continue
+		}
+		line := fmt.Sprintf("  #%d %s", result.Number, result.URL)
+		if result.Branch != "" {
+			line += fmt.Sprintf(" (branch %s)", result.Branch)
+		}
+		fmt.Fprintln(w, line)
+	}
+
+	if len(failed) == 0 {
+		return nil
+	}
+	fmt.Fprintln(w, "Failed:")
+	for _, result := range failed {
+		fmt.Fprintf(w, "  %s: %v\n", result.Title, result.Err)
+	}
+	return fmt.Errorf("%d of %d issues in the batch failed", len(failed), len(results))
+}
+
+// confirmProtectedRepo asks for confirmation before an issue is filed in a
+// repository marked protected. --i-know skips the question; without it,
+// --non-interactive refuses.
+func confirmProtectedRepo(repo *config.Repository, name string) error {
+	if !repo.Protected || iKnow {
+		return nil
+	}
+
+	target := "GitHub " + repo.GithubRepo
+	if repo.GitlabRepo != "" {
+		target = "GitLab " + repo.GitlabRepo
+	}
+	if nonInteractive {
+		return fmt.Errorf("repository '%s' (%s) is protected - pass --i-know to file an issue there", name, target)
+	}
+
+	confirmed, err := pterm.DefaultInteractiveConfirm.
+		WithDefaultText(fmt.Sprintf("'%s' is a protected repository. Really create an issue on %s?", name, target)).
+		WithDefaultValue(false).
+		Show()
+	if err != nil || !confirmed {
+		return fmt.Errorf("issue creation cancelled")
+	}
+	return nil
+}
+
+// createOfflineBranch creates a branch with a placeholder issue ID and records
+// the issue in the pending queue so `tix sync` can file it later
+func createOfflineBranch(gitRepo git.Backend, cfg *config.Settings, settings *RepoSettings) (string, error) {
+	issueID := utils.PendingIssueID(time.Now())
+	branchOpts := branchOptions{
+		BranchType:  branchType,
+		BaseBranch:  settings.BaseBranch,
+		BaseRemote:  baseRemote,
+		UseWorktree: useWorktree,
+		Force:       forceWorktree,
+		NoCheckout:  noCheckout,
+		Track:       settings.TrackUpstream,
+	}
+	branchName, err := createBranch(gitRepo, settings.CodeRepo, cfg, issueID, title, branchOpts)
+	if err != nil {
+		return "", err
+	}
+
+	pending := state.PendingIssue{
+		Repo:           settings.Name,
+		CodeRepo:       settings.CodeRepoName,
+		Title:          title,
+		Labels:         settings.Labels,
+		Milestone:      settings.Milestone,
+		GroupMilestone: settings.GroupMilestone,
+		SelfAssign:     selfAssign,
+		Assignees:      assignees,
+		Project:        projectBoard,
+		Body:           settings.Body,
+		QuickActions:   settings.QuickActions,
+		Confidential:   settings.Confidential,
+		Weight:         settings.Weight,
+		Attachments:    settings.Attachments,
+		Closed:         closeIssue,
+		Locked:         lockIssue,
+		Branch:         branchName,
+		PlaceholderID:  issueID,
+		BranchType:     branchType,
+		CreatedAt:      time.Now(),
+	}
+	if err := state.AddPending(pending); err != nil {
+		return "", fmt.Errorf("branch created but the pending issue couldn't be saved: %w", err)
+	}
+
+	fmt.Println("Offline: the issue will be filed and the branch renamed by 'tix sync'")
+	return branchName, nil
+}
+
+// dryRunBranchName returns the name a dry run's branch would get, with the
+// tmp-<timestamp> placeholder ID in place of the issue number
+func dryRunBranchName(gitRepo git.Backend, cfg *config.Settings, settings *RepoSettings) (string, error) {
+	projectPrefix := ""
+	if settings.Name != settings.CodeRepoName {
+		projectPrefix = settings.Name
+	}
+	return branchNameFor(gitRepo, cfg, settings.CodeRepo, utils.BranchNameVars{
+		Type:   branchType,
+		Prefix: projectPrefix,
+		ID:     utils.PendingIssueID(time.Now()),
+		Title:  title,
+	})
+}
+
+// printBranchName writes the created branch's name for --print-branch; out is
+// nil when the flag isn't set
+func printBranchName(out *os.File, branchName string) {
+	if out != nil {
+		fmt.Fprintln(out, branchName)
+	}
+}
+
+// addToProjectBoard adds a newly created issue to a project board. Failures are
+// reported as warnings since the issue itself was created.
+func addToProjectBoard(provider services.SCMProvider, issueNumber int, project string) {
+	boards, ok := provider.(services.ProjectBoardProvider)
+	if !ok {
+		printWarning("--project is only supported for GitHub repositories - skipping")
+		return
+	}
+
+	boardTitle, err := boards.AddIssueToProjectBoard(issueNumber, project)
+	if err != nil {
+		printWarning("couldn't add issue #%d to project %s: %v", issueNumber, project, err)
+		return
+	}
+	fmt.Printf("Added issue #%d to project: %s\n", issueNumber, boardTitle)
+}
+
+// checkBaseProtection warns when the code repository's default branch is
+// protected against direct pushes, so the new branch has to land through a
+// merge/pull request. A failed check is only a warning.
+func checkBaseProtection(cfg *config.Settings, settings *RepoSettings) {
+	provider := settings.Provider
+	if settings.CodeRepoName != settings.Name {
+		if settings.CodeRepo.GithubRepo == "" && settings.CodeRepo.GitlabRepo == "" {
+			return
+		}
+		var err error
+		provider, err = utils.CreateSCMProvider(&utils.SharedRepoInfo{
+			Repo:     settings.CodeRepo,
+			Name:     settings.CodeRepoName,
+			IsGitLab: settings.CodeRepo.GitlabRepo != "",
+		})
+		if err != nil {
+			printWarning("couldn't check branch protection: %v", err)
+			return
+		}
+	}
+
+	if notice := branchProtectionNotice(provider, cfg.ResolveDefaultBranch(settings.CodeRepo)); notice != "" {
+		printWarning("%s", notice)
+	}
+}
+
+// branchProtectionNotice returns the guidance to print when branch blocks
+// direct pushes, or "" when it doesn't or the provider can't tell
+func branchProtectionNotice(provider services.SCMProvider, branch string) string {
+	checker, ok := provider.(services.BranchProtectionChecker)
+	if !ok {
+		return ""
+	}
+	protection, err := checker.BranchProtection(branch)
+	if err != nil {
+		printWarning("couldn't check the protection of %s: %v", branch, err)
+		return ""
+	}
+	if !protection.PushBlocked {
+		return ""
+	}
+	return fmt.Sprintf("%s is protected; you'll need a merge/pull request to land this branch (tix mr)", branch)
+}
+
+// isNetworkError reports whether err was caused by failing to reach the server
+func isNetworkError(err error) bool {
+	var opErr *net.OpError
+	var dnsErr *net.DNSError
+	return errors.As(err, &opErr) || errors.As(err, &dnsErr)
+}
+
+// branchOptions controls how createBranch names and creates the branch
+type branchOptions struct {
+	Name          string // Use this branch name as is (--branch-only) instead of building one
+	ProjectPrefix string // Set when the issue lives in a different repo than the code
+	BranchType    string // Optional type prefix, e.g. "feature"
+	BaseBranch    string // Base to branch from; required for worktrees, "" means HEAD otherwise
+	BaseRemote    string // Remote BaseBranch was taken from with --base-remote, e.g. "upstream"
+	UseWorktree   bool   // Create a worktree instead of checking out in place
+	Reuse         bool   // With UseWorktree, check out an existing branch instead of failing
+	Force         bool   // With UseWorktree, remove a stale directory at the worktree path without asking
+	WorktreeDir   string // With UseWorktree, put the worktree here instead of the configured path (--worktree-dir)
+	NoCheckout    bool   // Create the branch but leave HEAD untouched
+	Track         bool   // Set the branch's upstream to the remote copy of BaseBranch
+}
+
+// createBranch creates and checks out a new branch and returns its name
+func createBranch(gitRepo git.Backend, repo *config.Repository, cfg *config.Settings, issueID string, issueTitle string, opts branchOptions) (string, error) {
 	// Create branch name
-	var branchName string
-	if projectPrefix != "" {
-		branchName = fmt.Sprintf("%s-%d-%s", projectPrefix, issueNumber, utils.TruncateAndDashCase(issueTitle, 50))
-	} else {
-		branchName = fmt.Sprintf("%d-%s", issueNumber, utils.TruncateAndDashCase(issueTitle, 50))
+	branchName := opts.Name
+	if branchName == "" {
+		var err error
+		branchName, err = branchNameFor(gitRepo, cfg, repo, utils.BranchNameVars{
+			Type:   opts.BranchType,
+			Prefix: opts.ProjectPrefix,
+			ID:     issueID,
+			Title:  issueTitle,
+		})
+		if err != nil {
+			return "", err
+		}
 	}
 	logger.Debug("Branch name created", map[string]interface{}{
 		"branch": branchName,
 	})
 
-	if useWorktree {
-		worktreeBase := cfg.ResolveWorktreePath(repo)
-		worktreeDir := filepath.Join(worktreeBase, branchName)
+	start, err := branchStartPoint(gitRepo, opts.BaseBranch)
+	if err != nil {
+		return "", err
+	}
+
+	if opts.UseWorktree {
+		worktreeDir := opts.WorktreeDir
+		if worktreeDir == "" {
+			worktreeDir, err = resolveWorktreeDir(cfg, repo, issueID, issueTitle, branchName)
+			if err != nil {
+				return "", err
+			}
+		}
 		logger.Info("Creating worktree", map[string]interface{}{
 			"branch":    branchName,
 			"directory": worktreeDir,
 		})
 
-		defaultBranch := cfg.ResolveDefaultBranch(repo)
-		if err := gitRepo.AddWorktree(worktreeDir, branchName, defaultBranch); err != nil {
-			return fmt.Errorf("failed to create worktree: %w", err)
+		reused, err := addIssueWorktree(gitRepo, worktreeDir, branchName, start, opts.Reuse, opts.Force, nonInteractive)
+		if err != nil {
+			return "", err
+		}
+		if reused {
+			// The existing branch keeps whatever upstream it already has
+			printSuccess("Created worktree for existing branch %s: %s", branchName, worktreeDir)
+			return branchName, nil
+		}
+
+		printSuccess("Created worktree: %s", worktreeDir)
+	} else if opts.NoCheckout {
+		logger.Info("Creating branch without checking it out", map[string]interface{}{
+			"branch": branchName,
+			"base":   opts.BaseBranch,
+		})
+
+		if err := checkBranchNotCheckedOut(gitRepo, branchName); err != nil {
+			return "", err
+		}
+		if err := createLocalBranch(gitRepo, branchName, start); err != nil {
+			logger.Error("Failed to create branch", err)
+			return "", fmt.Errorf("failed to create branch: %w", err)
 		}
 
-		fmt.Printf("Created worktree: %s\n", worktreeDir)
+		printSuccess("Created branch: %s", branchName)
 	} else {
 		logger.Info("Creating and checking out branch", map[string]interface{}{
 			"branch": branchName,
+			"base":   opts.BaseBranch,
 		})
 
-		if err := gitRepo.CreateBranch(branchName); err != nil {
+		if err := checkBranchNotCheckedOut(gitRepo, branchName); err != nil {
+			return "", err
+		}
+		if err := createLocalBranch(gitRepo, branchName, start); err != nil {
 			logger.Error("Failed to create branch", err)
-			return fmt.Errorf("failed to create branch: %w", err)
+			return "", fmt.Errorf("failed to create branch: %w", err)
 		}
 		if err := gitRepo.CheckoutBranch(branchName); err != nil {
 			logger.Error("Failed to checkout branch", err)
-			return fmt.Errorf("failed to checkout branch: %w", err)
+			return "", fmt.Errorf("failed to checkout branch: %w", err)
+		}
+
+		printSuccess("Created and checked out branch: %s", branchName)
+	}
+
+	if opts.Track {
+		trackBaseBranch(gitRepo, branchName, opts.BaseRemote, opts.BaseBranch)
+	}
+
+	return branchName, nil
+}
+
+// addIssueWorktree adds a worktree at worktreeDir on a new branchName based
+// on base. If the branch already exists, reuse checks it out in the worktree
+// instead (reporting true); git allows a branch in only one worktree, so a
+// branch already checked out elsewhere is an error. Leftovers of a deleted
+// worktree at worktreeDir are cleared first (see clearStaleWorktreeDir).
+func addIssueWorktree(gitRepo git.Backend, worktreeDir, branchName, base string, reuse, force, nonInteractive bool) (bool, error) {
+	if !gitRepo.BranchExists(branchName) {
+		if err := clearStaleWorktreeDir(gitRepo, worktreeDir, base, force, nonInteractive); err != nil {
+			return false, err
+		}
+		if err := gitRepo.AddWorktree(worktreeDir, branchName, base); err != nil {
+			return false, fmt.Errorf("failed to create worktree: %w", err)
+		}
+		return false, nil
+	}
+
+	if !reuse {
+		return false, fmt.Errorf("branch %s already exists - pass --reuse to create a worktree for it", branchName)
+	}
+	existing, err := gitRepo.WorktreePathForBranch(branchName)
+	if err != nil {
+		return false, err
+	}
+	if existing != "" {
+		// A worktree deleted by hand still holds on to its branch until pruned
+		if _, prunable, err := gitRepo.WorktreeRegistered(existing); err == nil && prunable {
+			if err := gitRepo.PruneWorktrees(); err != nil {
+				return false, err
+			}
+			fmt.Printf("Pruned git's record of the deleted worktree at %s\n", existing)
+			existing = ""
+		}
+	}
+	if existing != "" {
+		return false, fmt.Errorf("branch %s is already checked out in %s - use that worktree, or remove it with 'tix cleanup'", branchName, existing)
+	}
+	if err := clearStaleWorktreeDir(gitRepo, worktreeDir, branchName, force, nonInteractive); err != nil {
+		return false, err
+	}
+	if err := gitRepo.AddWorktreeForBranch(worktreeDir, branchName); err != nil {
+		return false, fmt.Errorf("failed to create worktree: %w", err)
+	}
+	return true, nil
+}
+
+// clearStaleWorktreeDir gets worktreeDir ready for a new worktree after one
+// was deleted by hand. If git still records a worktree there whose directory
+// or .git file is gone, the record is pruned. A leftover directory git doesn't
+// know about is removed once confirmed (or with --force), but only if it holds
+// exactly rev's files - anything modified or untracked means it may hold
+// uncommitted work, so tix refuses. Ignored files are removed with it.
+func clearStaleWorktreeDir(gitRepo git.Backend, worktreeDir, rev string, force, nonInteractive bool) error {
+	registered, prunable, err := gitRepo.WorktreeRegistered(worktreeDir)
+	if err != nil {
+		return err
+	}
+	if registered && !prunable {
+		// A live worktree; git reports the conflict
+		return nil
+	}
+	if prunable {
+		if err := gitRepo.PruneWorktrees(); err != nil {
+			return err
+		}
+		fmt.Printf("Pruned git's record of the deleted worktree at %s\n", worktreeDir)
+	}
+
+	info, err := os.Stat(worktreeDir)
+	if err != nil || !info.IsDir() {
+		return nil
+	}
+	entries, err := os.ReadDir(worktreeDir)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", worktreeDir, err)
+	}
+	if len(entries) == 0 {
+		return nil
+	}
+
+	clean, err := gitRepo.DirMatchesRevision(worktreeDir, rev)
+	if err != nil {
+		return err
+	}
+	if !clean {
+		return fmt.Errorf("%s already exists and has files that aren't committed on %s - move or delete it yourself", worktreeDir, rev)
+	}
+
+	if !force {
+		if nonInteractive {
+			return fmt.Errorf("%s is left over from a deleted worktree - pass --force to remove it", worktreeDir)
+		}
+		confirmed, err := pterm.DefaultInteractiveConfirm.
+			WithDefaultText(fmt.Sprintf("%s is left over from a deleted worktree. Remove it and create the worktree there?", worktreeDir)).
+			WithDefaultValue(false).
+			Show()
+		if err != nil || !confirmed {
+			return fmt.Errorf("worktree creation cancelled")
+		}
+	}
+
+	if err := os.RemoveAll(worktreeDir); err != nil {
+		return fmt.Errorf("failed to remove stale worktree directory: %w", err)
+	}
+	fmt.Printf("Removed stale worktree directory %s\n", worktreeDir)
+	return nil
+}
+
+// fastForwardBase brings the local base branch up to date with its upstream
+// for ff_base_before_create, so new branches don't start stale. Problems are
+// only warnings: the branch is then created from the local base as it is.
+func fastForwardBase(gitRepo git.Backend, base string) {
+	result, err := gitRepo.FastForwardBranch(base)
+	switch {
+	case err != nil:
+		printWarning("couldn't fast-forward %s: %v", base, err)
+	case result.Ahead > 0:
+		printWarning("%s has %d commit(s) that aren't on %s - not fast-forwarding it", base, result.Ahead, result.Upstream)
+	case result.Updated:
+		fmt.Printf("Fast-forwarded %s to %s (%d new commit(s))\n", base, result.Upstream, result.Behind)
+	}
+}
+
+// trackRemote is the remote whose copy of the base branch new branches track
+const trackRemote = "origin"
+
+// trackBaseBranch sets branchName's upstream to the copy of base on remote
+// (trackRemote when empty). A failure is only a warning since the branch
+// itself was created.
+func trackBaseBranch(gitRepo git.Backend, branchName, remote, base string) {
+	if remote == "" {
+		remote = trackRemote
+	}
+	upstream, err := gitRepo.SetUpstream(branchName, remote, base)
+	if err != nil {
+		printWarning("couldn't set upstream for %s: %v", branchName, err)
+		return
+	}
+	fmt.Printf("Branch %s tracks %s\n", branchName, upstream)
+}
+
+// openCodeRepo opens the git repository for a code repo's configured directory,
+// reporting a missing directory separately from one that isn't a git repository
+// or one without any commits yet
+func openCodeRepo(repoName, dir string) (*git.Repository, error) {
+	info, err := os.Stat(dir)
+	if err != nil {
+		if os.IsNotExist(err) {
+			return nil, fmt.Errorf("directory %s for repository '%s' does not exist - check 'directory' in your config", dir, repoName)
+		}
+		return nil, fmt.Errorf("couldn't access directory %s for repository '%s': %w", dir, repoName, err)
+	}
+	if !info.IsDir() {
+		return nil, fmt.Errorf("%s (directory for repository '%s') is not a directory", dir, repoName)
+	}
+
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		return nil, fmt.Errorf("%s (directory for repository '%s') is not a git repository", dir, repoName)
+	}
+
+	// A freshly initialized repository has nothing to branch from
+	hasCommits, err := gitRepo.HasCommits()
+	if err != nil {
+		return nil, err
+	}
+	if !hasCommits {
+		return nil, fmt.Errorf("repository '%s' in %s has no commits; make an initial commit first (e.g. git commit --allow-empty -m \"Initial commit\")", repoName, dir)
+	}
+	return gitRepo, nil
+}
+
+// branchIssueID returns the id of issue, filed in repo, that goes into its
+// branch name: the issue number, or for GitLab with gitlab_branch_id: id the
+// instance-wide issue id
+func branchIssueID(cfg *config.Settings, repo *config.Repository, issue *services.IssueResult) (string, error) {
+	if repo.GitlabRepo == "" {
+		return strconv.Itoa(issue.Number), nil
+	}
+	idKind, err := cfg.ResolveGitlabBranchID(repo)
+	if err != nil {
+		return "", err
+	}
+	if idKind == config.GitlabBranchIDIID {
+		return strconv.Itoa(issue.Number), nil
+	}
+	if issue.ID == 0 {
+		return "", fmt.Errorf("GitLab didn't return the id of issue #%d, which gitlab_branch_id: id needs for the branch name", issue.Number)
+	}
+	return strconv.Itoa(issue.ID), nil
+}
+
+// recordBranchIssue records the issue number of branch in git config when
+// its name carries another id (gitlab_branch_id: id), so the commands that
+// read the issue from the branch name find the right one. Failing to is only
+// a warning.
+func recordBranchIssue(gitRepo git.Backend, branch, issueID string, number int) {
+	if issueID == strconv.Itoa(number) {
+		return
+	}
+	if err := gitRepo.SetConfigValue(utils.BranchIssueConfigKey(branch), strconv.Itoa(number)); err != nil {
+		printWarning("couldn't record issue #%d for branch %s: %v", number, branch, err)
+	}
+}
+
+// branchNameFor renders the branch name for an issue from the repo's branch_template
+func branchNameFor(gitRepo git.Backend, cfg *config.Settings, repo *config.Repository, vars utils.BranchNameVars) (string, error) {
+	tmpl, err := cfg.ResolveBranchTemplate(repo)
+	if err != nil {
+		return "", err
+	}
+	vars.Title = utils.SanitizeTitle(vars.Title)
+	if !cfg.ResolveBranchIncludeSlug(repo) {
+		vars.Title = ""
+	} else if cfg.ResolveSlugTransliterate(repo) {
+		vars.Title = utils.Transliterate(vars.Title)
+	}
+	if strings.Contains(tmpl, "{user}") {
+		if vars.User, err = branchUser(gitRepo); err != nil {
+			return "", err
 		}
+	}
+	vars.MaxLength = cfg.ResolveBranchMaxLength(repo)
+	return utils.RenderBranchName(tmpl, vars), nil
+}
+
+// branchUser returns the {user} value for branch templates from git config,
+// preferring the user.email local part over user.name
+func branchUser(gitRepo git.Backend) (string, error) {
+	for _, key := range []string{"user.email", "user.name"} {
+		value, err := gitRepo.ConfigValue(key)
+		if err != nil {
+			return "", err
+		}
+		if user := utils.SanitizeBranchUser(value); user != "" {
+			return user, nil
+		}
+	}
+	return "", fmt.Errorf("branch_template uses {user} but neither git config user.email nor user.name is set")
+}
+
+// createLocalBranch creates branchName from base, or from HEAD when base is empty
+func createLocalBranch(gitRepo git.Backend, branchName, base string) error {
+	if base == "" {
+		return gitRepo.CreateBranch(branchName)
+	}
+	return gitRepo.CreateBranchFrom(branchName, base)
+}
+
+// branchStartPoint returns what a new branch is created from. A base branch
+// checked out in a worktree is resolved to its commit, since work in that
+// worktree moves the branch on; other bases, including "" for HEAD, are used
+// as they are.
+func branchStartPoint(gitRepo git.Backend, base string) (string, error) {
+	if base == "" || !gitRepo.BranchExists(base) {
+		return base, nil
+	}
+	worktreeDir, err := gitRepo.WorktreePathForBranch(base)
+	if err != nil || worktreeDir == "" {
+		return base, err
+	}
+	commit, err := gitRepo.ResolveCommit(base)
+	if err != nil {
+		return "", err
+	}
+	logger.Debug("Base branch is checked out in a worktree, branching from its commit", map[string]interface{}{
+		"base":     base,
+		"worktree": worktreeDir,
+		"commit":   commit,
+	})
+	return commit, nil
+}
 
-		fmt.Printf("Created and checked out branch: %s\n", branchName)
+// checkBranchNotCheckedOut fails when branchName already exists and is
+// checked out in a worktree. Creating it again would move the branch out
+// from under that worktree, and git allows a branch in only one worktree.
+func checkBranchNotCheckedOut(gitRepo git.Backend, branchName string) error {
+	if !gitRepo.BranchExists(branchName) {
+		return nil
+	}
+	existing, err := gitRepo.WorktreePathForBranch(branchName)
+	if err != nil {
+		return err
+	}
+	if existing != "" {
+		return fmt.Errorf("branch %s is already checked out in %s - use that worktree, or remove it with 'tix cleanup'", branchName, existing)
 	}
+	return nil
+}
 
+// headBase is the --base value that bases the new branch on the commit
+// that's checked out, e.g. to stack work on an existing feature branch
+const headBase = "HEAD"
+
+// checkHeadBase rejects the flags that need a base branch when the new
+// branch is based on the current commit (--base HEAD or --from-current)
+func checkHeadBase(cmd *cobra.Command, fromCurrent bool, base, remote string) error {
+	if fromCurrent && base != "" && base != headBase {
+		return fmt.Errorf("--from-current cannot be used with --base %s", base)
+	}
+	if !fromCurrent && base != headBase {
+		return nil
+	}
+	if remote != "" {
+		return fmt.Errorf("--base HEAD cannot be used with --base-remote")
+	}
+	if track, _ := cmd.Flags().GetBool("track"); track && cmd.Flags().Changed("track") {
+		return fmt.Errorf("--track cannot be used with --base HEAD - a commit has no remote branch to track")
+	}
+	return nil
+}
+
+// resolveHeadBase returns the commit checked out in the code repository, for
+// branches based on the current commit
+func resolveHeadBase(gitRepo git.Backend) (string, error) {
+	commit, err := gitRepo.HeadCommit()
+	if err != nil {
+		return "", err
+	}
+	fmt.Printf("Basing the new branch on the current commit %.7s\n", commit)
+	return commit, nil
+}
+
+// checkBaseTag rejects the other ways of choosing a base alongside --base-tag,
+// and --track, since a tag has no remote branch to track
+func checkBaseTag(cmd *cobra.Command, tag string, fromCurrent bool, base, remote string) error {
+	if tag == "" {
+		return nil
+	}
+	switch {
+	case base != "":
+		return fmt.Errorf("--base-tag cannot be used with --base")
+	case fromCurrent:
+		return fmt.Errorf("--base-tag cannot be used with --from-current")
+	case remote != "":
+		return fmt.Errorf("--base-tag cannot be used with --base-remote")
+	}
+	if track, _ := cmd.Flags().GetBool("track"); track && cmd.Flags().Changed("track") {
+		return fmt.Errorf("--track cannot be used with --base-tag - a tag has no remote branch to track")
+	}
+	return nil
+}
+
+// resolveBaseTag returns the commit tag points at, peeling annotated tags, for
+// --base-tag. Only tags are looked up, so a branch with the same name is never
+// picked instead.
+func resolveBaseTag(gitRepo git.Backend, tag string) (string, error) {
+	commit, err := gitRepo.ResolveCommit("refs/tags/" + tag)
+	if err != nil {
+		return "", fmt.Errorf("tag %s not found - list tags with 'git tag', or fetch them with 'git fetch --tags'", tag)
+	}
+	logger.Debug("Basing the new branch on a tag", map[string]interface{}{
+		"tag":    tag,
+		"commit": commit,
+	})
+	return commit, nil
+}
+
+// resolveBaseBranch picks the branch new work is based on. Resolution order:
+// --base > configured default branch > the only one of main/master that exists.
+// When both exist the user is asked to pick (or, non-interactively, to pass --base).
+func resolveBaseBranch(gitRepo git.Backend, cfg *config.Settings, repo *config.Repository, baseFlag string, nonInteractive bool) (string, error) {
+	if baseFlag != "" {
+		return baseFlag, nil
+	}
+	if configured := cfg.ConfiguredDefaultBranch(repo); configured != "" {
+		if !gitRepo.RevisionExists(configured) {
+			return "", missingDefaultBranchError(gitRepo, repo, configured)
+		}
+		return configured, nil
+	}
+
+	candidates, err := gitRepo.BaseBranchCandidates()
+	if err != nil {
+		return "", err
+	}
+	switch len(candidates) {
+	case 0:
+		return cfg.ResolveDefaultBranch(repo), nil
+	case 1:
+		return candidates[0], nil
+	}
+
+	if nonInteractive {
+		return "", fmt.Errorf("can't tell which base branch to use (found %s) - pass --base or set default_branch for %s", strings.Join(candidates, " and "), repo.Name)
+	}
+
+	branches, err := gitRepo.LocalBranches()
+	if err != nil {
+		return "", err
+	}
+	selected, err := pterm.DefaultInteractiveSelect.
+		WithOptions(branches).
+		WithDefaultText("No default_branch configured - select the base branch").
+		WithDefaultOption(candidates[0]).
+		Show()
+	if err != nil {
+		return "", fmt.Errorf("base branch selection cancelled")
+	}
+	return selected, nil
+}
+
+// resolveRemoteBaseBranch picks the remote-tracking branch new work is based
+// on for --base-remote: <remote>/<--base>, else <remote>/<configured default
+// branch>, else whichever of <remote>/main and <remote>/master exists. Only
+// already-fetched refs are used; nothing is fetched.
+func resolveRemoteBaseBranch(gitRepo git.Backend, cfg *config.Settings, repo *config.Repository, baseFlag, remote string) (string, error) {
+	names := []string{strings.TrimPrefix(baseFlag, remote+"/")}
+	if baseFlag == "" {
+		if configured := cfg.ConfiguredDefaultBranch(repo); configured != "" {
+			names = []string{configured}
+		} else {
+			names = []string{"main", "master"}
+		}
+	}
+
+	for _, name := range names {
+		if gitRepo.RevisionExists("refs/remotes/" + remote + "/" + name) {
+			return remote + "/" + name, nil
+		}
+	}
+	if len(names) == 1 {
+		return "", fmt.Errorf("remote-tracking branch %s/%s doesn't exist - run 'git fetch %s %s' or check --base-remote", remote, names[0], remote, names[0])
+	}
+	return "", fmt.Errorf("neither %s/main nor %s/master exists - run 'git fetch %s', or pass --base or set default_branch for %s", remote, remote, remote, repo.Name)
+}
+
+// missingDefaultBranchError explains a default_branch that doesn't exist in the
+// code repository (e.g. main configured in a repo still on master), listing
+// the local branches to pick from
+func missingDefaultBranchError(gitRepo git.Backend, repo *config.Repository, branch string) error {
+	branches, err := gitRepo.LocalBranches()
+	if err != nil || len(branches) == 0 {
+		return fmt.Errorf("default_branch '%s' for %s doesn't exist in %s - update default_branch in your config or pass --base", branch, repo.Name, repo.Directory)
+	}
+	return fmt.Errorf("default_branch '%s' for %s doesn't exist in %s (local branches: %s) - update default_branch in your config or pass --base", branch, repo.Name, repo.Directory, strings.Join(branches, ", "))
+}
+
+// resolveWorktreeDir returns the directory for a new worktree, rendered from
+// the configured worktree path template
+func resolveWorktreeDir(cfg *config.Settings, repo *config.Repository, issueID string, issueTitle string, branchName string) (string, error) {
+	if cfg.ResolveSlugTransliterate(repo) {
+		issueTitle = utils.Transliterate(issueTitle)
+	}
+	return cfg.ResolveWorktreeDir(repo, config.WorktreePathVars{
+		ID:     issueID,
+		Slug:   utils.TruncateAndDashCase(issueTitle, 50),
+		Branch: branchName,
+		Repo:   repo.Name,
+	})
+}
+
+// validateWorktreeDir checks a --worktree-dir path and returns it made
+// absolute. It must be outside the repository at repoDir, and it (or its
+// closest existing parent, as missing directories are created) must be a
+// writable directory.
+func validateWorktreeDir(dir, repoDir string) (string, error) {
+	abs, err := filepath.Abs(dir)
+	if err != nil {
+		return "", fmt.Errorf("invalid --worktree-dir %s: %w", dir, err)
+	}
+	if repoAbs, err := filepath.Abs(repoDir); err == nil {
+		rel, err := filepath.Rel(repoAbs, abs)
+		if err == nil && rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+			return "", fmt.Errorf("--worktree-dir %s is inside the repository at %s - pick a directory outside it", abs, repoAbs)
+		}
+	}
+
+	if err := checkWritableDir(abs); err != nil {
+		return "", fmt.Errorf("--worktree-dir %w", err)
+	}
+	return abs, nil
+}
+
+// checkWritableDir checks that dir, or its closest existing parent when dir
+// doesn't exist yet, is a directory files can be created in
+func checkWritableDir(dir string) error {
+	existing := dir
+	for {
+		info, err := os.Stat(existing)
+		if err == nil {
+			if !info.IsDir() {
+				return fmt.Errorf("%s: %s is not a directory", dir, existing)
+			}
+			break
+		}
+		parent := filepath.Dir(existing)
+		if parent == existing {
+			return fmt.Errorf("%s: %w", dir, err)
+		}
+		existing = parent
+	}
+
+	probe, err := os.CreateTemp(existing, ".tix-write-check-")
+	if err != nil {
+		return fmt.Errorf("%s isn't writable: %w", dir, err)
+	}
+	probe.Close()
+	os.Remove(probe.Name())
 	return nil
 }
 
 func init() {
 	rootCmd.AddCommand(createCmd)
 	createCmd.Flags().StringVarP(&title, "title", "t", "", "Title of the issue")
+	createCmd.Flags().StringVar(&adHocGithub, "github-repo", "", "File the issue in this GitHub repository (owner/name) without a config file entry")
+	createCmd.Flags().StringVar(&adHocGitlab, "gitlab-repo", "", "File the issue in this GitLab project (group/project) without a config file entry")
+	createCmd.Flags().StringVar(&adHocDir, "dir", "", "With --github-repo or --gitlab-repo, the checkout to create the branch in (default: the current directory)")
+	createCmd.Flags().BoolVar(&titleFromClip, "title-from-clipboard", false, "Use the text on the clipboard as the title, joined into one line")
 	createCmd.Flags().BoolVarP(&selfAssign, "assign", "a", true, "Assign the issue to yourself")
-	createCmd.Flags().BoolVarP(&useWorktree, "worktree", "w", false, "Create a git worktree instead of checking out a branch")
+	createCmd.Flags().StringSliceVar(&assignees, "assignee", nil, "Assign the issue to these users; 'me' or '@me' is you (repeatable or comma separated)")
+	createCmd.Flags().BoolVarP(&useWorktree, "worktree", "w", false, "Create a git worktree instead of checking out a branch (default: worktree.enabled)")
+	createCmd.Flags().StringVar(&worktreeDirArg, "worktree-dir", "", "With --worktree, create the worktree in this directory instead of the configured path (outside the repository)")
+	createCmd.Flags().BoolVar(&forceWorktree, "force", false, "With --worktree, remove a directory left over from a deleted worktree without asking (never one with uncommitted files)")
+	createCmd.Flags().BoolVar(&reuseBranch, "reuse", false, "With --worktree, check out the branch in a new worktree if it already exists instead of failing")
 	createCmd.Flags().BoolVar(&noAutoStash, "no-auto-stash", false, "Disable automatic stashing of uncommitted changes before branch creation")
 	createCmd.Flags().BoolVarP(&nonInteractive, "non-interactive", "n", false, "Skip all interactive prompts and use defaults (requires -t/--title)")
+	createCmd.Flags().BoolVar(&offline, "offline", false, "Create the branch with a placeholder ID and queue the issue for 'tix sync' instead of filing it now")
+	createCmd.Flags().BoolVar(&noBranch, "no-branch", false, "Only file the issue: skip all git operations (no branch, checkout or worktree)")
+	createCmd.Flags().BoolVar(&branchOnly, "branch-only", false, "Only create the branch (or worktree) named by --name, off the base branch: no issue and no provider calls")
+	createCmd.Flags().StringVar(&branchOnlyName, "name", "", "Branch name for --branch-only, used as is")
+	createCmd.Flags().BoolVar(&noCheckout, "no-checkout", false, "Create the branch without checking it out (leaves HEAD and the working tree untouched)")
+	createCmd.Flags().BoolVar(&confidential, "confidential", false, "Create a confidential issue (GitLab only; overrides default_confidential)")
+	createCmd.Flags().BoolVar(&closeIssue, "closed", false, "Close the issue right after creating it, e.g. to backfill finished work (usually with --no-branch)")
+	createCmd.Flags().BoolVar(&lockIssue, "lock", false, "Lock the issue's conversation (GitHub) or discussion (GitLab) after creating it")
+	createCmd.Flags().StringVar(&issueTemplate, "template", "", "Issue template to use as the description (overrides issue_template)")
+	createCmd.Flags().IntVar(&estimate, "estimate", 0, "Estimate for the issue: the weight on GitLab, a label from estimate_label_template (default estimate/{n}) on GitHub")
+	createCmd.Flags().StringVar(&batchFile, "batch", "", "Create every issue in this file (a YAML list, or one 'title | labels | assignees' per line); branches only for items with branch: true")
+	createCmd.Flags().StringVar(&fromFile, "from-file", "", "Create the issue from a markdown file: the front-matter title or first heading is the title, front-matter labels/assignees are added, and the rest is the description")
+	createCmd.Flags().StringVar(&issueBody, "body", "", "Issue description (overrides the issue template); GitLab quick actions in it are applied")
+	createCmd.Flags().StringArrayVar(&templateVars, "template-var", nil, "Fill a {{name}} placeholder in the description template, as name=value (repeatable)")
+	createCmd.Flags().StringArrayVar(&quickActions, "quick-action", nil, "GitLab quick action to append to the description, e.g. '/label ~bug' (repeatable)")
+	createCmd.Flags().StringArrayVar(&participants, "external-participant", nil, "Email address to add to the issue as an external participant, e.g. a support request's reporter (GitLab only; repeatable)")
+	createCmd.Flags().StringArrayVar(&attachments, "attach", nil, "File to upload and link from the issue description, e.g. a screenshot or log (GitLab only; repeatable)")
+	createCmd.Flags().StringVar(&projectBoard, "project", "", "GitHub project (v2) to add the issue to, by number or title")
+	createCmd.Flags().StringVar(&groupMilestone, "group-milestone", "", "GitLab group milestone to set, by title; must exist in the project's group or its ancestors (skips the milestone prompt)")
+	createCmd.Flags().BoolVar(&iKnow, "i-know", false, "Create the issue in a protected repository without asking for confirmation")
+	createCmd.Flags().BoolVar(&dryRun, "dry-run", false, "Print the API request that would create the issue (method, URL, headers with the token redacted, and JSON body) instead of sending it")
+	createCmd.Flags().BoolVar(&checkProtect, "check-protection", false, "After creating the branch, warn if the default branch is protected against direct pushes (overrides check_branch_protection)")
+	createCmd.Flags().BoolVar(&createLabels, "create-labels", false, "With confirm_new_labels, create labels the project doesn't have yet without asking")
+	createCmd.Flags().BoolVar(&checkRemote, "check-remote", false, "Validate the token, repository, labels, assignees and milestone against the provider without creating anything")
+	createCmd.Flags().BoolVar(&printBranch, "print-branch", false, "Print only the branch name to stdout (other output goes to stderr), for scripts")
+	createCmd.Flags().BoolVar(&copyURL, "copy-url", false, "Copy the created issue's URL to the clipboard")
+	createCmd.Flags().StringVar(&baseBranch, "base", "", "Branch to base the new branch on, or HEAD for the current commit (default: default_branch, or detected from main/master)")
+	createCmd.Flags().StringVar(&baseTag, "base-tag", "", "Base the new branch on the commit of this tag (annotated or lightweight), e.g. v1.2.3 for a hotfix")
+	createCmd.Flags().BoolVar(&fromCurrent, "from-current", false, "Base the new branch on the current commit, like --base HEAD (e.g. to stack on a feature branch)")
+	createCmd.Flags().StringVar(&baseRemote, "base-remote", "", "Base the new branch on this remote's copy of the base branch (e.g. origin/main) instead of the local one")
+	createCmd.Flags().BoolVar(&trackUpstream, "track", false, "Set the new branch's upstream to the remote base branch, e.g. origin/main (overrides track_upstream)")
+	createCmd.Flags().IntVar(&labelsFrom, "labels-from-issue", 0, "Copy the labels of this issue in the issue repository (except the ready/unready labels), merged with the other labels")
+	createCmd.Flags().StringSliceVar(&labelFlags, "label", nil, "Add these labels to the issue instead of prompting (repeatable or comma separated)")
+	createCmd.Flags().StringVar(&rotation, "rotation", "", "Assign the issue to the member whose turn it is in this rotation from the rotations setting")
+	createCmd.Flags().StringArrayVar(&presets, "preset", nil, "Add the labels of this preset from the presets setting, e.g. hotfix (repeatable)")
+	createCmd.Flags().StringSliceVar(&noLabels, "no-label", nil, "Leave this label off the issue, wherever it came from, e.g. one of default_labels (repeatable or comma separated)")
+	createCmd.Flags().BoolVar(&labelsAppend, "labels-append", false, "Add --label and --from-file labels to default_labels (the default)")
+	createCmd.Flags().BoolVar(&labelsReplace, "labels-replace", false, "Ignore default_labels and use only the labels from --label, --from-file and --type")
+	createCmd.Flags().BoolVar(&suggestLabels, "interactive-labels", false, "Pre-fill the labels prompt with labels matched from title keywords via keyword_label_map (overrides interactive_labels)")
+	createCmd.Flags().StringVar(&branchType, "type", "", "Branch type prefix (e.g. feature, bug); adds the label mapped in branch_type_labels")
 }
@@ -1,10 +1,24 @@
 package cmd
 
 import (
+	"bytes"
+	"fmt"
+	"io"
+	"net"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"reflect"
+	"regexp"
 	"strings"
 	"testing"
 
+	"github.com/spf13/cobra"
 	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/state"
+	"github.com/tedkulp/tix/internal/utils"
 )
 
 func TestCreateNoAutoStashFlag(t *testing.T) {
@@ -35,6 +49,423 @@ func TestCreateNonInteractiveFlag(t *testing.T) {
 	}
 }
 
+func TestCreateTypeFlag(t *testing.T) {
+	flag := createCmd.Flags().Lookup("type")
+	if flag == nil {
+		t.Fatal("expected --type flag to be registered on createCmd")
+	}
+	if flag.DefValue != "" {
+		t.Errorf("expected empty default, got %q", flag.DefValue)
+	}
+	if flag.Usage == "" {
+		t.Error("expected --type flag to have a usage description")
+	}
+}
+
+func TestCreateConfidentialAndTemplateFlags(t *testing.T) {
+	confidentialFlag := createCmd.Flags().Lookup("confidential")
+	if confidentialFlag == nil {
+		t.Fatal("expected --confidential flag to be registered on createCmd")
+	}
+	if confidentialFlag.DefValue != "false" {
+		t.Errorf("expected --confidential default false, got %q", confidentialFlag.DefValue)
+	}
+
+	templateFlag := createCmd.Flags().Lookup("template")
+	if templateFlag == nil {
+		t.Fatal("expected --template flag to be registered on createCmd")
+	}
+	if templateFlag.DefValue != "" {
+		t.Errorf("expected empty --template default, got %q", templateFlag.DefValue)
+	}
+}
+
+func TestCreateCopyURLFlag(t *testing.T) {
+	flag := createCmd.Flags().Lookup("copy-url")
+	if flag == nil {
+		t.Fatal("expected --copy-url flag to be registered on createCmd")
+	}
+	if flag.DefValue != "false" {
+		t.Errorf("expected --copy-url default false, got %q", flag.DefValue)
+	}
+}
+
+func TestTrackFlags(t *testing.T) {
+	for _, c := range []*cobra.Command{createCmd, startCmd} {
+		flag := c.Flags().Lookup("track")
+		if flag == nil {
+			t.Fatalf("expected --track flag to be registered on %s", c.Name())
+		}
+		if flag.DefValue != "false" {
+			t.Errorf("expected %s --track default false, got %q", c.Name(), flag.DefValue)
+		}
+	}
+}
+
+func TestPrintBranchFlags(t *testing.T) {
+	for _, c := range []*cobra.Command{createCmd, startCmd} {
+		if c.Flags().Lookup("print-branch") == nil {
+			t.Errorf("expected --print-branch flag to be registered on %s", c.Name())
+		}
+	}
+}
+
+func TestStdoutForScripting(t *testing.T) {
+	orig := os.Stdout
+	stdout, restore := stdoutForScripting()
+	if stdout != orig {
+		t.Error("expected the real stdout to be returned")
+	}
+	if os.Stdout != os.Stderr {
+		t.Error("expected os.Stdout to point at stderr while redirected")
+	}
+	restore()
+	if os.Stdout != orig {
+		t.Error("expected os.Stdout to be restored")
+	}
+}
+
+func TestCreatePrintBranchRequiresTitle(t *testing.T) {
+	origPrintBranch, origTitle := printBranch, title
+	defer func() { printBranch, title = origPrintBranch, origTitle }()
+
+	printBranch = true
+	title = ""
+
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil || !strings.Contains(err.Error(), "--print-branch requires -t/--title") {
+		t.Errorf("expected --print-branch to require a title, got %v", err)
+	}
+}
+
+// newTestGitDir returns a temporary git repository with one commit on main
+func newTestGitDir(t *testing.T) string {
+	t.Helper()
+	dir := t.TempDir()
+	for _, args := range [][]string{
+		{"init"},
+		{"config", "user.email", "test@test.com"},
+		{"config", "user.name", "Test"},
+		{"commit", "--allow-empty", "-m", "initial commit"},
+		{"branch", "-M", "main"},
+	} {
+		gitCmd := exec.Command("git", args...)
+		gitCmd.Dir = dir
+		if out, err := gitCmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+	}
+	return dir
+}
+
+func TestCreatePrintBranchStdoutIsBranchOnly(t *testing.T) {
+	dir := newTestGitDir(t)
+
+	configPath := filepath.Join(t.TempDir(), "tix.yml")
+	configYAML := "repositories:\n  - name: app\n    github_repo: owner/app\n    directory: " + dir + "\n"
+	if err := os.WriteFile(configPath, []byte(configYAML), 0600); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv("TIX_CONFIG", configPath)
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+	t.Setenv("GITHUB_TOKEN", "")
+
+	wd, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chdir(dir); err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = os.Chdir(wd) }()
+
+	origTitle, origOffline, origPrintBranch, origNonInteractive := title, offline, printBranch, nonInteractive
+	defer func() {
+		title, offline, printBranch, nonInteractive = origTitle, origOffline, origPrintBranch, origNonInteractive
+	}()
+	title = "Pipe safe"
+	offline = true
+	printBranch = true
+
+	r, w, err := os.Pipe()
+	if err != nil {
+		t.Fatal(err)
+	}
+	origStdout := os.Stdout
+	os.Stdout = w
+	runErr := createCmd.RunE(createCmd, []string{})
+	os.Stdout = origStdout
+	_ = w.Close()
+	out, err := io.ReadAll(r)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if runErr != nil {
+		t.Fatalf("create --offline --print-branch error: %v", runErr)
+	}
+	if !regexp.MustCompile(`^tmp-\d{14}-pipe-safe\n$`).Match(out) {
+		t.Errorf("expected stdout to hold only the branch name, got %q", out)
+	}
+}
+
+func TestCreateDryRunPrintBranch(t *testing.T) {
+	dir := newTestGitDir(t)
+
+	configPath := filepath.Join(t.TempDir(), "tix.yml")
+	configYAML := "repositories:\n  - name: app\n    github_repo: owner/app\n    directory: " + dir + "\n"
+	if err := os.WriteFile(configPath, []byte(configYAML), 0600); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv("TIX_CONFIG", configPath)
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+	t.Setenv("GITHUB_TOKEN", "test-token")
+
+	wd, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chdir(dir); err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = os.Chdir(wd) }()
+
+	origTitle, origDryRun, origPrintBranch, origNonInteractive := title, dryRun, printBranch, nonInteractive
+	defer func() {
+		title, dryRun, printBranch, nonInteractive = origTitle, origDryRun, origPrintBranch, origNonInteractive
+	}()
+	title = "Pipe safe"
+	dryRun = true
+	printBranch = true
+
+	r, w, err := os.Pipe()
+	if err != nil {
+		t.Fatal(err)
+	}
+	origStdout := os.Stdout
+	os.Stdout = w
+	runErr := createCmd.RunE(createCmd, []string{})
+	os.Stdout = origStdout
+	_ = w.Close()
+	out, err := io.ReadAll(r)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	if runErr != nil {
+		t.Fatalf("create --dry-run --print-branch error: %v", runErr)
+	}
+	if !regexp.MustCompile(`^tmp-\d{14}-pipe-safe\n$`).Match(out) {
+		t.Errorf("expected stdout to hold only the branch name, got %q", out)
+	}
+
+	branches, err := exec.Command("git", "-C", dir, "branch", "--list", "tmp-*").Output()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(branches) != 0 {
+		t.Errorf("dry run created a branch: %s", branches)
+	}
+}
+
+func TestCheckHeadBase(t *testing.T) {
+	newCmd := func(args ...string) *cobra.Command {
+		c := &cobra.Command{}
+		c.Flags().Bool("track", false, "")
+		if err := c.Flags().Parse(args); err != nil {
+			t.Fatal(err)
+		}
+		return c
+	}
+
+	tests := []struct {
+		name        string
+		args        []string
+		fromCurrent bool
+		base        string
+		remote      string
+		wantErr     string
+	}{
+		{name: "base HEAD", base: "HEAD"},
+		{name: "from-current", fromCurrent: true},
+		{name: "from-current with base HEAD", fromCurrent: true, base: "HEAD"},
+		{name: "other base", base: "develop", remote: "upstream", args: []string{"--track"}},
+		{name: "from-current with another base", fromCurrent: true, base: "develop", wantErr: "--from-current cannot be used with --base develop"},
+		{name: "base-remote", base: "HEAD", remote: "upstream", wantErr: "--base-remote"},
+		{name: "track", fromCurrent: true, args: []string{"--track"}, wantErr: "--track cannot be used"},
+		{name: "track disabled", fromCurrent: true, args: []string{"--track=false"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := checkHeadBase(newCmd(tt.args...), tt.fromCurrent, tt.base, tt.remote)
+			if tt.wantErr == "" {
+				if err != nil {
+					t.Errorf("checkHeadBase() error: %v", err)
+				}
+				return
+			}
+			if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+				t.Errorf("checkHeadBase() error = %v, want %q", err, tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestResolveBaseBranchMissingDefault(t *testing.T) {
+	dir := newTestGitDir(t)
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	cfg := &config.Settings{}
+
+	repo := &config.Repository{Name: "app", Directory: dir, DefaultBranch: "master"}
+	_, err = resolveBaseBranch(gitRepo, cfg, repo, "", true)
+	if err == nil {
+		t.Fatal("expected an error for a default_branch that doesn't exist")
+	}
+	for _, want := range []string{"default_branch 'master'", "local branches: main", "--base"} {
+		if !strings.Contains(err.Error(), want) {
+			t.Errorf("expected error to mention %q, got: %v", want, err)
+		}
+	}
+
+	repo.DefaultBranch = "main"
+	if base, err := resolveBaseBranch(gitRepo, cfg, repo, "", true); err != nil || base != "main" {
+		t.Errorf("resolveBaseBranch() = %q, %v; want main", base, err)
+	}
+}
+
+func TestResolveBaseTag(t *testing.T) {
+	dir := newTestGitDir(t)
+	runGit := func(args ...string) string {
+		gitCmd := exec.Command("git", args...)
+		gitCmd.Dir = dir
+		out, err := gitCmd.CombinedOutput()
+		if err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+		return strings.TrimSpace(string(out))
+	}
+	release := runGit("rev-parse", "HEAD")
+	runGit("tag", "-a", "v1.2.3", "-m", "Release 1.2.3")
+	runGit("tag", "v1.2.3-light")
+	// A branch named like a tag doesn't shadow it
+	runGit("commit", "--allow-empty", "-m", "after the release")
+	runGit("branch", "v1.2.4")
+	runGit("tag", "v1.2.4", release)
+
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, tag := range []string{"v1.2.3", "v1.2.3-light", "v1.2.4"} {
+		if commit, err := resolveBaseTag(gitRepo, tag); err != nil || commit != release {
+			t.Errorf("resolveBaseTag(%s) = %q, %v; want %s", tag, commit, err, release)
+		}
+	}
+	if _, err := resolveBaseTag(gitRepo, "v9.9.9"); err == nil || !strings.Contains(err.Error(), "tag v9.9.9 not found") {
+		t.Errorf("resolveBaseTag() of a missing tag error = %v", err)
+	}
+}
+
+func TestCheckBaseTag(t *testing.T) {
+	track := &cobra.Command{}
+	track.Flags().Bool("track", false, "")
+	if err := track.Flags().Parse([]string{"--track"}); err != nil {
+		t.Fatal(err)
+	}
+
+	if err := checkBaseTag(&cobra.Command{}, "v1.2.3", false, "", ""); err != nil {
+		t.Errorf("checkBaseTag() error: %v", err)
+	}
+	for _, tt := range []struct {
+		cmd         *cobra.Command
+		fromCurrent bool
+		base        string
+		remote      string
+		wantErr     string
+	}{
+		{cmd: &cobra.Command{}, base: "main", wantErr: "--base-tag cannot be used with --base"},
+		{cmd: &cobra.Command{}, fromCurrent: true, wantErr: "--from-current"},
+		{cmd: &cobra.Command{}, remote: "upstream", wantErr: "--base-remote"},
+		{cmd: track, wantErr: "--track cannot be used with --base-tag"},
+	} {
+		err := checkBaseTag(tt.cmd, "v1.2.3", tt.fromCurrent, tt.base, tt.remote)
+		if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+			t.Errorf("checkBaseTag() error = %v, want %q", err, tt.wantErr)
+		}
+	}
+}
+
+func TestOpenCodeRepo(t *testing.T) {
+	missing := filepath.Join(t.TempDir(), "missing")
+	_, err := openCodeRepo("app", missing)
+	if err == nil || !strings.Contains(err.Error(), "does not exist") {
+		t.Errorf("expected missing directory error, got %v", err)
+	}
+
+	notGit := t.TempDir()
+	_, err = openCodeRepo("app", notGit)
+	if err == nil || !strings.Contains(err.Error(), "is not a git repository") {
+		t.Errorf("expected not a git repository error, got %v", err)
+	}
+
+	empty := t.TempDir()
+	if out, err := exec.Command("git", "init", empty).CombinedOutput(); err != nil {
+		t.Fatalf("git init: %v\n%s", err, out)
+	}
+	_, err = openCodeRepo("app", empty)
+	if err == nil || !strings.Contains(err.Error(), "has no commits; make an initial commit first") {
+		t.Errorf("expected no commits error, got %v", err)
+	}
+
+	if _, err := openCodeRepo("app", newTestGitDir(t)); err != nil {
+		t.Errorf("openCodeRepo() on a repository with commits: %v", err)
+	}
+}
+
+func TestCreateNoCheckoutWithWorktree(t *testing.T) {
+	origNoCheckout := noCheckout
+	origWorktree := useWorktree
+	defer func() {
+		noCheckout = origNoCheckout
+		useWorktree = origWorktree
+	}()
+
+	noCheckout = true
+	useWorktree = true
+
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil {
+		t.Fatal("expected error when --no-checkout is combined with --worktree")
+	}
+	if !strings.Contains(err.Error(), "--no-checkout cannot be used with --worktree") {
+		t.Errorf("unexpected error: %s", err.Error())
+	}
+}
+
+func TestCreateNoBranchConflicts(t *testing.T) {
+	origNoBranch, origTitle := noBranch, title
+	defer func() {
+		noBranch, title = origNoBranch, origTitle
+		_ = createCmd.Flags().Set("worktree", "false")
+		createCmd.Flags().Lookup("worktree").Changed = false
+	}()
+
+	noBranch = true
+	title = "Just a ticket"
+	if err := createCmd.Flags().Set("worktree", "true"); err != nil {
+		t.Fatal(err)
+	}
+
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil || !strings.Contains(err.Error(), "--no-branch cannot be used with --worktree") {
+		t.Errorf("expected --no-branch to conflict with --worktree, got %v", err)
+	}
+}
+
 func TestCreateNonInteractiveRequiresTitle(t *testing.T) {
 	orig := nonInteractive
 	origTitle := title
@@ -55,6 +486,121 @@ func TestCreateNonInteractiveRequiresTitle(t *testing.T) {
 	}
 }
 
+func TestCreateTitleAndRepoFromEnv(t *testing.T) {
+	orig, origTitle := nonInteractive, title
+	defer func() { nonInteractive, title = orig, origTitle }()
+
+	configPath := filepath.Join(t.TempDir(), "tix.yml")
+	configYAML := "repositories:\n  - name: app\n    github_repo: owner/app\n    directory: " + t.TempDir() + "\n"
+	if err := os.WriteFile(configPath, []byte(configYAML), 0600); err != nil {
+		t.Fatal(err)
+	}
+	t.Setenv("TIX_CONFIG", configPath)
+	t.Setenv("TIX_TITLE", "From CI")
+	t.Setenv("TIX_REPO", "missing")
+
+	nonInteractive = true
+	title = ""
+
+	// TIX_TITLE satisfies --non-interactive, and TIX_REPO picks the issue repo
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil || !strings.Contains(err.Error(), "repository 'missing' not found in config") {
+		t.Errorf("expected TIX_REPO to select the issue repository, got %v", err)
+	}
+	if title != "From CI" {
+		t.Errorf("expected the title from TIX_TITLE, got %q", title)
+	}
+}
+
+func TestCreateCheckRemoteConflictsWithOffline(t *testing.T) {
+	origCheck, origOffline, origTitle := checkRemote, offline, title
+	defer func() {
+		checkRemote, offline, title = origCheck, origOffline, origTitle
+	}()
+
+	checkRemote = true
+	offline = true
+	title = "Preflight"
+
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil {
+		t.Fatal("expected error when --check-remote is combined with --offline")
+	}
+	if !strings.Contains(err.Error(), "--check-remote cannot be used with --offline") {
+		t.Errorf("unexpected error: %s", err.Error())
+	}
+}
+
+func TestSetupRepositoryOfflineNeedsNoToken(t *testing.T) {
+	t.Setenv("GITHUB_TOKEN", "")
+	cfg := &config.Settings{
+		Repositories: []config.Repository{
+			{Name: "app", GithubRepo: "owner/app", Directory: t.TempDir()},
+		},
+	}
+
+	origOffline, origNonInteractive := offline, nonInteractive
+	defer func() { offline, nonInteractive = origOffline, origNonInteractive }()
+	offline = true
+	nonInteractive = true
+
+	settings, err := setupRepository(cfg, "app", "")
+	if err != nil {
+		t.Fatalf("setupRepository() offline error: %v", err)
+	}
+	if settings.Provider != nil {
+		t.Error("expected no provider to be created for an offline run")
+	}
+}
+
+func TestCreateFromFileWithoutTitle(t *testing.T) {
+	origFromFile, origTitle := fromFile, title
+	defer func() { fromFile, title = origFromFile, origTitle }()
+
+	fromFile = filepath.Join(t.TempDir(), "draft.md")
+	if err := os.WriteFile(fromFile, []byte("---\nlabels: bug\n---\nNo heading here.\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	title = ""
+
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil || !strings.Contains(err.Error(), "no issue title found") {
+		t.Errorf("expected a missing title error, got %v", err)
+	}
+}
+
+func TestCreateNegativeEstimate(t *testing.T) {
+	origEstimate, origTitle := estimate, title
+	defer func() {
+		estimate, title = origEstimate, origTitle
+		_ = createCmd.Flags().Set("estimate", "0")
+		createCmd.Flags().Lookup("estimate").Changed = false
+	}()
+
+	title = "Estimate"
+	if err := createCmd.Flags().Set("estimate", "-1"); err != nil {
+		t.Fatal(err)
+	}
+
+	err := createCmd.RunE(createCmd, []string{})
+	if err == nil {
+		t.Fatal("expected error for a negative --estimate")
+	}
+	if !strings.Contains(err.Error(), "--estimate must be 0 or more") {
+		t.Errorf("unexpected error: %s", err.Error())
+	}
+}
+
+func TestAssigneesForDisplay(t *testing.T) {
+	got := assigneesForDisplay(services.IssueParams{SelfAssign: true, Assignees: []string{"alice"}})
+	if got != "me, alice" {
+		t.Errorf("assigneesForDisplay() = %q, want %q", got, "me, alice")
+	}
+	if got := assigneesForDisplay(services.IssueParams{}); got != "" {
+		t.Errorf("assigneesForDisplay() with no assignees = %q, want empty", got)
+	}
+}
+
 func TestCreateNonInteractiveAmbiguousRepo(t *testing.T) {
 	// setupRepository errors when nonInteractive=true, no issueRepoArg, no cwd match,
 	// and multiple repos exist. We test the helper directly.
@@ -78,3 +624,954 @@ func TestCreateNonInteractiveAmbiguousRepo(t *testing.T) {
 		t.Errorf("unexpected error: %s", err.Error())
 	}
 }
+
+// mockGitBackend records the git operations a command performs instead of
+// running them
+type mockGitBackend struct {
+	calls     []string
+	failOn    string            // Operation that returns an error, e.g. "CheckoutBranch"
+	branches  []string          // Existing local branches besides main
+	worktrees map[string]string // Branch -> worktree it's checked out in
+	revisions []string          // Revisions that exist besides main, e.g. "refs/remotes/origin/main"
+	prunable  []string          // Worktree paths git records whose directories were deleted
+	dirty     bool              // DirMatchesRevision finds uncommitted files
+}
+
+func (m *mockGitBackend) record(op string, args ...string) error {
+	m.calls = append(m.calls, strings.Join(append([]string{op}, args...), " "))
+	if op == m.failOn {
+		return fmt.Errorf("%s failed", op)
+	}
+	return nil
+}
+
+func (m *mockGitBackend) IsClean() (bool, error) { return true, m.record("IsClean") }
+func (m *mockGitBackend) Stash() error           { return m.record("Stash") }
+func (m *mockGitBackend) StashPop() error        { return m.record("StashPop") }
+func (m *mockGitBackend) CreateBranch(name string) error {
+	return m.record("CreateBranch", name)
+}
+func (m *mockGitBackend) CreateBranchFrom(name, base string) error {
+	return m.record("CreateBranchFrom", name, base)
+}
+func (m *mockGitBackend) CheckoutBranch(name string) error {
+	return m.record("CheckoutBranch", name)
+}
+func (m *mockGitBackend) SetUpstream(branch, remote, base string) (string, error) {
+	return remote + "/" + base, m.record("SetUpstream", branch, remote, base)
+}
+func (m *mockGitBackend) FastForwardBranch(branch string) (git.FastForwardResult, error) {
+	return git.FastForwardResult{Upstream: "origin/" + branch}, m.record("FastForwardBranch", branch)
+}
+func (m *mockGitBackend) LocalBranches() ([]string, error)        { return []string{"main"}, nil }
+func (m *mockGitBackend) BaseBranchCandidates() ([]string, error) { return []string{"main"}, nil }
+func (m *mockGitBackend) RevisionExists(rev string) bool {
+	for _, existing := range m.revisions {
+		if existing == rev {
+			return true
+		}
+	}
+	return rev == "main"
+}
+func (m *mockGitBackend) HeadCommit() (string, error) {
+	return "0123456789abcdef0123456789abcdef01234567", nil
+}
+func (m *mockGitBackend) ResolveCommit(rev string) (string, error) {
+	return "89abcdef0123456789abcdef0123456789abcdef", nil
+}
+func (m *mockGitBackend) BranchExists(name string) bool {
+	for _, branch := range m.branches {
+		if branch == name {
+			return true
+		}
+	}
+	return false
+}
+func (m *mockGitBackend) ConfigValue(key string) (string, error) {
+	if key == "user.email" {
+		return "tk@example.com", nil
+	}
+	return "", nil
+}
+func (m *mockGitBackend) SetConfigValue(key, value string) error {
+	return m.record("SetConfigValue", key, value)
+}
+func (m *mockGitBackend) AddWorktree(worktreePath, branchName, baseBranch string) error {
+	return m.record("AddWorktree", worktreePath, branchName, baseBranch)
+}
+func (m *mockGitBackend) AddWorktreeForBranch(worktreePath, branchName string) error {
+	return m.record("AddWorktreeForBranch", worktreePath, branchName)
+}
+func (m *mockGitBackend) WorktreePathForBranch(branchName string) (string, error) {
+	return m.worktrees[branchName], nil
+}
+func (m *mockGitBackend) WorktreeRegistered(path string) (bool, bool, error) {
+	for _, stale := range m.prunable {
+		if stale == path {
+			return true, true, nil
+		}
+	}
+	return false, false, nil
+}
+func (m *mockGitBackend) PruneWorktrees() error {
+	m.prunable = nil
+	return m.record("PruneWorktrees")
+}
+func (m *mockGitBackend) DirMatchesRevision(dir, rev string) (bool, error) {
+	return !m.dirty, m.record("DirMatchesRevision", dir, rev)
+}
+
+func TestCreateBranchOperations(t *testing.T) {
+	repo := &config.Repository{Name: "app", Directory: "/src/app"}
+	worktreeDir := filepath.Join("/src/app", ".worktrees", "42-fix-login")
+
+	tests := []struct {
+		name      string
+		opts      branchOptions
+		failOn    string
+		branches  []string
+		worktrees map[string]string
+		prunable  []string
+		wantCalls []string
+		wantErr   string
+	}{
+		{
+			name:      "checkout in place from HEAD",
+			wantCalls: []string{"CreateBranch 42-fix-login", "CheckoutBranch 42-fix-login"},
+		},
+		{
+			name:      "checkout in place from base",
+			opts:      branchOptions{BaseBranch: "main"},
+			wantCalls: []string{"CreateBranchFrom 42-fix-login main", "CheckoutBranch 42-fix-login"},
+		},
+		{
+			name:      "no checkout",
+			opts:      branchOptions{NoCheckout: true},
+			wantCalls: []string{"CreateBranch 42-fix-login"},
+		},
+		{
+			name:      "worktree with tracking",
+			opts:      branchOptions{BaseBranch: "main", UseWorktree: true, Track: true},
+			wantCalls: []string{"AddWorktree " + worktreeDir + " 42-fix-login main", "SetUpstream 42-fix-login origin main"},
+		},
+		{
+			name:      "worktree in --worktree-dir",
+			opts:      branchOptions{BaseBranch: "main", UseWorktree: true, WorktreeDir: "/fast/wt/42"},
+			wantCalls: []string{"AddWorktree /fast/wt/42 42-fix-login main"},
+		},
+		{
+			name:     "existing branch without reuse",
+			opts:     branchOptions{BaseBranch: "main", UseWorktree: true},
+			branches: []string{"42-fix-login"},
+			wantErr:  "pass --reuse",
+		},
+		{
+			name:      "existing branch reused in a worktree without tracking",
+			opts:      branchOptions{BaseBranch: "main", UseWorktree: true, Reuse: true, Track: true},
+			branches:  []string{"42-fix-login"},
+			wantCalls: []string{"AddWorktreeForBranch " + worktreeDir + " 42-fix-login"},
+		},
+		{
+			name:      "reused branch already in a worktree",
+			opts:      branchOptions{BaseBranch: "main", UseWorktree: true, Reuse: true},
+			branches:  []string{"42-fix-login"},
+			worktrees: map[string]string{"42-fix-login": "/src/app/.worktrees/old"},
+			wantErr:   "already checked out in /src/app/.worktrees/old",
+		},
+		{
+			name:      "worktree deleted by hand is pruned first",
+			opts:      branchOptions{BaseBranch: "main", UseWorktree: true},
+			prunable:  []string{worktreeDir},
+			wantCalls: []string{"PruneWorktrees", "AddWorktree " + worktreeDir + " 42-fix-login main"},
+		},
+		{
+			name:      "reused branch whose worktree was deleted by hand",
+			opts:      branchOptions{BaseBranch: "main", UseWorktree: true, Reuse: true},
+			branches:  []string{"42-fix-login"},
+			worktrees: map[string]string{"42-fix-login": "/src/app/.worktrees/old"},
+			prunable:  []string{"/src/app/.worktrees/old"},
+			wantCalls: []string{"PruneWorktrees", "AddWorktreeForBranch " + worktreeDir + " 42-fix-login"},
+		},
+		{
+			name:      "base branch checked out in a worktree is branched from its commit",
+			opts:      branchOptions{BaseBranch: "feature/login", UseWorktree: true, Track: true},
+			branches:  []string{"feature/login"},
+			worktrees: map[string]string{"feature/login": "/src/app/.worktrees/login"},
+			wantCalls: []string{"AddWorktree " + worktreeDir + " 42-fix-login 89abcdef0123456789abcdef0123456789abcdef", "SetUpstream 42-fix-login origin feature/login"},
+		},
+		{
+			name:      "new branch already checked out in a worktree",
+			opts:      branchOptions{BaseBranch: "main"},
+			branches:  []string{"42-fix-login"},
+			worktrees: map[string]string{"42-fix-login": "/src/app/.worktrees/old"},
+			wantErr:   "already checked out in /src/app/.worktrees/old",
+		},
+		{
+			name:      "checkout fails",
+			failOn:    "CheckoutBranch",
+			wantCalls: []string{"CreateBranch 42-fix-login", "CheckoutBranch 42-fix-login"},
+			wantErr:   "failed to checkout branch",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			backend := &mockGitBackend{failOn: tt.failOn, branches: tt.branches, worktrees: tt.worktrees, prunable: tt.prunable}
+			branch, err := createBranch(backend, repo, &config.Settings{}, "42", "Fix login", tt.opts)
+			if tt.wantErr != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+					t.Errorf("createBranch() error = %v, want %q", err, tt.wantErr)
+				}
+			} else if err != nil {
+				t.Fatalf("createBranch() error: %v", err)
+			} else if branch != "42-fix-login" {
+				t.Errorf("createBranch() = %q, want 42-fix-login", branch)
+			}
+			if !reflect.DeepEqual(backend.calls, tt.wantCalls) {
+				t.Errorf("git calls = %q, want %q", backend.calls, tt.wantCalls)
+			}
+		})
+	}
+}
+
+func TestCreateBranchWithName(t *testing.T) {
+	repo := &config.Repository{Name: "app", Directory: "/src/app"}
+	backend := &mockGitBackend{}
+
+	branch, err := createBranch(backend, repo, &config.Settings{}, "spike/cache", "spike/cache", branchOptions{Name: "spike/cache", BaseBranch: "main", UseWorktree: true})
+	if err != nil {
+		t.Fatalf("createBranch() error: %v", err)
+	}
+	if branch != "spike/cache" {
+		t.Errorf("createBranch() = %q, want the name as given", branch)
+	}
+	want := []string{"AddWorktree " + filepath.Join("/src/app", ".worktrees", "spike", "cache") + " spike/cache main"}
+	if !reflect.DeepEqual(backend.calls, want) {
+		t.Errorf("git calls = %q, want %q", backend.calls, want)
+	}
+}
+
+func TestCreateBranchWithWorktreeBranches(t *testing.T) {
+	dir := newTestGitDir(t)
+	repo := &config.Repository{Name: "app", Directory: dir}
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	run := func(dir string, args ...string) string {
+		t.Helper()
+		out, err := exec.Command("git", append([]string{"-C", dir}, args...)...).CombinedOutput()
+		if err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+		return strings.TrimSpace(string(out))
+	}
+
+	// A feature branch with its own commit, checked out in a first worktree
+	featureDir := filepath.Join(t.TempDir(), "feature")
+	if _, err := createBranch(gitRepo, repo, &config.Settings{}, "", "", branchOptions{Name: "feature", BaseBranch: "main", UseWorktree: true, WorktreeDir: featureDir}); err != nil {
+		t.Fatalf("createBranch() for the first worktree error: %v", err)
+	}
+	run(featureDir, "commit", "--allow-empty", "-m", "feature work")
+
+	// A second worktree stacked on the branch the first one has checked out
+	stackedDir := filepath.Join(t.TempDir(), "stacked")
+	if _, err := createBranch(gitRepo, repo, &config.Settings{}, "", "", branchOptions{Name: "stacked", BaseBranch: "feature", UseWorktree: true, WorktreeDir: stackedDir}); err != nil {
+		t.Fatalf("createBranch() based on a worktree's branch error: %v", err)
+	}
+	if got, want := run(stackedDir, "rev-parse", "HEAD"), run(featureDir, "rev-parse", "HEAD"); got != want {
+		t.Errorf("stacked branch starts at %s, want the feature worktree's commit %s", got, want)
+	}
+
+	// Creating the first worktree's branch again in place must not move it
+	_, err = createBranch(gitRepo, repo, &config.Settings{}, "", "", branchOptions{Name: "feature", BaseBranch: "main"})
+	if err == nil || !strings.Contains(err.Error(), "already checked out in") {
+		t.Errorf("createBranch() for a branch in another worktree error = %v, want it reported", err)
+	}
+	if got := run(dir, "rev-parse", "--abbrev-ref", "HEAD"); got != "main" {
+		t.Errorf("main checkout is on %s, want it left on main", got)
+	}
+}
+
+func TestValidateParticipants(t *testing.T) {
+	if err := validateParticipants([]string{"reporter@example.com", "jo.smith+support@example.co.uk"}); err != nil {
+		t.Errorf("validateParticipants() error: %v", err)
+	}
+	for _, email := range []string{"reporter", "Reporter <reporter@example.com>", "reporter@"} {
+		if err := validateParticipants([]string{email}); err == nil || !strings.Contains(err.Error(), "invalid --external-participant") {
+			t.Errorf("validateParticipants(%q) error = %v, want it rejected", email, err)
+		}
+	}
+
+	if got, want := externalParticipantAction([]string{"a@example.com", "b@example.com"}), "/add_email a@example.com b@example.com"; got != want {
+		t.Errorf("externalParticipantAction() = %q, want %q", got, want)
+	}
+}
+
+func TestValidateWorktreeDir(t *testing.T) {
+	root := t.TempDir()
+	repoDir := filepath.Join(root, "app")
+	if err := os.MkdirAll(repoDir, 0755); err != nil {
+		t.Fatal(err)
+	}
+	file := filepath.Join(root, "file")
+	if err := os.WriteFile(file, nil, 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	got, err := validateWorktreeDir(filepath.Join(root, "fast", "wt", "42"), repoDir)
+	if err != nil {
+		t.Fatalf("validateWorktreeDir() error: %v", err)
+	}
+	if got != filepath.Join(root, "fast", "wt", "42") {
+		t.Errorf("validateWorktreeDir() = %q, want the path unchanged", got)
+	}
+
+	for _, tt := range []struct {
+		dir     string
+		wantErr string
+	}{
+		{dir: filepath.Join(repoDir, ".worktrees", "42"), wantErr: "inside the repository"},
+		{dir: repoDir, wantErr: "inside the repository"},
+		{dir: filepath.Join(file, "42"), wantErr: "is not a directory"},
+	} {
+		if _, err := validateWorktreeDir(tt.dir, repoDir); err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+			t.Errorf("validateWorktreeDir(%q) error = %v, want %q", tt.dir, err, tt.wantErr)
+		}
+	}
+}
+
+func TestClearStaleWorktreeDir(t *testing.T) {
+	leftover := func(t *testing.T) string {
+		dir := filepath.Join(t.TempDir(), "42-fix-login")
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(filepath.Join(dir, "readme.txt"), []byte("hello"), 0644); err != nil {
+			t.Fatal(err)
+		}
+		return dir
+	}
+
+	t.Run("removed with force when it matches the base", func(t *testing.T) {
+		dir := leftover(t)
+		if err := clearStaleWorktreeDir(&mockGitBackend{}, dir, "main", true, true); err != nil {
+			t.Fatalf("clearStaleWorktreeDir() error: %v", err)
+		}
+		if _, err := os.Stat(dir); !os.IsNotExist(err) {
+			t.Errorf("expected %s to be removed, stat error: %v", dir, err)
+		}
+	})
+
+	t.Run("non-interactive without force refuses", func(t *testing.T) {
+		dir := leftover(t)
+		err := clearStaleWorktreeDir(&mockGitBackend{}, dir, "main", false, true)
+		if err == nil || !strings.Contains(err.Error(), "pass --force") {
+			t.Errorf("expected a --force error, got %v", err)
+		}
+		if _, err := os.Stat(dir); err != nil {
+			t.Errorf("expected %s to be kept: %v", dir, err)
+		}
+	})
+
+	t.Run("uncommitted files are never removed", func(t *testing.T) {
+		dir := leftover(t)
+		err := clearStaleWorktreeDir(&mockGitBackend{dirty: true}, dir, "main", true, true)
+		if err == nil || !strings.Contains(err.Error(), "aren't committed on main") {
+			t.Errorf("expected an uncommitted files error, got %v", err)
+		}
+		if _, err := os.Stat(dir); err != nil {
+			t.Errorf("expected %s to be kept: %v", dir, err)
+		}
+	})
+
+	t.Run("missing directory is left to git", func(t *testing.T) {
+		backend := &mockGitBackend{}
+		if err := clearStaleWorktreeDir(backend, filepath.Join(t.TempDir(), "missing"), "main", false, true); err != nil {
+			t.Fatalf("clearStaleWorktreeDir() error: %v", err)
+		}
+		if len(backend.calls) != 0 {
+			t.Errorf("expected no git calls, got %q", backend.calls)
+		}
+	})
+}
+
+func TestBranchNameForUser(t *testing.T) {
+	cfg := &config.Settings{BranchTemplate: "{user}/{id}-{slug}"}
+	got, err := branchNameFor(&mockGitBackend{}, cfg, &config.Repository{}, utils.BranchNameVars{ID: "7", Title: "Crash"})
+	if err != nil {
+		t.Fatalf("branchNameFor() error: %v", err)
+	}
+	if got != "tk/7-crash" {
+		t.Errorf("branchNameFor() = %q, want tk/7-crash", got)
+	}
+}
+
+func TestConfirmProtectedRepo(t *testing.T) {
+	origNonInteractive, origIKnow := nonInteractive, iKnow
+	defer func() { nonInteractive, iKnow = origNonInteractive, origIKnow }()
+	nonInteractive = true
+
+	iKnow = false
+	if err := confirmProtectedRepo(&config.Repository{GithubRepo: "acme/internal"}, "internal"); err != nil {
+		t.Errorf("unprotected repository should not need confirmation, got %v", err)
+	}
+
+	protected := &config.Repository{GitlabRepo: "acme/website", Protected: true}
+	err := confirmProtectedRepo(protected, "website")
+	if err == nil || !strings.Contains(err.Error(), "'website' (GitLab acme/website) is protected") {
+		t.Errorf("expected a protected repository error naming the repo and provider, got %v", err)
+	}
+
+	iKnow = true
+	if err := confirmProtectedRepo(protected, "website"); err != nil {
+		t.Errorf("--i-know should skip the confirmation, got %v", err)
+	}
+}
+
+func TestIssueLabelSources(t *testing.T) {
+	defaults := []string{"triage", "backend"}
+	tests := []struct {
+		name       string
+		fileLabels []string
+		flagLabels []string
+		envLabels  []string
+		replace    bool
+		want       string
+	}{
+		{name: "defaults only", want: "triage,backend"},
+		{name: "append flag labels", flagLabels: []string{"bug", "ui,backend"}, want: "triage,backend,bug,ui"},
+		{name: "replace defaults", flagLabels: []string{"bug"}, replace: true, want: "bug"},
+		{name: "replace keeps file labels", fileLabels: []string{"docs"}, flagLabels: []string{"bug"}, replace: true, want: "docs,bug"},
+		{name: "environment when no flags", envLabels: []string{"release"}, want: "triage,backend,release"},
+		{name: "flags win over environment", flagLabels: []string{"bug"}, envLabels: []string{"release"}, want: "triage,backend,bug"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			base := baseIssueLabels(defaults, tt.fileLabels, tt.replace)
+			got := utils.MergeLabels(base, explicitLabels(tt.flagLabels, tt.envLabels)...)
+			if got != tt.want {
+				t.Errorf("labels = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+// issueProvider is an SCMProvider that only answers GetIssue
+type issueProvider struct {
+	services.SCMProvider
+	issues map[int]*services.IssueResult
+}
+
+func (p *issueProvider) GetIssue(issueNumber int) (*services.IssueResult, error) {
+	if issue, ok := p.issues[issueNumber]; ok {
+		return issue, nil
+	}
+	return nil, fmt.Errorf("404 Not Found")
+}
+
+func TestCopiedIssueLabels(t *testing.T) {
+	cfg := &config.Settings{ReadyLabel: "ready", UnreadyLabel: "blocked"}
+	settings := &RepoSettings{
+		Repo:     &config.Repository{Name: "app"},
+		Provider: &issueProvider{issues: map[int]*services.IssueResult{
+			12: {Number: 12, Labels: []string{"bug", "ready", "backend"}},
+		}},
+	}
+
+	got, err := copiedIssueLabels(cfg, settings, 12)
+	if err != nil {
+		t.Fatalf("copiedIssueLabels() error: %v", err)
+	}
+	if !reflect.DeepEqual(got, []string{"bug", "backend"}) {
+		t.Errorf("copiedIssueLabels() = %q, want bug and backend without the ready label", got)
+	}
+
+	_, err = copiedIssueLabels(cfg, settings, 99)
+	if err == nil || !strings.Contains(err.Error(), "couldn't fetch issue #99") {
+		t.Errorf("expected a fetch error for a missing issue, got %v", err)
+	}
+}
+
+// batchProvider is an SCMProvider that files issues, failing for failTitle
+type batchProvider struct {
+	services.SCMProvider
+	created   []services.IssueParams
+	failTitle string
+}
+
+func (p *batchProvider) CreateIssue(params services.IssueParams) (*services.IssueResult, error) {
+	if params.Title == p.failTitle {
+		return nil, fmt.Errorf("422 Validation Failed")
+	}
+	p.created = append(p.created, params)
+	number := 10 + len(p.created)
+	return &services.IssueResult{Number: number, Title: params.Title, URL: fmt.Sprintf("https://github.com/acme/app/issues/%d", number)}, nil
+}
+
+func (p *batchProvider) GetURL() string { return "https://github.com/acme/app" }
+
+func TestCreateBatch(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+	oldNonInteractive, oldSelfAssign, oldNoCheckout := nonInteractive, selfAssign, noCheckout
+	defer func() { nonInteractive, selfAssign, noCheckout = oldNonInteractive, oldSelfAssign, oldNoCheckout }()
+	nonInteractive, selfAssign, noCheckout = true, true, true
+
+	repo := &config.Repository{Name: "app", GithubRepo: "acme/app", DefaultLabels: config.LabelList{"triage"}}
+	provider := &batchProvider{failTitle: "Broken"}
+	settings := &RepoSettings{Repo: repo, Name: "app", Provider: provider, CodeRepo: repo, CodeRepoName: "app"}
+	backend := &mockGitBackend{}
+
+	err := createBatch(createCmd, &config.Settings{}, settings, backend, []utils.BatchIssue{
+		{Title: "Fix login", Labels: []string{"bug"}, Assignees: []string{"alice"}, Branch: true},
+		{Title: "Broken"},
+		{Title: "Add export"},
+	}, nil)
+	if err == nil || !strings.Contains(err.Error(), "1 of 3 issues in the batch failed") {
+		t.Errorf("createBatch() error = %v, want the failure reported", err)
+	}
+
+	if len(provider.created) != 2 {
+		t.Fatalf("created %d issues, want 2 (the batch continues past a failure)", len(provider.created))
+	}
+	first, last := provider.created[0], provider.created[1]
+	if first.Labels != "triage,bug" || first.SelfAssign || !reflect.DeepEqual(first.Assignees, []string{"alice"}) {
+		t.Errorf("first issue = %+v, want default and item labels, assigned to alice only", first)
+	}
+	if last.Title != "Add export" || last.Labels != "triage" || !last.SelfAssign {
+		t.Errorf("last issue = %+v, want default labels and self-assigned", last)
+	}
+	if !reflect.DeepEqual(backend.calls, []string{"CreateBranch 11-fix-login"}) {
+		t.Errorf("git calls = %q, want a branch only for the item that asked for one", backend.calls)
+	}
+}
+
+// lostResponseProvider files issues but drops the response of the first one,
+// like a connection reset after the server created it
+type lostResponseProvider struct {
+	services.SCMProvider
+	created []services.IssueParams
+}
+
+func (p *lostResponseProvider) CreateIssue(params services.IssueParams) (*services.IssueResult, error) {
+	p.created = append(p.created, params)
+	if len(p.created) == 1 {
+		return nil, &net.OpError{Op: "read", Net: "tcp", Err: fmt.Errorf("connection reset by peer")}
+	}
+	return &services.IssueResult{Number: 10 + len(p.created), Title: params.Title}, nil
+}
+
+func (p *lostResponseProvider) FindIssueByBody(text string) (*services.IssueResult, error) {
+	for i, params := range p.created {
+		if strings.Contains(params.Body, text) {
+			return &services.IssueResult{Number: 11 + i, Title: params.Title}, nil
+		}
+	}
+	return nil, nil
+}
+
+func TestFileIssueIdempotent(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+	cfg := &config.Settings{IdempotentCreate: true}
+	repo := &config.Repository{Name: "app", GithubRepo: "acme/app"}
+	provider := &lostResponseProvider{}
+	params := services.IssueParams{Title: "Add export", Body: "Details"}
+
+	if _, err := fileIssue(cfg, repo, "app", provider, params); err == nil {
+		t.Fatal("expected the lost response to be reported")
+	}
+	body := provider.created[0].Body
+	if !strings.HasPrefix(body, "Details\n\n<!-- tix-idempotency-key: ") {
+		t.Errorf("issue body = %q, want the idempotency marker after the description", body)
+	}
+
+	// The retry finds the issue the first attempt created
+	issue, err := fileIssue(cfg, repo, "app", provider, params)
+	if err != nil {
+		t.Fatalf("fileIssue() retry error: %v", err)
+	}
+	if issue.Number != 11 || len(provider.created) != 1 {
+		t.Errorf("retry returned #%d after %d creates, want the existing #11 and no new issue", issue.Number, len(provider.created))
+	}
+
+	// Once found, the attempt is forgotten and the same title is filed anew
+	issue, err = fileIssue(cfg, repo, "app", provider, params)
+	if err != nil {
+		t.Fatalf("fileIssue() error: %v", err)
+	}
+	if issue.Number != 12 || provider.created[1].Body == body {
+		t.Errorf("got #%d with body %q, want a new issue with a fresh key", issue.Number, provider.created[1].Body)
+	}
+
+	// Without the setting the body is left alone
+	provider = &lostResponseProvider{created: []services.IssueParams{{}}}
+	if _, err := fileIssue(&config.Settings{}, repo, "app", provider, params); err != nil {
+		t.Fatalf("fileIssue() error: %v", err)
+	}
+	if provider.created[1].Body != "Details" {
+		t.Errorf("issue body = %q, want it unchanged", provider.created[1].Body)
+	}
+}
+
+func TestFileIssueDryRunRecordsNoAttempt(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+	orig := dryRun
+	defer func() { dryRun = orig }()
+	dryRun = true
+
+	provider := &lostResponseProvider{}
+	params := services.IssueParams{Title: "Add export", Body: "Details"}
+	_, _ = fileIssue(&config.Settings{IdempotentCreate: true}, &config.Repository{Name: "app", GithubRepo: "acme/app"}, "app", provider, params)
+	attempts, err := state.LoadAttempts()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(attempts) != 0 || provider.created[0].Body != "Details" {
+		t.Errorf("dry run recorded %d attempt(s) with body %q, want none", len(attempts), provider.created[0].Body)
+	}
+}
+
+func TestBatchSummary(t *testing.T) {
+	var buf bytes.Buffer
+	err := batchSummary(&buf, []batchResult{
+		{Title: "Fix login", Number: 11, URL: "https://github.com/acme/app/issues/11", Branch: "11-fix-login"},
+		{Title: "Broken", Err: fmt.Errorf("failed to create issue: 422 Validation Failed")},
+		{Title: "Add export", Number: 12, URL: "https://github.com/acme/app/issues/12"},
+	})
+	if err == nil {
+		t.Error("batchSummary() should fail when an issue failed")
+	}
+
+	want := "\nCreated 2 of 3 issues:\n" +
+		"  #11 https://github.com/acme/app/issues/11 (branch 11-fix-login)\n" +
+		"  #12 https://github.com/acme/app/issues/12\n" +
+		"Failed:\n" +
+		"  Broken: failed to create issue: 422 Validation Failed\n"
+	if buf.String() != want {
+		t.Errorf("batchSummary() output = %q, want %q", buf.String(), want)
+	}
+}
+
+func TestResolveRemoteBaseBranch(t *testing.T) {
+	backend := &mockGitBackend{revisions: []string{"refs/remotes/upstream/master", "refs/remotes/origin/release"}}
+	repo := &config.Repository{Name: "app"}
+
+	tests := []struct {
+		name     string
+		cfg      *config.Settings
+		baseFlag string
+		remote   string
+		want     string
+		wantErr  string
+	}{
+		{name: "detected default", cfg: &config.Settings{}, remote: "upstream", want: "upstream/master"},
+		{name: "--base", cfg: &config.Settings{}, baseFlag: "release", remote: "origin", want: "origin/release"},
+		{name: "--base with remote prefix", cfg: &config.Settings{}, baseFlag: "origin/release", remote: "origin", want: "origin/release"},
+		{name: "configured default missing on remote", cfg: &config.Settings{Worktree: config.WorktreeConfig{DefaultBranch: "develop"}}, remote: "origin", wantErr: "origin/develop doesn't exist"},
+		{name: "no default on remote", cfg: &config.Settings{}, remote: "origin", wantErr: "neither origin/main nor origin/master exists"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := resolveRemoteBaseBranch(backend, tt.cfg, repo, tt.baseFlag, tt.remote)
+			if tt.wantErr != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+					t.Errorf("resolveRemoteBaseBranch() error = %v, want %q", err, tt.wantErr)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("resolveRemoteBaseBranch() error: %v", err)
+			}
+			if got != tt.want {
+				t.Errorf("resolveRemoteBaseBranch() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestFastForwardBaseBeforeCreate(t *testing.T) {
+	repo := &config.Repository{Name: "app", FFBaseBeforeCreate: true}
+	if !(&config.Settings{}).ResolveFFBaseBeforeCreate(repo) {
+		t.Fatal("expected ff_base_before_create on the repository to enable it")
+	}
+
+	backend := &mockGitBackend{}
+	fastForwardBase(backend, "main")
+	if want := []string{"FastForwardBranch main"}; !reflect.DeepEqual(backend.calls, want) {
+		t.Errorf("git calls = %q, want %q", backend.calls, want)
+	}
+}
+
+func TestCheckRequiredLabels(t *testing.T) {
+	tests := []struct {
+		name    string
+		cfg     *config.Settings
+		repo    *config.Repository
+		labels  string
+		wantErr bool
+	}{
+		{name: "not required", cfg: &config.Settings{}, repo: &config.Repository{}, labels: ""},
+		{name: "required globally", cfg: &config.Settings{RequireLabels: true}, repo: &config.Repository{}, labels: " , ", wantErr: true},
+		{name: "required for the repo", cfg: &config.Settings{}, repo: &config.Repository{RequireLabels: true}, labels: "", wantErr: true},
+		{name: "required and labeled", cfg: &config.Settings{RequireLabels: true}, repo: &config.Repository{}, labels: "bug"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := checkRequiredLabels(tt.cfg, tt.repo, "app", tt.labels)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("checkRequiredLabels() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if err != nil && !strings.Contains(err.Error(), "require_labels") {
+				t.Errorf("error %q doesn't name the setting", err)
+			}
+		})
+	}
+}
+
+// labelProvider records the labels created through LabelCreator
+type labelProvider struct {
+	services.SCMProvider
+	existing []string
+	created  []string
+}
+
+func (p *labelProvider) LabelExists(label string) (bool, error) {
+	for _, existing := range p.existing {
+		if existing == label {
+			return true, nil
+		}
+	}
+	return false, nil
+}
+
+func (p *labelProvider) CreateLabel(label, color string) error {
+	p.created = append(p.created, label+" "+color)
+	return nil
+}
+
+func TestCreateColoredLabels(t *testing.T) {
+	cfg := &config.Settings{LabelColorMap: map[string]string{"priority::*": "#FF0000", "*": "cccccc"}}
+	repo := &config.Repository{LabelColorMap: map[string]string{"priority::low": "#00ff00"}}
+	provider := &labelProvider{existing: []string{"bug"}}
+
+	createColoredLabels(cfg, repo, provider, "bug,priority::high,priority::low,docs", nil)
+	want := []string{"priority::high #ff0000", "priority::low #00ff00", "docs #cccccc"}
+	if !reflect.DeepEqual(provider.created, want) {
+		t.Errorf("created labels = %q, want %q", provider.created, want)
+	}
+
+	// Without label_color_map the provider creates labels as it always has
+	provider = &labelProvider{}
+	createColoredLabels(&config.Settings{}, &config.Repository{}, provider, "docs", nil)
+	if len(provider.created) != 0 {
+		t.Errorf("created labels = %q, want none", provider.created)
+	}
+
+	// Aliased labels are created under the provider's name, in the canonical
+	// label's color
+	provider = &labelProvider{}
+	createColoredLabels(cfg, repo, provider, "priority::low", map[string]string{"priority::low": "P3"})
+	if want := []string{"P3 #00ff00"}; !reflect.DeepEqual(provider.created, want) {
+		t.Errorf("created labels = %q, want %q", provider.created, want)
+	}
+}
+
+func (p *labelProvider) GetURL() string {
+	return "https://github.com/acme/app"
+}
+
+func TestConfirmNewLabels(t *testing.T) {
+	origNonInteractive, origCreateLabels := nonInteractive, createLabels
+	defer func() { nonInteractive, createLabels = origNonInteractive, origCreateLabels }()
+	nonInteractive = true
+
+	cfg := &config.Settings{ConfirmNewLabels: true, LabelAliases: map[string]map[string]string{"github": {"priority::low": "P3"}}}
+	repo := &config.Repository{GithubRepo: "acme/app"}
+	provider := &labelProvider{existing: []string{"bug", "P3"}}
+
+	if err := confirmNewLabels(cfg, repo, provider, "bug,priority::low"); err != nil {
+		t.Errorf("confirmNewLabels() with existing labels error = %v", err)
+	}
+	err := confirmNewLabels(cfg, repo, provider, "bug,bgu")
+	if err == nil || !strings.Contains(err.Error(), "bgu") || !strings.Contains(err.Error(), "--create-labels") {
+		t.Errorf("confirmNewLabels() error = %v, want the new label and --create-labels named", err)
+	}
+	if err := confirmNewLabels(&config.Settings{}, repo, provider, "bgu"); err != nil {
+		t.Errorf("confirmNewLabels() without confirm_new_labels error = %v", err)
+	}
+
+	createLabels = true
+	if err := confirmNewLabels(cfg, repo, provider, "bgu"); err != nil {
+		t.Errorf("confirmNewLabels() with --create-labels error = %v", err)
+	}
+}
+
+// protectionProvider reports a fixed BranchProtection
+type protectionProvider struct {
+	services.SCMProvider
+	protection services.BranchProtection
+	branches   []string
+}
+
+func (p *protectionProvider) BranchProtection(branch string) (*services.BranchProtection, error) {
+	p.branches = append(p.branches, branch)
+	return &p.protection, nil
+}
+
+func TestBranchProtectionNotice(t *testing.T) {
+	tests := []struct {
+		name       string
+		protection services.BranchProtection
+		want       string
+	}{
+		{name: "unprotected", want: ""},
+		{name: "protected but pushable", protection: services.BranchProtection{Protected: true}, want: ""},
+		{name: "pushes blocked", protection: services.BranchProtection{Protected: true, PushBlocked: true}, want: "main is protected; you'll need a merge/pull request to land this branch (tix mr)"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			provider := &protectionProvider{protection: tt.protection}
+			if got := branchProtectionNotice(provider, "main"); got != tt.want {
+				t.Errorf("branchProtectionNotice() = %q, want %q", got, tt.want)
+			}
+			if !reflect.DeepEqual(provider.branches, []string{"main"}) {
+				t.Errorf("checked branches %q, want [main]", provider.branches)
+			}
+		})
+	}
+
+	// Providers that can't report protection are skipped
+	if got := branchProtectionNotice(&labelProvider{}, "main"); got != "" {
+		t.Errorf("branchProtectionNotice() without a checker = %q, want none", got)
+	}
+}
+
+func TestBranchIssueID(t *testing.T) {
+	issue := &services.IssueResult{Number: 12, ID: 48213}
+	tests := []struct {
+		name    string
+		cfg     *config.Settings
+		repo    *config.Repository
+		want    string
+		wantErr string
+	}{
+		{name: "gitlab default", cfg: &config.Settings{}, repo: &config.Repository{GitlabRepo: "acme/app"}, want: "12"},
+		{name: "gitlab global id", cfg: &config.Settings{GitlabBranchID: "id"}, repo: &config.Repository{GitlabRepo: "acme/app"}, want: "48213"},
+		{name: "per-repo overrides global", cfg: &config.Settings{GitlabBranchID: "id"}, repo: &config.Repository{GitlabRepo: "acme/app", GitlabBranchID: "iid"}, want: "12"},
+		{name: "github ignores it", cfg: &config.Settings{GitlabBranchID: "id"}, repo: &config.Repository{GithubRepo: "acme/app"}, want: "12"},
+		{name: "invalid", cfg: &config.Settings{GitlabBranchID: "uuid"}, repo: &config.Repository{GitlabRepo: "acme/app"}, wantErr: "must be iid or id"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := branchIssueID(tt.cfg, tt.repo, issue)
+			if tt.wantErr != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+					t.Errorf("branchIssueID() error = %v, want %q", err, tt.wantErr)
+				}
+				return
+			}
+			if err != nil || got != tt.want {
+				t.Errorf("branchIssueID() = %q, %v, want %q", got, err, tt.want)
+			}
+		})
+	}
+
+	// A pending issue filed before its id was recorded can't name the branch
+	_, err := branchIssueID(&config.Settings{GitlabBranchID: "id"}, &config.Repository{GitlabRepo: "acme/app"}, &services.IssueResult{Number: 12})
+	if err == nil {
+		t.Error("branchIssueID() without an id succeeded, want an error")
+	}
+}
+
+func TestRecordBranchIssue(t *testing.T) {
+	dir := newTestGitDir(t)
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	// A gitlab_branch_id: id branch carries the instance-wide id 12345 of #7
+	cfg := &config.Settings{GitlabBranchID: "id"}
+	repo := &config.Repository{GitlabRepo: "acme/app"}
+	issueID, err := branchIssueID(cfg, repo, &services.IssueResult{Number: 7, ID: 12345})
+	if err != nil {
+		t.Fatal(err)
+	}
+	branch := issueID + "-fix-login"
+	if err := gitRepo.CreateBranch(branch); err != nil {
+		t.Fatal(err)
+	}
+	recordBranchIssue(gitRepo, branch, issueID, 7)
+
+	// tix mr and tix status read the issue from the branch like this
+	if _, number, err := utils.BranchIssueInfo(dir, branch); err != nil || number != 7 {
+		t.Errorf("BranchIssueInfo(%s) = %d, %v, want issue 7", branch, number, err)
+	}
+	if ref, _ := commitRef(&config.Settings{}, repo, dir, branch); ref != "Refs #7" {
+		t.Errorf("commitRef(%s) = %q, want Refs #7", branch, ref)
+	}
+
+	// Branches named by the issue number need nothing recorded
+	mock := &mockGitBackend{}
+	recordBranchIssue(mock, "7-fix-login", "7", 7)
+	if len(mock.calls) != 0 {
+		t.Errorf("recorded %v for an iid branch, want nothing", mock.calls)
+	}
+}
+
+func TestRotationTurnUsedOnceFiled(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+	cfg := &config.Settings{Rotations: map[string]config.Rotation{"triage": {Members: []string{"alice", "bob"}}}}
+	app := &config.Repository{Name: "app"}
+	api := &config.Repository{Name: "api", Rotations: map[string]config.Rotation{"triage": {Members: []string{"carol", "dave"}}}}
+
+	// Picking the assignee doesn't use up the turn, e.g. when filing fails
+	for i := 0; i < 2; i++ {
+		if member, err := rotationAssignee(cfg, app, "triage"); err != nil || member != "alice" {
+			t.Fatalf("rotationAssignee() = %q, %v, want alice", member, err)
+		}
+	}
+	advanceRotation(cfg, app, "triage")
+	if member, _ := rotationAssignee(cfg, app, "triage"); member != "bob" {
+		t.Errorf("rotationAssignee() after filing = %q, want bob", member)
+	}
+
+	// A per-repo rotation of the same name keeps its own turns
+	if member, _ := rotationAssignee(cfg, api, "triage"); member != "carol" {
+		t.Errorf("rotationAssignee() for api = %q, want carol", member)
+	}
+}
+
+func TestAddAdHocRepository(t *testing.T) {
+	dir := t.TempDir()
+	cfg := &config.Settings{Repositories: []config.Repository{{Name: "acme/api", GithubRepo: "acme/api"}}}
+
+	name, err := addAdHocRepository(cfg, "acme/app", "", dir)
+	if err != nil {
+		t.Fatalf("addAdHocRepository() error = %v", err)
+	}
+	repo := cfg.GetRepo(name)
+	if name != "acme/app" || repo == nil {
+		t.Fatalf("addAdHocRepository() = %q, not added to the config", name)
+	}
+	if repo.GithubRepo != "acme/app" || repo.Directory != dir || !repo.IsCodeRepo() {
+		t.Errorf("added repository = %+v", repo)
+	}
+
+	for _, tt := range []struct{ name, github, gitlab, dir string }{
+		{name: "both providers", github: "acme/app", gitlab: "group/app"},
+		{name: "dir alone", dir: dir},
+		{name: "no owner", github: "app"},
+		{name: "already configured", github: "acme/api"},
+	} {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := addAdHocRepository(cfg, tt.github, tt.gitlab, tt.dir); err == nil {
+				t.Error("addAdHocRepository() succeeded, want an error")
+			}
+		})
+	}
+}
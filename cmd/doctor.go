@@ -0,0 +1,218 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/pterm/pterm"
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+// doctorCheck is one line of the tix doctor checklist
+type doctorCheck struct {
+	Name string
+	Err  error  // nil when the check passed
+	Hint string // How to fix a failed check
+}
+
+var doctorCmd = &cobra.Command{
+	Use:   "doctor",
+	Short: "Check the config, tokens, repositories and network for problems",
+	Long: `Run every check tix depends on and print a checklist with a hint for each
+failure:
+
+  - the config file exists and is valid
+  - GitHub and GitLab can be reached (through any configured proxy)
+  - each repository's API token is set and accepted
+  - each repository's directory is a git repository with its base branch
+  - each repository's worktree directory is writable
+
+The command exits non-zero if any check failed.`,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting doctor command")
+
+		checks := 0
+		failed := 0
+		report := func(indent string, check doctorCheck) {
+			checks++
+			if check.Err == nil {
+				fmt.Println(indent + pterm.FgGreen.Sprint("✓ ") + check.Name)
+				return
+			}
+			failed++
+			fmt.Println(indent + pterm.FgRed.Sprint("✗ ") + check.Name + ": " + check.Err.Error())
+			if check.Hint != "" {
+				fmt.Println(indent + "  " + pterm.FgGray.Sprint(check.Hint))
+			}
+		}
+
+		cfg, check := doctorConfigCheck()
+		report("", check)
+		if cfg == nil {
+			return fmt.Errorf("the config couldn't be loaded, so nothing else was checked")
+		}
+
+		for _, host := range doctorHosts(cfg) {
+			report("", doctorCheck{
+				Name: "Reach " + host,
+				Err:  services.CheckReachable(host),
+				Hint: "check your network connection, or HTTPS_PROXY/NO_PROXY and ca_cert_path if you're behind a proxy",
+			})
+		}
+
+		for i := range cfg.Repositories {
+			repo := &cfg.Repositories[i]
+			fmt.Println(repo.Name + ":")
+			for _, check := range doctorTokenChecks(repo) {
+				report("  ", check)
+			}
+			if repo.IsCodeRepo() {
+				for _, check := range doctorRepoChecks(cfg, repo) {
+					report("  ", check)
+				}
+			}
+		}
+
+		if failed > 0 {
+			return fmt.Errorf("%d of %d checks failed", failed, checks)
+		}
+		printSuccess("All %d checks passed.", checks)
+		logger.Debug("Doctor command completed successfully")
+		return nil
+	},
+}
+
+// doctorConfigCheck loads the config file, returning nil settings when it
+// can't be loaded
+func doctorConfigCheck() (*config.Settings, doctorCheck) {
+	path, err := config.ConfigPath()
+	if err != nil {
+		return nil, doctorCheck{Name: "Config file", Err: err}
+	}
+	check := doctorCheck{Name: "Config file " + path}
+
+	if _, err := os.Stat(path); os.IsNotExist(err) {
+		check.Err = fmt.Errorf("not found")
+		check.Hint = "run 'tix config edit' to create one, or point tix at another file with --config or TIX_CONFIG"
+		return nil, check
+	}
+
+	cfg, err := utils.LoadConfig()
+	if err != nil {
+		check.Err = err
+		check.Hint = "run 'tix config edit' to fix it"
+		return nil, check
+	}
+	if len(cfg.Repositories) == 0 {
+		check.Err = fmt.Errorf("no repositories configured")
+		check.Hint = "add a repository under 'repositories:' - see the Configuration section of the README"
+		return nil, check
+	}
+	return cfg, check
+}
+
+// doctorHosts returns the API hosts the configured repositories use
+func doctorHosts(cfg *config.Settings) []string {
+	var hosts []string
+	seen := map[string]bool{}
+	for _, repo := range cfg.Repositories {
+		host := "https://api.github.com"
+		if repo.GitlabRepo != "" {
+			host = "https://gitlab.com"
+		}
+		if !seen[host] {
+			seen[host] = true
+			hosts = append(hosts, host)
+		}
+	}
+	return hosts
+}
+
+// doctorTokenChecks checks that repo's API token is set and that the
+// provider accepts it
+func doctorTokenChecks(repo *config.Repository) []doctorCheck {
+	forge, variable, setting := "GitHub", "GITHUB_TOKEN", "github_token"
+	if repo.GitlabRepo != "" {
+		forge, variable, setting = "GitLab", "GITLAB_TOKEN", "gitlab_token"
+	}
+
+	provider, err := utils.CreateSCMProvider(&utils.SharedRepoInfo{
+		Repo:     repo,
+		Name:     repo.Name,
+		IsGitLab: repo.GitlabRepo != "",
+	})
+	if err != nil {
+		return []doctorCheck{{
+			Name: forge + " token is set",
+			Err:  err,
+			Hint: fmt.Sprintf("set %s, or %s/%s_file in the config", variable, setting, setting),
+		}}
+	}
+	checks := []doctorCheck{{Name: forge + " token is set"}}
+
+	check := doctorCheck{Name: forge + " accepts the token"}
+	if resolver, ok := provider.(services.UserResolver); ok {
+		username, err := resolver.CurrentUsername()
+		if err != nil {
+			check.Err = err
+			check.Hint = fmt.Sprintf("create a new token with API access and update %s", variable)
+		} else {
+			check.Name += " (authenticated as " + username + ")"
+		}
+	}
+	return append(checks, check)
+}
+
+// doctorRepoChecks checks that repo's directory is a git repository with a
+// base branch and that new worktrees can be created
+func doctorRepoChecks(cfg *config.Settings, repo *config.Repository) []doctorCheck {
+	gitRepo, err := git.Open(repo.Directory)
+	if err != nil {
+		return []doctorCheck{{
+			Name: repo.Directory + " is a git repository",
+			Err:  err,
+			Hint: fmt.Sprintf("clone the repository there or fix 'directory' for %s", repo.Name),
+		}}
+	}
+	checks := []doctorCheck{{Name: repo.Directory + " is a git repository"}}
+
+	if branch := cfg.ConfiguredDefaultBranch(repo); branch != "" {
+		check := doctorCheck{Name: "Base branch " + branch + " exists"}
+		if !gitRepo.RevisionExists(branch) {
+			check.Err = missingDefaultBranchError(gitRepo, repo, branch)
+		}
+		checks = append(checks, check)
+	} else {
+		check := doctorCheck{Name: "Base branch"}
+		candidates, err := gitRepo.BaseBranchCandidates()
+		switch {
+		case err != nil:
+			check.Err = err
+		case len(candidates) == 0:
+			check.Err = fmt.Errorf("neither main nor master exists")
+			check.Hint = fmt.Sprintf("set default_branch for %s", repo.Name)
+		case len(candidates) == 1:
+			check.Name += " " + candidates[0] + " exists"
+		default:
+			check.Name += " " + strings.Join(candidates, " and ") + " exist; tix will ask which to use unless default_branch is set"
+		}
+		checks = append(checks, check)
+	}
+
+	worktreePath := cfg.ResolveWorktreePath(repo)
+	return append(checks, doctorCheck{
+		Name: "Worktree directory " + worktreePath + " is writable",
+		Err:  checkWritableDir(worktreePath),
+		Hint: fmt.Sprintf("fix its permissions or set worktree.path for %s", repo.Name),
+	})
+}
+
+func init() {
+	rootCmd.AddCommand(doctorCmd)
+}
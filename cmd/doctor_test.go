@@ -0,0 +1,49 @@
+package cmd
+
+import (
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+)
+
+func TestDoctorHosts(t *testing.T) {
+	cfg := &config.Settings{Repositories: []config.Repository{
+		{Name: "app", GitlabRepo: "acme/app"},
+		{Name: "website", GithubRepo: "acme/website"},
+		{Name: "docs", GithubRepo: "acme/docs"},
+	}}
+	want := []string{"https://gitlab.com", "https://api.github.com"}
+	if got := doctorHosts(cfg); !reflect.DeepEqual(got, want) {
+		t.Errorf("doctorHosts() = %v, want %v", got, want)
+	}
+}
+
+func TestDoctorRepoChecks(t *testing.T) {
+	dir := newTestGitDir(t)
+
+	checks := doctorRepoChecks(&config.Settings{}, &config.Repository{Name: "app", Directory: dir})
+	if len(checks) != 3 {
+		t.Fatalf("expected 3 checks, got %+v", checks)
+	}
+	for _, check := range checks {
+		if check.Err != nil {
+			t.Errorf("check %q failed: %v", check.Name, check.Err)
+		}
+	}
+	if checks[1].Name != "Base branch main exists" {
+		t.Errorf("base branch check = %q, want main found", checks[1].Name)
+	}
+
+	checks = doctorRepoChecks(&config.Settings{}, &config.Repository{Name: "app", Directory: dir, DefaultBranch: "develop"})
+	if checks[1].Err == nil || !strings.Contains(checks[1].Err.Error(), "default_branch 'develop'") {
+		t.Errorf("base branch check error = %v, want the missing default_branch reported", checks[1].Err)
+	}
+
+	checks = doctorRepoChecks(&config.Settings{}, &config.Repository{Name: "app", Directory: filepath.Join(t.TempDir(), "missing")})
+	if len(checks) != 1 || checks[0].Err == nil || checks[0].Hint == "" {
+		t.Errorf("checks for a missing directory = %+v, want only a failed git repository check with a hint", checks)
+	}
+}
@@ -0,0 +1,131 @@
+package cmd
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/state"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+// defaultHistoryLimit is how many entries `tix history` shows by default
+const defaultHistoryLimit = 20
+
+var historyLimit int
+
+var historyCmd = &cobra.Command{
+	Use:   "history",
+	Short: "List the issues tix has created",
+	Long: `List the issues created with tix create and tix sync, newest first, with
+the branch and worktree made for each.
+
+Entries are appended to history.jsonl in the state directory
+($XDG_STATE_HOME/tix, default ~/.local/state/tix), or to history.path.
+Set history.disabled to stop recording.`,
+	Args: cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting history command")
+
+		if historyLimit < 0 {
+			return fmt.Errorf("--limit must be 0 (no limit) or more")
+		}
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		path, err := historyPath(cfg)
+		if err != nil {
+			return err
+		}
+		entries, err := state.LoadHistory(path)
+		if err != nil {
+			return err
+		}
+
+		if len(entries) == 0 {
+			fmt.Println("No issues recorded yet.")
+			return nil
+		}
+
+		for _, entry := range recentHistory(entries, historyLimit) {
+			fmt.Println(formatHistoryEntry(entry))
+		}
+
+		logger.Debug("History command completed successfully")
+		return nil
+	},
+}
+
+// historyPath returns the history log path: history.path, or history.jsonl
+// in the state directory
+func historyPath(cfg *config.Settings) (string, error) {
+	if path := cfg.ResolveHistoryPath(); path != "" {
+		return path, nil
+	}
+	return state.HistoryPath()
+}
+
+// recordHistory appends a created issue to the history log unless
+// history.disabled is set. A failure is only a warning since the issue exists.
+func recordHistory(cfg *config.Settings, entry state.HistoryEntry) {
+	if cfg.History.Disabled {
+		return
+	}
+	path, err := historyPath(cfg)
+	if err == nil {
+		err = state.AppendHistory(path, entry)
+	}
+	if err != nil {
+		printWarning("couldn't record the issue in the history log: %v", err)
+	}
+}
+
+// providerName names the provider a repository's issues live on, for history entries
+func providerName(repo *config.Repository) string {
+	if repo.GitlabRepo != "" {
+		return "gitlab"
+	}
+	return "github"
+}
+
+// recentHistory returns up to limit entries (0 for all), newest first
+func recentHistory(entries []state.HistoryEntry, limit int) []state.HistoryEntry {
+	recent := make([]state.HistoryEntry, 0, len(entries))
+	for i := len(entries) - 1; i >= 0; i-- {
+		if limit > 0 && len(recent) == limit {
+			break
+		}
+		recent = append(recent, entries[i])
+	}
+	return recent
+}
+
+// formatHistoryEntry renders an entry as one line: when, issue, title, then
+// the branch, worktree and URL that exist for it
+func formatHistoryEntry(entry state.HistoryEntry) string {
+	parts := []string{
+		entry.CreatedAt.Local().Format("2006-01-02 15:04"),
+		fmt.Sprintf("%s #%d", entry.Repo, entry.IssueNumber),
+		entry.Title,
+	}
+	if entry.Branch != "" {
+		parts = append(parts, "["+entry.Branch+"]")
+	}
+	if entry.Worktree != "" {
+		parts = append(parts, entry.Worktree)
+	}
+	if entry.URL != "" {
+		parts = append(parts, entry.URL)
+	}
+	return strings.Join(parts, "  ")
+}
+
+func init() {
+	rootCmd.AddCommand(historyCmd)
+	historyCmd.Flags().IntVarP(&historyLimit, "limit", "l", defaultHistoryLimit, "Maximum number of entries to show (0 for all)")
+}
@@ -0,0 +1,52 @@
+package cmd
+
+import (
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/state"
+)
+
+func TestRecordHistory(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "history.jsonl")
+	cfg := &config.Settings{History: config.HistoryConfig{Path: path}}
+
+	recordHistory(cfg, state.HistoryEntry{Repo: "backend", IssueNumber: 1, Title: "First"})
+	recordHistory(cfg, state.HistoryEntry{Repo: "backend", IssueNumber: 2, Title: "Second", Branch: "2-second"})
+
+	entries, err := state.LoadHistory(path)
+	if err != nil {
+		t.Fatalf("LoadHistory() error = %v", err)
+	}
+	if len(entries) != 2 {
+		t.Fatalf("expected 2 entries, got %d", len(entries))
+	}
+
+	recent := recentHistory(entries, 1)
+	if len(recent) != 1 || recent[0].IssueNumber != 2 {
+		t.Errorf("recentHistory(entries, 1) = %+v, want issue #2 only", recent)
+	}
+	if all := recentHistory(entries, 0); len(all) != 2 || all[0].IssueNumber != 2 {
+		t.Errorf("recentHistory(entries, 0) = %+v, want both entries newest first", all)
+	}
+
+	line := formatHistoryEntry(recent[0])
+	for _, want := range []string{"backend #2", "Second", "[2-second]"} {
+		if !strings.Contains(line, want) {
+			t.Errorf("formatHistoryEntry() = %q, want it to contain %q", line, want)
+		}
+	}
+
+	cfg.History.Disabled = true
+	recordHistory(cfg, state.HistoryEntry{Repo: "backend", IssueNumber: 3, CreatedAt: time.Now()})
+	entries, err = state.LoadHistory(path)
+	if err != nil {
+		t.Fatalf("LoadHistory() error = %v", err)
+	}
+	if len(entries) != 2 {
+		t.Errorf("expected history.disabled to skip recording, got %d entries", len(entries))
+	}
+}
@@ -0,0 +1,167 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var installHooksForce bool
+
+// commitMsgHook is the commit-msg hook tix install-hooks writes. It hands the
+// message to tix hook commit-msg, and does nothing when tix isn't on the PATH
+// so commits never fail because of it.
+const commitMsgHook = `#!/bin/sh
+# Installed by tix install-hooks: adds the branch's issue to commit messages
+command -v tix >/dev/null 2>&1 || exit 0
+exec tix hook commit-msg "$1"
+`
+
+var installHooksCmd = &cobra.Command{
+	Use:   "install-hooks",
+	Short: "Install a commit-msg hook that references the branch's issue",
+	Long: `Install a commit-msg git hook in the repository you're in. On a tix branch
+(e.g. 123-fix-login) it adds a line referencing the issue to each commit
+message, "Refs #123" by default - set commit_ref_format to change it, using
+{id} for the issue number and {ref} for the reference (#123, or
+group/project#123 for a cross-repo branch).
+
+Worktrees share the repository's hooks, so installing once covers all of them.
+An existing commit-msg hook is only replaced with --force.`,
+	Args: cobra.NoArgs,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting install-hooks command")
+
+		wd, err := os.Getwd()
+		if err != nil {
+			return fmt.Errorf("failed to get current directory: %w", err)
+		}
+		gitRepo, err := git.Open(wd)
+		if err != nil {
+			return fmt.Errorf("%s is not a git repository", wd)
+		}
+		hooksDir, err := gitRepo.HooksDir()
+		if err != nil {
+			return err
+		}
+
+		hookPath := filepath.Join(hooksDir, "commit-msg")
+		if existing, err := os.ReadFile(hookPath); err == nil {
+			if string(existing) == commitMsgHook {
+				printSuccess("The tix commit-msg hook is already installed in %s", hookPath)
+				return nil
+			}
+			if !installHooksForce {
+				return fmt.Errorf("%s already exists - rerun with --force to replace it", hookPath)
+			}
+		}
+
+		if err := os.MkdirAll(hooksDir, 0755); err != nil {
+			return fmt.Errorf("failed to create hooks directory %s: %w", hooksDir, err)
+		}
+		if err := os.WriteFile(hookPath, []byte(commitMsgHook), 0755); err != nil {
+			return fmt.Errorf("failed to write %s: %w", hookPath, err)
+		}
+		// WriteFile keeps the mode of a file it replaces
+		if err := os.Chmod(hookPath, 0755); err != nil {
+			return fmt.Errorf("failed to make %s executable: %w", hookPath, err)
+		}
+		printSuccess("Installed the commit-msg hook in %s", hookPath)
+
+		logger.Debug("Install-hooks command completed successfully")
+		return nil
+	},
+}
+
+var hookCmd = &cobra.Command{
+	Use:    "hook commit-msg <message-file>",
+	Short:  "Run a git hook installed by tix install-hooks",
+	Hidden: true,
+	Args:   cobra.ExactArgs(2),
+	RunE: func(cmd *cobra.Command, args []string) error {
+		if args[0] != "commit-msg" {
+			return fmt.Errorf("unknown hook %s", args[0])
+		}
+		// A hook that fails would abort the commit, so problems are only
+		// warnings
+		if err := runCommitMsgHook(args[1]); err != nil {
+			printWarning("tix commit-msg hook: %v", err)
+		}
+		return nil
+	},
+}
+
+// runCommitMsgHook adds the reference to the branch's issue to the commit
+// message in messageFile. Branches that don't carry an issue are left alone.
+func runCommitMsgHook(messageFile string) error {
+	wd, err := os.Getwd()
+	if err != nil {
+		return err
+	}
+	branch, err := git.GetBranchFromDir(wd)
+	if err != nil {
+		return nil
+	}
+
+	cfg, err := utils.LoadConfig()
+	if err != nil {
+		cfg = &config.Settings{}
+	}
+	repo, _ := utils.MatchRepository(cfg, wd)
+	if repo == nil {
+		repo = &config.Repository{}
+	}
+	ref, ok := commitRef(cfg, repo, wd, branch)
+	if !ok {
+		return nil
+	}
+
+	message, err := os.ReadFile(messageFile)
+	if err != nil {
+		return err
+	}
+	updated := utils.AppendIssueRef(string(message), ref)
+	if updated == string(message) {
+		return nil
+	}
+	return os.WriteFile(messageFile, []byte(updated), 0644)
+}
+
+// commitRef returns the line commit_ref_format gives commits on branch of
+// the checkout at dir in repo, and false when the branch doesn't carry an
+// issue number. A cross-repo branch only does when its prefix is a configured
+// repository, so names such as release-2 are left alone.
+func commitRef(cfg *config.Settings, repo *config.Repository, dir, branch string) (string, bool) {
+	project, number, err := utils.BranchIssueInfo(dir, branch)
+	if err != nil {
+		return "", false
+	}
+
+	ref := fmt.Sprintf("#%d", number)
+	if project != "" && project != repo.Name {
+		issueRepo := cfg.GetRepo(project)
+		if issueRepo == nil {
+			return "", false
+		}
+		slug := issueRepo.GithubRepo
+		if issueRepo.Provider() == config.ProviderGitLab {
+			slug = issueRepo.GitlabRepo
+		}
+		ref = slug + ref
+	}
+	return strings.NewReplacer("{id}", strconv.Itoa(number), "{ref}", ref).Replace(cfg.ResolveCommitRefFormat(repo)), true
+}
+
+func init() {
+	rootCmd.AddCommand(installHooksCmd)
+	rootCmd.AddCommand(hookCmd)
+	installHooksCmd.Flags().BoolVar(&installHooksForce, "force", false, "Replace an existing commit-msg hook")
+}
@@ -0,0 +1,96 @@
+package cmd
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+)
+
+func TestCommitRef(t *testing.T) {
+	cfg := &config.Settings{Repositories: []config.Repository{
+		{Name: "app", GithubRepo: "owner/app"},
+		{Name: "docs", GitlabRepo: "group/docs"},
+		{Name: "mirror", GithubRepo: "owner/mirror", GitlabRepo: "group/mirror"},
+	}}
+	app := &cfg.Repositories[0]
+
+	tests := []struct {
+		name   string
+		format string
+		branch string
+		want   string
+		wantOK bool
+	}{
+		{name: "default format", branch: "42-fix-login", want: "Refs #42", wantOK: true},
+		{name: "custom format", format: "Issue: {id}", branch: "feature/42-fix-login", want: "Issue: 42", wantOK: true},
+		{name: "cross-repo branch", branch: "docs-7-fix-typo", want: "Refs group/docs#7", wantOK: true},
+		{name: "mirrored cross-repo branch", branch: "mirror-3-fix-typo", want: "Refs group/mirror#3", wantOK: true},
+		{name: "prefix that isn't a repository", branch: "release-2"},
+		{name: "branch without an issue", branch: "main"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cfg.CommitRefFormat = tt.format
+			got, ok := commitRef(cfg, app, t.TempDir(), tt.branch)
+			if got != tt.want || ok != tt.wantOK {
+				t.Errorf("commitRef(%q) = %q, %v, want %q, %v", tt.branch, got, ok, tt.want, tt.wantOK)
+			}
+		})
+	}
+}
+
+func TestInstallHooks(t *testing.T) {
+	dir := newTestGitDir(t)
+	wd, err := os.Getwd()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chdir(dir); err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = os.Chdir(wd) }()
+	t.Cleanup(func() { installHooksForce = false })
+	hookPath := filepath.Join(dir, ".git", "hooks", "commit-msg")
+
+	if err := installHooksCmd.RunE(installHooksCmd, nil); err != nil {
+		t.Fatalf("install-hooks error: %v", err)
+	}
+	info, err := os.Stat(hookPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if info.Mode()&0111 == 0 {
+		t.Errorf("hook mode = %v, want it executable", info.Mode())
+	}
+	// Installing again is a no-op
+	if err := installHooksCmd.RunE(installHooksCmd, nil); err != nil {
+		t.Errorf("reinstalling error: %v", err)
+	}
+
+	// Someone else's hook is kept unless --force is given
+	if err := os.WriteFile(hookPath, []byte("#!/bin/sh\nexit 0\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	err = installHooksCmd.RunE(installHooksCmd, nil)
+	if err == nil || !strings.Contains(err.Error(), "--force") {
+		t.Fatalf("install-hooks over an existing hook error = %v, want a refusal mentioning --force", err)
+	}
+	installHooksForce = true
+	if err := installHooksCmd.RunE(installHooksCmd, nil); err != nil {
+		t.Fatalf("install-hooks --force error: %v", err)
+	}
+	got, err := os.ReadFile(hookPath)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(got) != commitMsgHook {
+		t.Errorf("hook = %q, want the tix hook", got)
+	}
+	if info, err := os.Stat(hookPath); err != nil || info.Mode()&0111 == 0 {
+		t.Errorf("replaced hook isn't executable: %v", err)
+	}
+}
@@ -0,0 +1,142 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/pterm/pterm"
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var (
+	listLimit int
+	listSince string
+)
+
+var listCmd = &cobra.Command{
+	Use:   "list [repo]",
+	Short: "List open issues",
+	Long: `List the open issues of a repository, newest first.
+
+The repository is taken from the argument, or the configured repository
+matching the current directory, or chosen interactively.
+
+Results are fetched page by page and printed as they arrive, up to --limit
+issues (0 for no limit). Use --since to only show issues updated since a date
+(2006-01-02) or an age such as 7d or 12h.`,
+	Args: cobra.MaximumNArgs(1),
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting list command")
+
+		if listLimit < 0 {
+			return fmt.Errorf("--limit must be 0 (no limit) or more")
+		}
+
+		var since time.Time
+		if listSince != "" {
+			var err error
+			since, err = utils.ParseSince(listSince, time.Now())
+			if err != nil {
+				return err
+			}
+		}
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		repo, repoName, err := selectConfiguredRepository(cfg, args)
+		if err != nil {
+			return err
+		}
+
+		provider, err := utils.CreateSCMProvider(&utils.SharedRepoInfo{
+			Repo:     repo,
+			Name:     repoName,
+			IsGitLab: repo.GitlabRepo != "",
+		})
+		if err != nil {
+			return err
+		}
+
+		count := 0
+		err = provider.ListIssues(services.ListIssuesOptions{Limit: listLimit, Since: since}, func(issue services.IssueResult) {
+			count++
+			line := fmt.Sprintf("#%-6d %s", issue.Number, issue.Title)
+			if len(issue.Labels) > 0 {
+				line += fmt.Sprintf("  [%s]", strings.Join(issue.Labels, ", "))
+			}
+			fmt.Println(line)
+		})
+		if err != nil {
+			return err
+		}
+
+		if count == 0 {
+			fmt.Printf("No open issues in %s.\n", repoName)
+		}
+
+		logger.Debug("List command completed successfully")
+		return nil
+	},
+}
+
+// selectConfiguredRepository picks the repository to work with: the named
+// argument, the code repo matching the current directory, or a prompt. A
+// repository on both GitHub and GitLab is narrowed to one of them.
+func selectConfiguredRepository(cfg *config.Settings, args []string) (*config.Repository, string, error) {
+	repo, repoName, err := findConfiguredRepository(cfg, args)
+	if err != nil {
+		return nil, "", err
+	}
+	if err := utils.ChooseProvider(repo, true); err != nil {
+		return nil, "", err
+	}
+	return repo, repoName, nil
+}
+
+func findConfiguredRepository(cfg *config.Settings, args []string) (*config.Repository, string, error) {
+	if len(args) == 1 {
+		repo, err := cfg.FindRepo(args[0])
+		if err != nil {
+			return nil, "", err
+		}
+		return repo, repo.Name, nil
+	}
+
+	wd, err := os.Getwd()
+	if err != nil {
+		return nil, "", fmt.Errorf("failed to determine current directory")
+	}
+
+	repoNames := cfg.GetRepoNames()
+	if matchingRepo, repoName := utils.MatchRepository(cfg, wd); matchingRepo != nil {
+		return matchingRepo, repoName, nil
+	}
+
+	if len(repoNames) == 0 {
+		return nil, "", fmt.Errorf("no repositories configured")
+	}
+
+	selected, err := pterm.DefaultInteractiveSelect.
+		WithOptions(repoNames).
+		WithDefaultText("Select a repository").
+		Show()
+	if err != nil {
+		return nil, "", fmt.Errorf("repository selection cancelled")
+	}
+	return cfg.GetRepo(selected), selected, nil
+}
+
+func init() {
+	rootCmd.AddCommand(listCmd)
+	listCmd.Flags().IntVarP(&listLimit, "limit", "l", services.DefaultListLimit, "Maximum number of issues to list (0 for no limit)")
+	listCmd.Flags().StringVar(&listSince, "since", "", "Only list issues updated since a date (2006-01-02) or an age like 7d or 12h")
+}
@@ -0,0 +1,29 @@
+package cmd
+
+import (
+	"strconv"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/services"
+)
+
+func TestListFlags(t *testing.T) {
+	limit := listCmd.Flags().Lookup("limit")
+	if limit == nil {
+		t.Fatal("expected --limit flag to be registered on listCmd")
+	}
+	if limit.Shorthand != "l" {
+		t.Errorf("expected --limit shorthand 'l', got %q", limit.Shorthand)
+	}
+	if limit.DefValue != strconv.Itoa(services.DefaultListLimit) {
+		t.Errorf("expected --limit default %d, got %q", services.DefaultListLimit, limit.DefValue)
+	}
+
+	since := listCmd.Flags().Lookup("since")
+	if since == nil {
+		t.Fatal("expected --since flag to be registered on listCmd")
+	}
+	if since.DefValue != "" {
+		t.Errorf("expected --since default empty, got %q", since.DefValue)
+	}
+}
@@ -0,0 +1,158 @@
+package cmd
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var moveFrom string
+
+var moveCmd = &cobra.Command{
+	Use:   "move <target> [issue-number]",
+	Short: "Move an issue to another project or repository",
+	Long: `Move an issue to another GitLab project, or transfer it to another GitHub
+repository, for tickets filed in the wrong place.
+
+The target is a configured repository name or a "group/project" (GitLab) or
+"owner/repo" (GitHub) path, and must use the same provider as the issue.
+
+Without an issue number, the issue is taken from the current branch name.
+With one, the issue's repository is --from, or the configured repository
+matching the current directory, or chosen interactively.
+
+Examples:
+  tix move backend              # Move the current branch's issue to 'backend'
+  tix move acme/website 42      # Transfer issue #42 to acme/website`,
+	Args: cobra.RangeArgs(1, 2),
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting move command")
+
+		target := args[0]
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		var repoInfo *utils.SharedRepoInfo
+		if len(args) == 2 {
+			issueNumber, err := strconv.Atoi(strings.TrimPrefix(args[1], "#"))
+			if err != nil || issueNumber <= 0 {
+				return fmt.Errorf("invalid issue number: %s", args[1])
+			}
+
+			var fromArgs []string
+			if moveFrom != "" {
+				fromArgs = []string{moveFrom}
+			}
+			repo, repoName, err := selectConfiguredRepository(cfg, fromArgs)
+			if err != nil {
+				return err
+			}
+			repoInfo = &utils.SharedRepoInfo{
+				Repo:        repo,
+				Name:        repoName,
+				IsGitLab:    repo.GitlabRepo != "",
+				IssueNumber: issueNumber,
+			}
+		} else {
+			repoInfo, err = utils.SelectSharedRepository()
+			if err != nil {
+				return err
+			}
+		}
+
+		targetPath, err := resolveMoveTarget(cfg, target, repoInfo.Repo)
+		if err != nil {
+			return err
+		}
+
+		provider, err := utils.CreateSCMProvider(repoInfo)
+		if err != nil {
+			return err
+		}
+		mover, ok := provider.(services.IssueMover)
+		if !ok {
+			return fmt.Errorf("moving issues isn't supported for %s", provider.GetURL())
+		}
+
+		logger.Info("Moving issue", map[string]interface{}{
+			"issue":  repoInfo.IssueNumber,
+			"from":   repoInfo.Name,
+			"target": targetPath,
+		})
+
+		moved, err := mover.MoveIssue(repoInfo.IssueNumber, targetPath)
+		if err != nil {
+			return err
+		}
+
+		if moved.URL != "" {
+			printSuccess("Moved issue #%d to %s: %s", repoInfo.IssueNumber, targetPath, moved.URL)
+		} else {
+			printSuccess("Moved issue #%d to %s as #%d", repoInfo.IssueNumber, targetPath, moved.Number)
+		}
+		if repoInfo.Branch != "" {
+			fmt.Printf("Branch %s still refers to the old issue #%d.\n", repoInfo.Branch, repoInfo.IssueNumber)
+		}
+
+		logger.Debug("Move command completed successfully")
+		return nil
+	},
+}
+
+// resolveMoveTarget turns a move target into a project/repo path: either a
+// configured repository on the same provider as source, or a path in the
+// provider's format ("group/subgroup/project" or "owner/repo")
+func resolveMoveTarget(cfg *config.Settings, target string, source *config.Repository) (string, error) {
+	isGitLab := source.GitlabRepo != ""
+	sourcePath := source.GithubRepo
+	if isGitLab {
+		sourcePath = source.GitlabRepo
+	}
+
+	path := target
+	if repo := cfg.GetRepo(target); repo != nil {
+		path = repo.GithubRepo
+		if isGitLab {
+			path = repo.GitlabRepo
+		}
+		if path == "" {
+			return "", fmt.Errorf("repository '%s' uses a different provider - issues can only move between repositories on the same forge", target)
+		}
+	} else {
+		parts := strings.Split(target, "/")
+		valid := len(parts) >= 2
+		for _, part := range parts {
+			if part == "" {
+				valid = false
+			}
+		}
+		if !isGitLab && len(parts) != 2 {
+			valid = false
+		}
+		if !valid {
+			if isGitLab {
+				return "", fmt.Errorf("target '%s' is not a configured repository or a group/project path", target)
+			}
+			return "", fmt.Errorf("target '%s' is not a configured repository or an owner/repo path", target)
+		}
+	}
+
+	if strings.EqualFold(path, sourcePath) {
+		return "", fmt.Errorf("the issue is already in %s", path)
+	}
+	return path, nil
+}
+
+func init() {
+	rootCmd.AddCommand(moveCmd)
+	moveCmd.Flags().StringVar(&moveFrom, "from", "", "Configured repository the issue is in (when an issue number is given)")
+}
@@ -0,0 +1,55 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+)
+
+func TestResolveMoveTarget(t *testing.T) {
+	cfg := &config.Settings{
+		Repositories: []config.Repository{
+			{Name: "app", GitlabRepo: "acme/app"},
+			{Name: "backend", GitlabRepo: "acme/services/backend"},
+			{Name: "website", GithubRepo: "acme/website"},
+		},
+	}
+	gitlabSource := cfg.GetRepo("app")
+	githubSource := &config.Repository{Name: "docs", GithubRepo: "acme/docs"}
+
+	tests := []struct {
+		name    string
+		target  string
+		source  *config.Repository
+		want    string
+		wantErr string
+	}{
+		{name: "configured repo", target: "backend", source: gitlabSource, want: "acme/services/backend"},
+		{name: "gitlab nested path", target: "acme/other/project", source: gitlabSource, want: "acme/other/project"},
+		{name: "github path", target: "acme/website", source: githubSource, want: "acme/website"},
+		{name: "configured repo on another forge", target: "website", source: gitlabSource, wantErr: "different provider"},
+		{name: "github nested path", target: "acme/a/b", source: githubSource, wantErr: "owner/repo path"},
+		{name: "bare unknown name", target: "nowhere", source: gitlabSource, wantErr: "not a configured repository"},
+		{name: "empty segment", target: "acme//app", source: gitlabSource, wantErr: "not a configured repository"},
+		{name: "same project", target: "app", source: gitlabSource, wantErr: "already in acme/app"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := resolveMoveTarget(cfg, tt.target, tt.source)
+			if tt.wantErr != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+					t.Errorf("resolveMoveTarget(%q) error = %v, want it to contain %q", tt.target, err, tt.wantErr)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("resolveMoveTarget(%q) error: %v", tt.target, err)
+			}
+			if got != tt.want {
+				t.Errorf("resolveMoveTarget(%q) = %q, want %q", tt.target, got, tt.want)
+			}
+		})
+	}
+}
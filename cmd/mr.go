@@ -3,7 +3,6 @@ package cmd
 import (
 	"fmt"
 	"os"
-	"path/filepath"
 	"strings"
 
 	"github.com/pterm/pterm"
@@ -30,10 +29,12 @@ It will extract the issue number from the branch name and create a merge request
 		remote, _ := cmd.Flags().GetString("remote")
 		// Get the auto-merge flag value
 		autoMerge, _ := cmd.Flags().GetBool("auto-merge")
+		// Get the close-keyword flag value
+		closeKeywordFlag, _ := cmd.Flags().GetString("close-keyword")
 
-		cfg, err := config.Load()
+		cfg, err := utils.LoadConfig()
 		if err != nil {
-			return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
+			return configLoadError(err)
 		}
 
 		logger.Debug("Config loaded successfully", map[string]interface{}{
@@ -50,30 +51,8 @@ It will extract the issue number from the branch name and create a merge request
 			"directory": wd,
 		})
 
-		// Find repo that matches the current directory (only code repos)
-		var matchingRepo *config.Repository
-		var repoName string
-		var bestMatchLength = 0 // Track the best match length
-
-		for i, repo := range cfg.Repositories {
-			if !repo.IsCodeRepo() {
-				continue
-			}
-			absRepoDir, err := filepath.Abs(repo.Directory)
-			if err != nil {
-				continue
-			}
-
-			// Check if current directory is within the repo directory
-			if strings.HasPrefix(wd, absRepoDir) {
-				// If we found a better match (longer path), use it
-				if len(absRepoDir) > bestMatchLength {
-					matchingRepo = &cfg.Repositories[i]
-					repoName = cfg.GetRepoNames()[i]
-					bestMatchLength = len(absRepoDir)
-				}
-			}
-		}
+		// Find the code repo the current directory belongs to
+		matchingRepo, repoName := utils.MatchRepository(cfg, wd)
 
 		// If no matching repo found, show selector (only code repos)
 		if matchingRepo == nil {
@@ -125,7 +104,7 @@ It will extract the issue number from the branch name and create a merge request
 		})
 
 		// Extract issue info from branch name (may include project name for cross-repo)
-		projectName, issueNumber, err := utils.ExtractIssueInfo(currentBranch)
+		projectName, issueNumber, err := utils.BranchIssueInfo(wd, currentBranch)
 		if err != nil {
 			return fmt.Errorf("couldn't extract issue number from branch name '%s' - use a branch like '123-my-feature' or 'project-123-my-feature'", currentBranch)
 		}
@@ -141,6 +120,28 @@ It will extract the issue number from the branch name and create a merge request
 			targetBranch = matchingRepo.DefaultBranch
 		}
 
+		// Resolve the keyword that links the MR to the issue (flag > config)
+		var closeKeyword string
+		if closeKeywordFlag != "" {
+			closeKeyword, err = config.NormalizeCloseKeyword(closeKeywordFlag)
+		} else {
+			closeKeyword, err = cfg.ResolveCloseKeyword(matchingRepo)
+		}
+		if err != nil {
+			return err
+		}
+
+		// A mirrored code repo follows the provider of a cross-repo issue repo
+		pairedRepo := matchingRepo
+		if projectName != "" && projectName != repoName {
+			if issueRepo := cfg.GetRepo(projectName); issueRepo != nil {
+				pairedRepo = issueRepo
+			}
+		}
+		if err := utils.ChoosePairedProvider(matchingRepo, pairedRepo, true); err != nil {
+			return err
+		}
+
 		// Create SCM provider for MR repo (where the code is)
 		var provider services.SCMProvider
 
@@ -168,6 +169,9 @@ It will extract the issue number from the branch name and create a merge request
 			}
 
 			// Validate providers match
+			if err := utils.MatchProvider(issueRepo, matchingRepo); err != nil {
+				return err
+			}
 			if (matchingRepo.GithubRepo != "" && issueRepo.GitlabRepo != "") ||
 				(matchingRepo.GitlabRepo != "" && issueRepo.GithubRepo != "") {
 				return fmt.Errorf("issue repo '%s' and code repo '%s' must use the same provider", projectName, repoName)
@@ -208,6 +212,7 @@ It will extract the issue number from the branch name and create a merge request
 				AutoMerge:          autoMerge,
 				IssueProvider:      issueProvider,
 				CrossRepoIssueRef:  crossRepoIssueRef,
+				CloseKeyword:       closeKeyword,
 			},
 		)
 
@@ -236,7 +241,7 @@ It will extract the issue number from the branch name and create a merge request
 			return fmt.Errorf("failed to create request: %w", err)
 		}
 
-		fmt.Printf("Created request: %s\n", request.URL)
+		printSuccess("Created request: %s", request.URL)
 
 		logger.Debug("MR command completed successfully")
 		return nil
@@ -248,4 +253,5 @@ func init() {
 	mrCmd.Flags().BoolP("draft", "d", false, "Create the merge request as a draft")
 	mrCmd.Flags().StringP("remote", "r", "origin", "Git remote to push to")
 	mrCmd.Flags().BoolP("auto-merge", "a", false, "Enable auto-merge when the pipeline succeeds")
+	mrCmd.Flags().String("close-keyword", "", "Keyword used to close the issue on merge: Closes, Fixes or Resolves (overrides close_keyword)")
 }
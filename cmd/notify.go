@@ -0,0 +1,34 @@
+package cmd
+
+import (
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/state"
+)
+
+// issueCreated records a newly filed issue in the history log and sends the
+// notify_webhook notification. Neither can fail the command since the issue
+// already exists.
+func issueCreated(cfg *config.Settings, repo *config.Repository, entry state.HistoryEntry) {
+	recordHistory(cfg, entry)
+	notifyWebhook(cfg, repo, entry)
+}
+
+// notifyWebhook POSTs the issue to the repo's notify_webhook, if one is set.
+// A failure is only a warning.
+func notifyWebhook(cfg *config.Settings, repo *config.Repository, entry state.HistoryEntry) {
+	url := cfg.ResolveNotifyWebhook(repo)
+	if url == "" {
+		return
+	}
+
+	payload := services.NewIssueCreatedPayload(entry.Repo, entry.Provider, entry.IssueNumber, entry.Title, entry.URL, entry.Branch)
+	if err := services.PostWebhook(url, payload); err != nil {
+		printWarning("couldn't send the notify_webhook notification: %v", err)
+		return
+	}
+	logger.Debug("Sent issue notification", map[string]interface{}{
+		"issue": entry.IssueNumber,
+	})
+}
@@ -0,0 +1,92 @@
+package cmd
+
+import (
+	"fmt"
+	"os"
+	"strconv"
+
+	"github.com/mattn/go-isatty"
+	"github.com/pterm/pterm"
+	"github.com/tedkulp/tix/internal/logger"
+)
+
+// Values accepted by --color
+const (
+	colorAuto   = "auto"
+	colorAlways = "always"
+	colorNever  = "never"
+)
+
+// colorEnabled decides whether output to a stream is styled. "always" and
+// "never" are explicit; "auto" colors only when the stream is a terminal and
+// NO_COLOR is unset.
+func colorEnabled(mode string, noColorEnv bool, isTTY bool) (bool, error) {
+	switch mode {
+	case colorAlways:
+		return true, nil
+	case colorNever:
+		return false, nil
+	case colorAuto, "":
+		return isTTY && !noColorEnv, nil
+	}
+	return false, fmt.Errorf("invalid --color %q - use auto, always or never", mode)
+}
+
+// Whether stdout and stderr are styled. They start out as --color auto would
+// set them, for errors reported before the flags are parsed.
+var (
+	stdoutColor = autoColor(os.Stdout)
+	stderrColor = autoColor(os.Stderr)
+)
+
+func isTerminal(f *os.File) bool {
+	return isatty.IsTerminal(f.Fd()) || isatty.IsCygwinTerminal(f.Fd())
+}
+
+func autoColor(f *os.File) bool {
+	enabled, _ := colorEnabled(colorAuto, os.Getenv("NO_COLOR") != "", isTerminal(f))
+	return enabled
+}
+
+// applyColorMode decides for stdout and stderr on their own whether output
+// is styled, and sets up pterm and the logger, which write to stdout
+func applyColorMode(mode string) error {
+	noColorEnv := os.Getenv("NO_COLOR") != ""
+	out, err := colorEnabled(mode, noColorEnv, isTerminal(os.Stdout))
+	if err != nil {
+		return err
+	}
+	errOut, _ := colorEnabled(mode, noColorEnv, isTerminal(os.Stderr))
+	stdoutColor, stderrColor = out, errOut
+	useColor(stdoutColor)
+	return nil
+}
+
+// useColor turns styled output on or off for pterm and the logger
+func useColor(enabled bool) {
+	if enabled {
+		pterm.EnableColor()
+	} else {
+		pterm.DisableColor()
+	}
+	logger.SetColor(enabled)
+}
+
+// stderrStyle colors text for stderr when stderr is styled. pterm's colors
+// follow stdout, so this doesn't go through them.
+func stderrStyle(color pterm.Color, text string) string {
+	if !stderrColor {
+		return text
+	}
+	return "\x1b[" + strconv.Itoa(int(color)) + "m" + text + "\x1b[0m"
+}
+
+// printSuccess prints a line reporting something tix did, in green
+func printSuccess(format string, args ...interface{}) {
+	fmt.Println(pterm.FgGreen.Sprintf(format, args...))
+}
+
+// printWarning prints a "Warning: " line to stderr, in yellow
+func printWarning(format string, args ...interface{}) {
+	fmt.Fprintln(os.Stderr, stderrStyle(pterm.FgYellow, fmt.Sprintf("Warning: "+format, args...)))
+}
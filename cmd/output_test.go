@@ -0,0 +1,56 @@
+package cmd
+
+import (
+	"testing"
+
+	"github.com/pterm/pterm"
+)
+
+func TestColorEnabled(t *testing.T) {
+	tests := []struct {
+		mode    string
+		noColor bool
+		isTTY   bool
+		want    bool
+		wantErr bool
+	}{
+		{mode: "auto", isTTY: true, want: true},
+		{mode: "auto", isTTY: false, want: false},
+		{mode: "auto", noColor: true, isTTY: true, want: false},
+		{mode: "always", noColor: true, isTTY: false, want: true},
+		{mode: "never", isTTY: true, want: false},
+		{mode: "sometimes", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		got, err := colorEnabled(tt.mode, tt.noColor, tt.isTTY)
+		if tt.wantErr {
+			if err == nil {
+				t.Errorf("colorEnabled(%q) expected an error", tt.mode)
+			}
+			continue
+		}
+		if err != nil {
+			t.Errorf("colorEnabled(%q) unexpected error: %v", tt.mode, err)
+			continue
+		}
+		if got != tt.want {
+			t.Errorf("colorEnabled(%q, noColor=%v, tty=%v) = %v, want %v", tt.mode, tt.noColor, tt.isTTY, got, tt.want)
+		}
+	}
+}
+
+func TestStderrStyle(t *testing.T) {
+	saved := stderrColor
+	defer func() { stderrColor = saved }()
+
+	stderrColor = false
+	if got := stderrStyle(pterm.FgRed, "Error: boom"); got != "Error: boom" {
+		t.Errorf("stderrStyle() with stderr color off = %q, want plain text", got)
+	}
+
+	stderrColor = true
+	if got, want := stderrStyle(pterm.FgRed, "Error: boom"), "\x1b[31mError: boom\x1b[0m"; got != want {
+		t.Errorf("stderrStyle() with stderr color on = %q, want %q", got, want)
+	}
+}
@@ -1,16 +1,26 @@
 package cmd
 
 import (
+	"errors"
 	"fmt"
 	"os"
+	"strings"
 
+	"github.com/pterm/pterm"
 	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
 	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/utils"
 )
 
 // Flag variables
 var (
-	verboseCount int
+	verboseCount  int
+	configFile    string
+	colorMode     string
+	waitRateLimit bool
+	providerName  string
 )
 
 var rootCmd = &cobra.Command{
@@ -28,9 +38,23 @@ in your Git repositories, with support for both GitHub and GitLab.`,
 			return nil
 		}
 
+		if err := applyColorMode(colorMode); err != nil {
+			return err
+		}
+
 		// Initialize logger with verbose count
 		logger.InitLogger(verboseCount)
 
+		if configFile != "" {
+			config.SetConfigFile(configFile)
+		}
+		if waitRateLimit {
+			services.SetRateLimitWait(services.MaxRateLimitWait)
+		}
+		if err := utils.SetProvider(providerName); err != nil {
+			return err
+		}
+
 		switch verboseCount {
 		case 0:
 			// WARN level - no startup message needed
@@ -71,14 +95,73 @@ in your Git repositories, with support for both GitHub and GitLab.`,
 	},
 }
 
+// configLoadError turns a config file read/parse failure into a short hint and
+// passes other errors (e.g. an unreadable token file) through unchanged
+func configLoadError(err error) error {
+	logger.Info("Failed to load config", map[string]interface{}{
+		"error": err.Error(),
+	})
+	if errors.Is(err, config.ErrConfigNotFound) {
+		return missingConfigError()
+	}
+	if strings.Contains(err.Error(), "failed to read config") || strings.Contains(err.Error(), "failed to unmarshal config") {
+		return fmt.Errorf("couldn't load configuration file. Run with --verbose for details, or 'tix config edit' to fix it")
+	}
+	return err
+}
+
+// exampleConfig is the smallest useful config file, shown to first-time users
+const exampleConfig = `repositories:
+  - name: app
+    directory: ~/src/app
+    github_repo: owner/app     # or gitlab_repo: group/app`
+
+// missingConfigError explains where tix looks for its config file and what a
+// minimal one looks like, for a first run with no config
+func missingConfigError() error {
+	path, err := config.ConfigPath()
+	if err != nil {
+		path = "~/.tix.yml"
+	}
+	return fmt.Errorf("no config file found at %s\n\n"+
+		"tix needs one listing the repositories to create tickets in. A minimal one:\n\n%s\n\n"+
+		"Save it there (or run 'tix config edit' to start from it), or point tix at\n"+
+		"another file with --config or TIX_CONFIG.\n"+
+		"See the Configuration section of the README for every setting", path, exampleConfig)
+}
+
+// stdoutForScripting points human output (fmt, pterm and the logger) at stderr
+// so stdout carries only machine-readable output. It returns the real stdout
+// and a func that restores the original outputs.
+func stdoutForScripting() (*os.File, func()) {
+	stdout := os.Stdout
+	os.Stdout = os.Stderr
+	useColor(stderrColor)
+	pterm.SetDefaultOutput(os.Stderr)
+	logger.SetOutput(os.Stderr)
+
+	return stdout, func() {
+		os.Stdout = stdout
+		useColor(stdoutColor)
+		pterm.SetDefaultOutput(stdout)
+		logger.SetOutput(stdout)
+	}
+}
+
 func Execute() error {
 	err := rootCmd.Execute()
 	// Handle errors here instead of Cobra's default handling
 	if err != nil {
+		// The rate limit message says all there is to say; drop the
+		// request and call site context wrapped around it
+		var rateErr *services.RateLimitError
+		if errors.As(err, &rateErr) {
+			err = rateErr
+		}
 		if verboseCount > 0 {
 			logger.Error(err.Error(), err)
 		} else {
-			fmt.Fprintf(os.Stderr, "Error: %s\n", err.Error())
+			fmt.Fprintln(os.Stderr, stderrStyle(pterm.FgRed, "Error: "+err.Error()))
 		}
 		return err
 	}
@@ -89,6 +172,9 @@ func init() {
 	// Here you will define your flags and configuration settings.
 	// Cobra supports persistent flags, which, if defined here,
 	// will be global for your application.
-	rootCmd.PersistentFlags().StringP("config", "c", "~/.tix.yml", "config file (default is $HOME/.tix.yml)")
+	rootCmd.PersistentFlags().StringVarP(&configFile, "config", "c", "", "config file (default is $TIX_CONFIG, or $HOME/.tix.yml)")
+	rootCmd.PersistentFlags().StringVar(&colorMode, "color", colorAuto, "when to color output: auto (terminal and no NO_COLOR), always or never")
+	rootCmd.PersistentFlags().BoolVar(&waitRateLimit, "wait", false, "when GitHub's rate limit is exceeded, wait up to 5 minutes for it to reset and retry")
+	rootCmd.PersistentFlags().StringVar(&providerName, "provider", "", "for repositories on both GitHub and GitLab, the one to use this run: github or gitlab (default is primary_provider)")
 	rootCmd.PersistentFlags().CountVarP(&verboseCount, "verbose", "v", "increase verbosity: -v for INFO, -vv for DEBUG (default: WARN)")
 }
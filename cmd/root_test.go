@@ -0,0 +1,29 @@
+package cmd
+
+import (
+	"fmt"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+)
+
+func TestConfigLoadErrorMissingConfig(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tix.yml")
+	config.SetConfigFile(path)
+	defer config.SetConfigFile("")
+
+	_, err := config.Load()
+	msg := configLoadError(err).Error()
+	for _, want := range []string{"no config file found at " + path, "repositories:", "--config or TIX_CONFIG"} {
+		if !strings.Contains(msg, want) {
+			t.Errorf("configLoadError() = %q, missing %q", msg, want)
+		}
+	}
+
+	other := fmt.Errorf("failed to read config file: bad yaml")
+	if got := configLoadError(other).Error(); strings.Contains(got, "no config file found") {
+		t.Errorf("configLoadError() for a parse error = %q, want the generic hint", got)
+	}
+}
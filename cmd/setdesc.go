@@ -2,9 +2,9 @@ package cmd
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"os"
-	"path/filepath"
 	"strings"
 
 	"github.com/pterm/pterm"
@@ -70,7 +70,12 @@ GitHub users can also use --only-pr (-p) as an alternative.`,
 		// Find and select repository
 		repoInfo, err := selectRepository()
 		if err != nil {
-			// Provide cleaner error messages for common setup issues
+			// Provide cleaner error messages for common setup issues; type errors
+			// in the config already name the bad settings
+			var decodeErr *config.DecodeError
+			if errors.As(err, &decodeErr) {
+				return decodeErr
+			}
 			if strings.Contains(err.Error(), "failed to load config") {
 				return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
 			}
@@ -129,7 +134,7 @@ GitHub users can also use --only-pr (-p) as an alternative.`,
 
 // selectRepository determines which repository to work with
 func selectRepository() (*RepoInfo, error) {
-	cfg, err := config.Load()
+	cfg, err := utils.LoadConfig()
 	if err != nil {
 		return nil, fmt.Errorf("failed to load config: %w", err)
 	}
@@ -148,32 +153,9 @@ func selectRepository() (*RepoInfo, error) {
 		"directory": wd,
 	})
 
-	// Find repo that matches the current directory, or the best candidate
-	// Only consider code repos (repos with directory)
-	var matchingRepo *config.Repository
-	var repoName string
-	bestMatchLength := 0
-
+	// Find the code repo the current directory belongs to, if any
 	repoNames := cfg.GetRepoNames()
-	for i, repo := range cfg.Repositories {
-		if !repo.IsCodeRepo() {
-			continue
-		}
-		absRepoDir, err := filepath.Abs(repo.Directory)
-		if err != nil {
-			continue
-		}
-
-		// Check if current directory is within the repo directory
-		if strings.HasPrefix(wd, absRepoDir) {
-			// If we found a better match (longer path prefix), use it
-			if len(absRepoDir) > bestMatchLength {
-				matchingRepo = &cfg.Repositories[i]
-				repoName = cfg.GetRepoNames()[i]
-				bestMatchLength = len(absRepoDir)
-			}
-		}
-	}
+	matchingRepo, repoName := utils.MatchRepository(cfg, wd)
 
 	// If we found a match, we'll offer it as the default option
 	if matchingRepo != nil {
@@ -232,19 +214,13 @@ func selectRepository() (*RepoInfo, error) {
 	})
 
 	// Validate repository configuration
-	if (selectedRepo.GithubRepo == "" && selectedRepo.GitlabRepo == "") ||
-		(selectedRepo.GithubRepo != "" && selectedRepo.GitlabRepo != "") {
+	if selectedRepo.GithubRepo == "" && selectedRepo.GitlabRepo == "" {
 		logger.Error("Invalid repository configuration", nil, map[string]any{
-			"repo":        selectedRepoName,
-			"github_repo": selectedRepo.GithubRepo,
-			"gitlab_repo": selectedRepo.GitlabRepo,
+			"repo": selectedRepoName,
 		})
-		return nil, fmt.Errorf("repository must have exactly one of github_repo or gitlab_repo")
+		return nil, fmt.Errorf("repository must have github_repo or gitlab_repo")
 	}
 
-	// Determine if GitLab or GitHub repository
-	isGitlab := selectedRepo.GitlabRepo != ""
-
 	// Get current branch from the working directory — handles git worktrees correctly.
 	currentBranch, err := git.GetBranchFromDir(wd)
 	if err != nil {
@@ -256,7 +232,7 @@ func selectRepository() (*RepoInfo, error) {
 	})
 
 	// Extract issue info from branch name (may include project name for cross-repo)
-	projectName, issueNumber, err := utils.ExtractIssueInfo(currentBranch)
+	projectName, issueNumber, err := utils.BranchIssueInfo(wd, currentBranch)
 	if err != nil {
 		return nil, fmt.Errorf("failed to extract issue number from branch name: %w", err)
 	}
@@ -281,6 +257,9 @@ func selectRepository() (*RepoInfo, error) {
 		isCrossRepo = true
 
 		// Validate providers match
+		if err := utils.ChoosePairedProvider(selectedRepo, issueRepo, !setdescNonInteractive); err != nil {
+			return nil, err
+		}
 		if (selectedRepo.GithubRepo != "" && issueRepo.GitlabRepo != "") ||
 			(selectedRepo.GitlabRepo != "" && issueRepo.GithubRepo != "") {
 			return nil, fmt.Errorf("issue repo '%s' and code repo '%s' must use the same provider", projectName, selectedRepoName)
@@ -292,6 +271,14 @@ func selectRepository() (*RepoInfo, error) {
 		})
 	}
 
+	// The issue is in the code repo itself (a no-op when cross-repo settled it)
+	if err := utils.ChooseProvider(selectedRepo, !setdescNonInteractive); err != nil {
+		return nil, err
+	}
+
+	// Determine if GitLab or GitHub repository
+	isGitlab := selectedRepo.GitlabRepo != ""
+
 	// Create a description provider based on the code repository type (for MR)
 	var descriptionProvider services.MRDescriptionProvider
 	if isGitlab {
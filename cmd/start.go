@@ -3,14 +3,12 @@ package cmd
 import (
 	"fmt"
 	"os"
-	"path/filepath"
 	"strconv"
 	"strings"
 
 	"github.com/pterm/pterm"
 	"github.com/spf13/cobra"
 	"github.com/tedkulp/tix/internal/config"
-	"github.com/tedkulp/tix/internal/git"
 	"github.com/tedkulp/tix/internal/logger"
 	"github.com/tedkulp/tix/internal/services"
 	"github.com/tedkulp/tix/internal/utils"
@@ -18,8 +16,18 @@ import (
 
 var (
 	startUseWorktree    bool
+	startReuseBranch    bool
+	startForceWorktree  bool
+	startWorktreeDir    string
 	startNoAutoStash    bool
 	startNonInteractive bool
+	startBaseBranch     string
+	startBaseRemote     string
+	startBaseTag        string
+	startSuffix         string
+	startTrackUpstream  bool
+	startPrintBranch    bool
+	startFromCurrent    bool
 )
 
 var startCmd = &cobra.Command{
@@ -33,17 +41,50 @@ Usage:
   tix start 123                    # Create branch from issue #123 in current repo
   tix start project 123            # Create branch from issue #123 in 'project' repo
 
-If the issue is from a different repo, the branch name will include the project prefix.`,
+If the issue is from a different repo, the branch name will include the project prefix.
+
+Use --suffix to start another branch (and worktree) for an issue that already
+has one, e.g. a spike next to the fix: 'tix start 123 -w --suffix spike'
+creates 123-slug-spike alongside 123-slug.`,
 	RunE: func(cmd *cobra.Command, args []string) error {
+		var suffix string
+		if startSuffix != "" {
+			if suffix = utils.TruncateAndDashCase(startSuffix, 30); suffix == "" {
+				return fmt.Errorf("--suffix %q has no letters or digits to use in a branch name", startSuffix)
+			}
+		}
+
 		if startNonInteractive && len(args) == 0 {
 			return fmt.Errorf("--non-interactive requires an issue number argument")
 		}
+		if err := checkHeadBase(cmd, startFromCurrent, startBaseBranch, startBaseRemote); err != nil {
+			return err
+		}
+		if err := checkBaseTag(cmd, startBaseTag, startFromCurrent, startBaseBranch, startBaseRemote); err != nil {
+			return err
+		}
+
+		// --print-branch is for scripts, so every input must come from arguments
+		if startPrintBranch {
+			if len(args) == 0 {
+				return fmt.Errorf("--print-branch requires an issue number argument")
+			}
+			startNonInteractive = true
+		}
+
+		// With --print-branch, stdout carries only the branch name
+		var branchOut *os.File
+		if startPrintBranch {
+			var restore func()
+			branchOut, restore = stdoutForScripting()
+			defer restore()
+		}
 
 		logger.Debug("Starting start command")
 
-		cfg, err := config.Load()
+		cfg, err := utils.LoadConfig()
 		if err != nil {
-			return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
+			return configLoadError(err)
 		}
 
 		// Parse arguments
@@ -76,27 +117,8 @@ If the issue is from a different repo, the branch name will include the project
 			return fmt.Errorf("failed to get current directory")
 		}
 
-		// Find code repo (must have directory)
-		var codeRepo *config.Repository
-		var codeRepoName string
-		var bestMatchLength int
-
-		for i, repo := range cfg.Repositories {
-			if !repo.IsCodeRepo() {
-				continue
-			}
-			absRepoDir, err := filepath.Abs(repo.Directory)
-			if err != nil {
-				continue
-			}
-			if strings.HasPrefix(wd, absRepoDir) {
-				if len(absRepoDir) > bestMatchLength {
-					codeRepo = &cfg.Repositories[i]
-					codeRepoName = cfg.GetRepoNames()[i]
-					bestMatchLength = len(absRepoDir)
-				}
-			}
-		}
+		// Find the code repo the current directory belongs to
+		codeRepo, codeRepoName := utils.MatchRepository(cfg, wd)
 
 		// If no matching code repo found, prompt for one (or error in non-interactive mode)
 		if codeRepo == nil {
@@ -177,11 +199,11 @@ If the issue is from a different repo, the branch name will include the project
 			issueRepoName = codeRepoName
 		} else {
 			// Look up project by name
-			issueRepo = cfg.GetRepo(projectName)
-			if issueRepo == nil {
-				return fmt.Errorf("repository '%s' not found in config", projectName)
+			issueRepo, err = cfg.FindRepo(projectName)
+			if err != nil {
+				return err
 			}
-			issueRepoName = projectName
+			issueRepoName = issueRepo.Name
 		}
 
 		logger.Info("Issue repository selected", map[string]interface{}{
@@ -189,11 +211,35 @@ If the issue is from a different repo, the branch name will include the project
 		})
 
 		// Validate providers match
+		if err := utils.ChoosePairedProvider(codeRepo, issueRepo, !startNonInteractive); err != nil {
+			return err
+		}
 		if (codeRepo.GithubRepo != "" && issueRepo.GitlabRepo != "") ||
 			(codeRepo.GitlabRepo != "" && issueRepo.GithubRepo != "") {
 			return fmt.Errorf("issue repo and code repo must use the same provider (both GitHub or both GitLab)")
 		}
 
+		if !cmd.Flags().Changed("worktree") {
+			startUseWorktree = cfg.ResolveWorktreeEnabled(codeRepo)
+		}
+		if startReuseBranch && !startUseWorktree {
+			return fmt.Errorf("--reuse requires --worktree")
+		}
+		if startWorktreeDir != "" {
+			if !startUseWorktree {
+				return fmt.Errorf("--worktree-dir requires --worktree")
+			}
+			if startWorktreeDir, err = validateWorktreeDir(startWorktreeDir, codeRepo.Directory); err != nil {
+				return err
+			}
+		}
+
+		// Open the code repo before calling the API so a bad directory fails fast
+		gitRepo, err := openCodeRepo(codeRepoName, codeRepo.Directory)
+		if err != nil {
+			return err
+		}
+
 		// Create provider for issue repo
 		var issueProvider services.SCMProvider
 		if issueRepo.GithubRepo != "" {
@@ -219,26 +265,38 @@ If the issue is from a different repo, the branch name will include the project
 			"title":  issue.Title,
 		})
 
-		// Generate branch name
-		var branchName string
+		// Generate branch name (cross-repo branches get the project prefix)
+		projectPrefix := ""
 		if projectName != "" && issueRepoName != codeRepoName {
-			// Cross-repo: use project prefix
-			branchName = fmt.Sprintf("%s-%d-%s", projectName, issueNumber, utils.TruncateAndDashCase(issue.Title, 50))
-		} else {
-			// Same repo: no prefix
-			branchName = fmt.Sprintf("%d-%s", issueNumber, utils.TruncateAndDashCase(issue.Title, 50))
+			projectPrefix = projectName
+		}
+		issueID, err := branchIssueID(cfg, issueRepo, issue)
+		if err != nil {
+			return err
+		}
+		// The suffix comes out of the branch_max_length budget like the rest
+		vars := utils.BranchNameVars{
+			Prefix: projectPrefix,
+			ID:     issueID,
+			Title:  issue.Title,
+		}
+		plainBranch, err := branchNameFor(gitRepo, cfg, codeRepo, vars)
+		if err != nil {
+			return err
+		}
+		branchName := plainBranch
+		if suffix != "" {
+			vars.Suffix = suffix
+			if branchName, err = branchNameFor(gitRepo, cfg, codeRepo, vars); err != nil {
+				return err
+			}
 		}
 
 		logger.Debug("Branch name created", map[string]interface{}{
 			"branch": branchName,
 		})
 
-		// Open git repo and validate it's clean
-		gitRepo, err := git.Open(codeRepo.Directory)
-		if err != nil {
-			return fmt.Errorf("failed to open git repository at %s: %w", codeRepo.Directory, err)
-		}
-
+		// Validate the repo is clean
 		if !startUseWorktree {
 			isClean, err := gitRepo.IsClean()
 			if err != nil {
@@ -254,47 +312,116 @@ If the issue is from a different repo, the branch name will include the project
 				fmt.Println("Stashed changes, will restore after branch creation.")
 				defer func() {
 					if popErr := gitRepo.StashPop(); popErr != nil {
-						fmt.Fprintf(os.Stderr, "Warning: failed to restore stashed changes: %v\n", popErr)
+						printWarning("failed to restore stashed changes: %v", popErr)
 						fmt.Fprintf(os.Stderr, "Your changes are still in the stash — run `git stash pop` manually.\n")
 					}
 				}()
 			}
 		}
 
+		track := cfg.ResolveTrackUpstream(codeRepo)
+		if cmd.Flags().Changed("track") {
+			track = startTrackUpstream
+		}
+
+		// Worktrees and tracked branches always need a base; other in-place
+		// branches only when --base or --base-remote is given
+		var base string
+		if startBaseTag != "" {
+			// A tag has no remote branch to track
+			track = false
+			base, err = resolveBaseTag(gitRepo, startBaseTag)
+			if err != nil {
+				return err
+			}
+		} else if startFromCurrent || startBaseBranch == headBase {
+			// A commit has no remote branch to track
+			track = false
+			base, err = resolveHeadBase(gitRepo)
+			if err != nil {
+				return err
+			}
+		} else if startBaseRemote != "" {
+			base, err = resolveRemoteBaseBranch(gitRepo, cfg, codeRepo, startBaseBranch, startBaseRemote)
+			if err != nil {
+				return err
+			}
+		} else if startUseWorktree || startBaseBranch != "" || track {
+			base, err = resolveBaseBranch(gitRepo, cfg, codeRepo, startBaseBranch, startNonInteractive)
+			if err != nil {
+				return err
+			}
+			if cfg.ResolveFFBaseBeforeCreate(codeRepo) {
+				fastForwardBase(gitRepo, base)
+			}
+		}
+
+		start, err := branchStartPoint(gitRepo, base)
+		if err != nil {
+			return err
+		}
+
 		// Create and checkout branch
 		if startUseWorktree {
-			worktreeBase := cfg.ResolveWorktreePath(codeRepo)
-			worktreeDir := filepath.Join(worktreeBase, branchName)
+			worktreeDir := startWorktreeDir
+			if worktreeDir == "" {
+				worktreeDir, err = resolveWorktreeDir(cfg, codeRepo, issueID, issue.Title, branchName)
+				if err != nil {
+					return err
+				}
+				// A path template without {branch} would put both branches in one directory
+				if suffix != "" {
+					if plainDir, err := resolveWorktreeDir(cfg, codeRepo, issueID, issue.Title, plainBranch); err == nil && plainDir == worktreeDir {
+						worktreeDir += "-" + suffix
+					}
+				}
+			}
 			logger.Info("Creating worktree", map[string]interface{}{
 				"branch":    branchName,
 				"directory": worktreeDir,
 			})
 
-			defaultBranch := cfg.ResolveDefaultBranch(codeRepo)
-			if err := gitRepo.AddWorktree(worktreeDir, branchName, defaultBranch); err != nil {
-				return fmt.Errorf("failed to create worktree: %w", err)
+			reused, err := addIssueWorktree(gitRepo, worktreeDir, branchName, start, startReuseBranch, startForceWorktree, startNonInteractive)
+			if err != nil {
+				return err
+			}
+			if reused {
+				// The existing branch keeps whatever upstream it already has
+				track = false
+				printSuccess("Created worktree for existing branch %s: %s", branchName, worktreeDir)
+			} else {
+				printSuccess("Created worktree: %s", worktreeDir)
 			}
-
-			fmt.Printf("Created worktree: %s\n", worktreeDir)
 		} else {
 			logger.Info("Creating and checking out branch", map[string]interface{}{
 				"branch": branchName,
+				"base":   base,
 			})
 
-			if err := gitRepo.CreateBranch(branchName); err != nil {
+			if err := checkBranchNotCheckedOut(gitRepo, branchName); err != nil {
+				return err
+			}
+			if err := createLocalBranch(gitRepo, branchName, start); err != nil {
 				return fmt.Errorf("failed to create branch: %w", err)
 			}
 			if err := gitRepo.CheckoutBranch(branchName); err != nil {
 				return fmt.Errorf("failed to checkout branch: %w", err)
 			}
 
-			fmt.Printf("Created and checked out branch: %s\n", branchName)
+			printSuccess("Created and checked out branch: %s", branchName)
+		}
+
+		recordBranchIssue(gitRepo, branchName, issueID, issueNumber)
+		if track {
+			trackBaseBranch(gitRepo, branchName, startBaseRemote, base)
 		}
 
 		// Show issue URL
 		issueURL := fmt.Sprintf("%s/issues/%d", issueProvider.GetURL(), issueNumber)
 		fmt.Printf("Issue: %s\n", issueURL)
 
+		printBranchName(branchOut, branchName)
+
 		logger.Debug("Start command completed successfully")
 		return nil
 	},
@@ -302,7 +429,17 @@ If the issue is from a different repo, the branch name will include the project
 
 func init() {
 	rootCmd.AddCommand(startCmd)
-	startCmd.Flags().BoolVarP(&startUseWorktree, "worktree", "w", false, "Create a git worktree instead of checking out a branch")
+	startCmd.Flags().BoolVarP(&startUseWorktree, "worktree", "w", false, "Create a git worktree instead of checking out a branch (default: worktree.enabled)")
+	startCmd.Flags().StringVar(&startSuffix, "suffix", "", "Append a suffix to the branch name (e.g. spike for 123-slug-spike) to start another branch for the same issue")
+	startCmd.Flags().StringVar(&startWorktreeDir, "worktree-dir", "", "With --worktree, create the worktree in this directory instead of the configured path (outside the repository)")
+	startCmd.Flags().BoolVar(&startForceWorktree, "force", false, "With --worktree, remove a directory left over from a deleted worktree without asking (never one with uncommitted files)")
+	startCmd.Flags().BoolVar(&startReuseBranch, "reuse", false, "With --worktree, check out the branch in a new worktree if it already exists instead of failing")
 	startCmd.Flags().BoolVar(&startNoAutoStash, "no-auto-stash", false, "Disable automatic stashing of uncommitted changes before branch creation")
+	startCmd.Flags().StringVar(&startBaseBranch, "base", "", "Branch to base the new branch on, or HEAD for the current commit (default: default_branch, or detected from main/master)")
+	startCmd.Flags().StringVar(&startBaseTag, "base-tag", "", "Base the new branch on the commit of this tag (annotated or lightweight), e.g. v1.2.3 for a hotfix")
+	startCmd.Flags().BoolVar(&startFromCurrent, "from-current", false, "Base the new branch on the current commit, like --base HEAD (e.g. to stack on a feature branch)")
+	startCmd.Flags().StringVar(&startBaseRemote, "base-remote", "", "Base the new branch on this remote's copy of the base branch (e.g. origin/main) instead of the local one")
+	startCmd.Flags().BoolVar(&startPrintBranch, "print-branch", false, "Print only the branch name to stdout (other output goes to stderr), for scripts")
+	startCmd.Flags().BoolVar(&startTrackUpstream, "track", false, "Set the new branch's upstream to the remote base branch, e.g. origin/main (overrides track_upstream)")
 	startCmd.Flags().BoolVarP(&startNonInteractive, "non-interactive", "n", false, "Skip all interactive prompts and use defaults (requires issue number argument)")
 }
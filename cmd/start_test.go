@@ -3,6 +3,9 @@ package cmd
 import (
 	"strings"
 	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/utils"
 )
 
 func TestStartNoAutoStashFlag(t *testing.T) {
@@ -64,3 +67,26 @@ func TestStartNonInteractiveAmbiguousRepo(t *testing.T) {
 	// fires on happy path with zero args — already covered by RequiresIssueNumber).
 	_ = startNonInteractive // flag is set; verified registered in TestStartNonInteractiveFlag
 }
+
+func TestStartSuffixNeedsLettersOrDigits(t *testing.T) {
+	orig := startSuffix
+	defer func() { startSuffix = orig }()
+
+	startSuffix = "--"
+	err := startCmd.RunE(startCmd, []string{"42"})
+	if err == nil || !strings.Contains(err.Error(), "--suffix") {
+		t.Errorf("expected an error for a suffix with nothing usable, got %v", err)
+	}
+}
+
+func TestStartSuffixWithinBranchMaxLength(t *testing.T) {
+	cfg := &config.Settings{BranchMaxLength: 24}
+	vars := utils.BranchNameVars{ID: "42", Title: "Fix the login redirect loop", Suffix: "spike"}
+	name, err := branchNameFor(&mockGitBackend{}, cfg, &config.Repository{}, vars)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if name != "42-fix-the-login-r-spike" || len(name) > cfg.BranchMaxLength {
+		t.Errorf("branchNameFor() = %q (%d bytes), want the slug shortened to fit the suffix in %d", name, len(name), cfg.BranchMaxLength)
+	}
+}
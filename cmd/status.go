@@ -3,20 +3,25 @@ package cmd
 import (
 	"encoding/json"
 	"fmt"
+	"io"
 	"os"
-	"path/filepath"
 	"strings"
 
 	"github.com/spf13/cobra"
-	"github.com/tedkulp/tix/internal/config"
 	"github.com/tedkulp/tix/internal/git"
 	"github.com/tedkulp/tix/internal/logger"
 	"github.com/tedkulp/tix/internal/services"
 	"github.com/tedkulp/tix/internal/utils"
 )
 
+// statusSchemaVersion is the version of the --json output shape. Bump it when
+// a field is removed, renamed or changes meaning; adding fields doesn't need a
+// bump, so consumers should ignore fields they don't know.
+const statusSchemaVersion = 1
+
 // statusJSON is the JSON output shape for --json flag.
 type statusJSON struct {
+	SchemaVersion int      `json:"schema_version"`
 	Branch        string   `json:"branch"`
 	IssueNumber   int      `json:"issue_number"`
 	IssueTitle    string   `json:"issue_title"`
@@ -42,9 +47,9 @@ Exits with code 1 if the configuration or API calls fail.`,
 
 		jsonOutput, _ := cmd.Flags().GetBool("json")
 
-		cfg, err := config.Load()
+		cfg, err := utils.LoadConfig()
 		if err != nil {
-			return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
+			return configLoadError(err)
 		}
 
 		wd, err := os.Getwd()
@@ -52,25 +57,8 @@ Exits with code 1 if the configuration or API calls fail.`,
 			return fmt.Errorf("failed to determine current directory")
 		}
 
-		// Find code repo matching the current directory
-		var matchingRepo *config.Repository
-		var repoName string
-		bestMatchLength := 0
-
-		for i, repo := range cfg.Repositories {
-			if !repo.IsCodeRepo() {
-				continue
-			}
-			absRepoDir, err := filepath.Abs(repo.Directory)
-			if err != nil {
-				continue
-			}
-			if strings.HasPrefix(wd, absRepoDir) && len(absRepoDir) > bestMatchLength {
-				matchingRepo = &cfg.Repositories[i]
-				repoName = repo.Name
-				bestMatchLength = len(absRepoDir)
-			}
-		}
+		// Find the code repo the current directory belongs to
+		matchingRepo, repoName := utils.MatchRepository(cfg, wd)
 
 		if matchingRepo == nil {
 			return fmt.Errorf("no configured repository found for directory %s", wd)
@@ -83,13 +71,10 @@ Exits with code 1 if the configuration or API calls fail.`,
 			return fmt.Errorf("failed to determine current git branch")
 		}
 
-		projectName, issueNumber, err := utils.ExtractIssueInfo(currentBranch)
+		projectName, issueNumber, err := utils.BranchIssueInfo(wd, currentBranch)
 		if err != nil {
 			if jsonOutput {
-				out := statusJSON{Branch: currentBranch, IssueLabels: []string{}}
-				enc := json.NewEncoder(os.Stdout)
-				enc.SetIndent("", "  ")
-				_ = enc.Encode(out)
+				_ = writeStatusJSON(os.Stdout, statusJSON{Branch: currentBranch, IssueLabels: []string{}})
 				os.Exit(1)
 			}
 			return fmt.Errorf("branch %q is not a ticket branch", currentBranch)
@@ -151,9 +136,7 @@ Exits with code 1 if the configuration or API calls fail.`,
 				SuggestedNext: ws.SuggestedNext,
 				MRLookupError: mrLookupError,
 			}
-			enc := json.NewEncoder(os.Stdout)
-			enc.SetIndent("", "  ")
-			return enc.Encode(out)
+			return writeStatusJSON(os.Stdout, out)
 		}
 
 		// Human-readable output
@@ -183,7 +166,7 @@ Exits with code 1 if the configuration or API calls fail.`,
 		}
 
 		if ws.MRLookupErr != nil {
-			fmt.Fprintf(os.Stderr, "Warning: MR lookup failed: %v\n", ws.MRLookupErr)
+			printWarning("MR lookup failed: %v", ws.MRLookupErr)
 		}
 
 		if ws.SuggestedNext != "" {
@@ -194,6 +177,14 @@ Exits with code 1 if the configuration or API calls fail.`,
 	},
 }
 
+// writeStatusJSON writes out as indented JSON, stamped with the schema version
+func writeStatusJSON(w io.Writer, out statusJSON) error {
+	out.SchemaVersion = statusSchemaVersion
+	enc := json.NewEncoder(w)
+	enc.SetIndent("", "  ")
+	return enc.Encode(out)
+}
+
 func init() {
 	rootCmd.AddCommand(statusCmd)
 	statusCmd.Flags().BoolP("json", "j", false, "Output status as JSON")
@@ -0,0 +1,29 @@
+package cmd
+
+import (
+	"bytes"
+	"encoding/json"
+	"testing"
+)
+
+func TestWriteStatusJSONSchemaVersion(t *testing.T) {
+	var buf bytes.Buffer
+	if err := writeStatusJSON(&buf, statusJSON{Branch: "42-add-export", IssueNumber: 42, IssueLabels: []string{}}); err != nil {
+		t.Fatalf("writeStatusJSON() error: %v", err)
+	}
+
+	var got map[string]any
+	if err := json.Unmarshal(buf.Bytes(), &got); err != nil {
+		t.Fatalf("output isn't JSON: %v\n%s", err, buf.String())
+	}
+	if got["schema_version"] != float64(statusSchemaVersion) {
+		t.Errorf("schema_version = %v, want %d", got["schema_version"], statusSchemaVersion)
+	}
+
+	// The documented fields consumers rely on
+	for _, key := range []string{"branch", "issue_number", "issue_title", "issue_labels", "milestone", "mr_url", "mr_number", "mr_is_draft", "suggested_next", "mr_lookup_error"} {
+		if _, ok := got[key]; !ok {
+			t.Errorf("missing %q in status JSON", key)
+		}
+	}
+}
@@ -0,0 +1,192 @@
+package cmd
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/state"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var syncCmd = &cobra.Command{
+	Use:   "sync",
+	Short: "File issues queued while offline and rename their branches",
+	Long: `File the issues queued by 'tix create --offline' and rename their
+placeholder branches (tmp-<timestamp>-slug) to the usual <id>-slug form.
+Worktrees created for those branches are moved to match the new branch name.
+
+Issues that fail to sync stay in the queue and are retried on the next run.`,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting sync command")
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		pending, err := state.LoadPending()
+		if err != nil {
+			return err
+		}
+		if len(pending) == 0 {
+			fmt.Println("No pending issues to sync.")
+			return nil
+		}
+
+		var remaining []state.PendingIssue
+		for i := range pending {
+			item := &pending[i]
+			newBranch, err := syncPendingIssue(cfg, item)
+			if err != nil {
+				fmt.Printf("Failed: %s (%s): %v\n", item.Title, item.Branch, err)
+				remaining = append(remaining, *item)
+				continue
+			}
+			fmt.Printf("Synced: #%d %s -> %s\n", item.IssueNumber, item.Branch, newBranch)
+		}
+
+		if err := state.SavePending(remaining); err != nil {
+			return err
+		}
+
+		if len(remaining) > 0 {
+			return fmt.Errorf("%d of %d pending issues failed to sync", len(remaining), len(pending))
+		}
+
+		logger.Debug("Sync command completed successfully")
+		return nil
+	},
+}
+
+// syncPendingIssue files a queued issue (unless a previous sync already did)
+// and renames its placeholder branch and worktree. It returns the new branch name.
+func syncPendingIssue(cfg *config.Settings, item *state.PendingIssue) (string, error) {
+	issueRepo := cfg.GetRepo(item.Repo)
+	if issueRepo == nil {
+		return "", fmt.Errorf("repository '%s' not found in config", item.Repo)
+	}
+	codeRepo := cfg.GetRepo(item.CodeRepo)
+	if codeRepo == nil || !codeRepo.IsCodeRepo() {
+		return "", fmt.Errorf("code repository '%s' not found in config", item.CodeRepo)
+	}
+
+	issueURL := ""
+	if item.IssueNumber == 0 {
+		provider, err := utils.CreateSCMProvider(&utils.SharedRepoInfo{
+			Repo:     issueRepo,
+			Name:     item.Repo,
+			IsGitLab: issueRepo.GitlabRepo != "",
+		})
+		if err != nil {
+			return "", err
+		}
+
+		issue, err := fileIssue(cfg, issueRepo, item.Repo, provider, services.IssueParams{
+			Title:          item.Title,
+			Labels:         item.Labels,
+			SelfAssign:     item.SelfAssign,
+			Assignees:      item.Assignees,
+			MilestoneTitle: item.Milestone,
+			GroupMilestone: item.GroupMilestone,
+			Body:           item.Body,
+			QuickActions:   item.QuickActions,
+			Confidential:   item.Confidential,
+			Weight:         item.Weight,
+			Attachments:    item.Attachments,
+			Closed:         item.Closed,
+			Lock:           item.Locked,
+		})
+		if err != nil {
+			return "", fmt.Errorf("failed to create issue: %w", err)
+		}
+		item.IssueNumber = issue.Number
+		item.IssueID = issue.ID
+		issueURL = fmt.Sprintf("%s/issues/%d", provider.GetURL(), issue.Number)
+		printSuccess("Created issue: %s", issueURL)
+
+		if item.Project != "" {
+			addToProjectBoard(provider, issue.Number, item.Project)
+		}
+	}
+
+	projectPrefix := ""
+	if item.Repo != item.CodeRepo {
+		projectPrefix = item.Repo
+	}
+	issueID, err := branchIssueID(cfg, issueRepo, &services.IssueResult{Number: item.IssueNumber, ID: item.IssueID})
+	if err != nil {
+		return "", err
+	}
+
+	gitRepo, err := git.Open(codeRepo.Directory)
+	if err != nil {
+		return "", fmt.Errorf("couldn't open git repository at %s", codeRepo.Directory)
+	}
+
+	newBranch, err := branchNameFor(gitRepo, cfg, codeRepo, utils.BranchNameVars{
+		Type:   item.BranchType,
+		Prefix: projectPrefix,
+		ID:     issueID,
+		Title:  item.Title,
+	})
+	if err != nil {
+		return "", err
+	}
+
+	// Find the worktree before renaming, while it still has the old branch checked out
+	oldWorktreeDir, err := gitRepo.WorktreePathForBranch(item.Branch)
+	if err != nil {
+		return "", err
+	}
+
+	if err := gitRepo.RenameBranch(item.Branch, newBranch); err != nil {
+		return "", err
+	}
+	logger.Info("Branch renamed", map[string]interface{}{
+		"from": item.Branch,
+		"to":   newBranch,
+	})
+	recordBranchIssue(gitRepo, newBranch, issueID, item.IssueNumber)
+
+	entry := state.HistoryEntry{
+		CreatedAt:   time.Now(),
+		Repo:        item.Repo,
+		CodeRepo:    item.CodeRepo,
+		Provider:    providerName(issueRepo),
+		IssueNumber: item.IssueNumber,
+		Title:       item.Title,
+		URL:         issueURL,
+		Branch:      newBranch,
+		Worktree:    oldWorktreeDir,
+	}
+
+	// Only move worktrees tix placed itself, i.e. at the templated path for the old branch
+	if oldWorktreeDir != "" {
+		expectedDir, err := resolveWorktreeDir(cfg, codeRepo, item.PlaceholderID, item.Title, item.Branch)
+		if err == nil && expectedDir == oldWorktreeDir {
+			newWorktreeDir, err := resolveWorktreeDir(cfg, codeRepo, issueID, item.Title, newBranch)
+			if err == nil {
+				err = gitRepo.MoveWorktree(oldWorktreeDir, newWorktreeDir)
+			}
+			if err != nil {
+				// The issue and branch are done; don't leave the item queued over the worktree
+				printWarning("branch renamed but the worktree at %s could not be moved: %v", oldWorktreeDir, err)
+			} else {
+				fmt.Printf("Moved worktree: %s -> %s\n", oldWorktreeDir, newWorktreeDir)
+				entry.Worktree = newWorktreeDir
+			}
+		}
+	}
+	issueCreated(cfg, issueRepo, entry)
+
+	return newBranch, nil
+}
+
+func init() {
+	rootCmd.AddCommand(syncCmd)
+}
@@ -0,0 +1,69 @@
+package cmd
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var templatesCmd = &cobra.Command{
+	Use:   "templates [repo]",
+	Short: "List the issue templates --template can use",
+	Long: `List the issue templates in a repository's checkout: .github/ISSUE_TEMPLATE
+for GitHub repositories and .gitlab/issue_templates for GitLab ones. Each
+template is shown with the name to pass to 'tix create --template' and a
+one-line description from its front matter (about or description) or its
+first line.
+
+Without a repository argument, the repository containing the current
+directory is used, or you're asked to pick one.`,
+	Args: cobra.MaximumNArgs(1),
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting templates command")
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		repo, repoName, err := selectConfiguredRepository(cfg, args)
+		if err != nil {
+			return err
+		}
+		if repo.Directory == "" {
+			return fmt.Errorf("%s has no directory configured - templates are read from the local checkout", repoName)
+		}
+
+		templates, err := utils.ListIssueTemplates(repo.Directory, repo.GitlabRepo != "")
+		if err != nil {
+			return err
+		}
+		if len(templates) == 0 {
+			templateDir := utils.GithubIssueTemplateDir
+			if repo.GitlabRepo != "" {
+				templateDir = utils.GitlabIssueTemplateDir
+			}
+			fmt.Printf("No issue templates found in %s/%s\n", repo.Directory, templateDir)
+			return nil
+		}
+
+		width := 0
+		for _, template := range templates {
+			if len(template.Name) > width {
+				width = len(template.Name)
+			}
+		}
+		for _, template := range templates {
+			fmt.Printf("%-*s  %s\n", width, template.Name, template.Description)
+		}
+
+		logger.Debug("Templates command completed successfully")
+		return nil
+	},
+}
+
+func init() {
+	rootCmd.AddCommand(templatesCmd)
+}
@@ -4,6 +4,8 @@ import (
 	"fmt"
 
 	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
 	"github.com/tedkulp/tix/internal/version"
 )
 
@@ -11,9 +13,31 @@ import (
 var versionCmd = &cobra.Command{
 	Use:   "version",
 	Short: "Display version information",
-	Long:  `Display the current version of tix, including the commit hash and build date.`,
+	Long: `Display the current version of tix, including the commit hash and build date.
+
+With --verbose (-v), also print the git, GitHub and GitLab client libraries tix
+was built against, the config file in use and the installed git version -
+useful to include in bug reports.`,
 	Run: func(cmd *cobra.Command, args []string) {
 		fmt.Println(version.GetFullVersion())
+		if verboseCount == 0 {
+			return
+		}
+
+		fmt.Println("built with:")
+		for _, dep := range version.ClientLibraryVersions() {
+			fmt.Printf("  %s %s\n", dep.Path, dep.Version)
+		}
+		configPath, err := config.ConfigPath()
+		if err != nil {
+			configPath = err.Error()
+		}
+		fmt.Printf("config: %s\n", configPath)
+		gitVersion, err := git.Version()
+		if err != nil {
+			gitVersion = err.Error()
+		}
+		fmt.Printf("git: %s\n", gitVersion)
 	},
 }
 
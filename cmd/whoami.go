@@ -0,0 +1,115 @@
+package cmd
+
+import (
+	"fmt"
+	"net/url"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/services"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var whoamiCmd = &cobra.Command{
+	Use:   "whoami [repo...]",
+	Short: "Show which user each provider's token authenticates as",
+	Long: `Look up the authenticated user on GitHub or GitLab for the named
+repositories, or for every configured repository when none are given, and
+print the username and host for each one.
+
+Repositories whose token is missing or rejected are reported individually,
+and the command exits non-zero if any of them failed.
+
+Examples:
+  tix whoami                    # Check every configured repository
+  tix whoami backend website    # Check only 'backend' and 'website'`,
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting whoami command")
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		repos, err := whoamiRepos(cfg, args)
+		if err != nil {
+			return err
+		}
+
+		failed := 0
+		for _, repo := range repos {
+			host, username, err := resolveWhoami(repo)
+			if err != nil {
+				failed++
+				printWarning("%s: authentication failed: %v", repo.Name, err)
+				continue
+			}
+			printSuccess("%s (%s): authenticated as %s", repo.Name, host, username)
+		}
+
+		if failed > 0 {
+			return fmt.Errorf("authentication failed for %d of %d repositories", failed, len(repos))
+		}
+
+		logger.Debug("Whoami command completed successfully")
+		return nil
+	},
+}
+
+// whoamiRepos returns the named repositories, or every configured repository
+// when no names are given
+func whoamiRepos(cfg *config.Settings, names []string) ([]*config.Repository, error) {
+	if len(names) == 0 {
+		if len(cfg.Repositories) == 0 {
+			return nil, fmt.Errorf("no repositories configured")
+		}
+		repos := make([]*config.Repository, len(cfg.Repositories))
+		for i := range cfg.Repositories {
+			repos[i] = &cfg.Repositories[i]
+		}
+		return repos, nil
+	}
+
+	repos := make([]*config.Repository, 0, len(names))
+	for _, name := range names {
+		repo, err := cfg.FindRepo(name)
+		if err != nil {
+			return nil, err
+		}
+		repos = append(repos, repo)
+	}
+	return repos, nil
+}
+
+// resolveWhoami returns the provider host and the username its token
+// authenticates as for repo
+func resolveWhoami(repo *config.Repository) (string, string, error) {
+	provider, err := utils.CreateSCMProvider(&utils.SharedRepoInfo{
+		Repo:     repo,
+		Name:     repo.Name,
+		IsGitLab: repo.GitlabRepo != "",
+	})
+	if err != nil {
+		return "", "", err
+	}
+
+	host := provider.GetURL()
+	if u, err := url.Parse(host); err == nil && u.Host != "" {
+		host = u.Host
+	}
+
+	resolver, ok := provider.(services.UserResolver)
+	if !ok {
+		return host, "", fmt.Errorf("looking up the current user isn't supported for %s", host)
+	}
+	username, err := resolver.CurrentUsername()
+	if err != nil {
+		return host, "", err
+	}
+	return host, username, nil
+}
+
+func init() {
+	rootCmd.AddCommand(whoamiCmd)
+}
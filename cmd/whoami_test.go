@@ -0,0 +1,41 @@
+package cmd
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+)
+
+func TestWhoamiRepos(t *testing.T) {
+	cfg := &config.Settings{
+		Repositories: []config.Repository{
+			{Name: "app", GitlabRepo: "acme/app"},
+			{Name: "website", GithubRepo: "acme/website"},
+		},
+	}
+
+	all, err := whoamiRepos(cfg, nil)
+	if err != nil {
+		t.Fatalf("whoamiRepos() error: %v", err)
+	}
+	if len(all) != 2 || all[0].Name != "app" || all[1].Name != "website" {
+		t.Errorf("whoamiRepos() without names = %v, want every configured repository", all)
+	}
+
+	named, err := whoamiRepos(cfg, []string{"website"})
+	if err != nil {
+		t.Fatalf("whoamiRepos() error: %v", err)
+	}
+	if len(named) != 1 || named[0].Name != "website" {
+		t.Errorf("whoamiRepos(website) = %v, want only website", named)
+	}
+
+	if _, err := whoamiRepos(cfg, []string{"app", "nowhere"}); err == nil || !strings.Contains(err.Error(), "'nowhere' not found") {
+		t.Errorf("expected not found error for an unknown repository, got %v", err)
+	}
+
+	if _, err := whoamiRepos(&config.Settings{}, nil); err == nil {
+		t.Error("expected error when no repositories are configured")
+	}
+}
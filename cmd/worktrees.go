@@ -0,0 +1,105 @@
+package cmd
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"github.com/tedkulp/tix/internal/git"
+	"github.com/tedkulp/tix/internal/logger"
+	"github.com/tedkulp/tix/internal/utils"
+)
+
+var worktreesCmd = &cobra.Command{
+	Use:   "worktrees [repo]",
+	Short: "List a repository's git worktrees",
+	Long: `List the worktrees git has registered for a code repository, with the
+branch each one has checked out. Worktrees on a tix branch (one carrying an
+issue number, like 123-fix-login) are marked with their issue, and locked
+worktrees and prunable ones - whose directory has been deleted - are flagged.
+
+Without a repository argument, the repository containing the current
+directory is used, or you're asked to pick one.`,
+	Args: cobra.MaximumNArgs(1),
+	RunE: func(cmd *cobra.Command, args []string) error {
+		logger.Debug("Starting worktrees command")
+
+		cfg, err := utils.LoadConfig()
+		if err != nil {
+			return configLoadError(err)
+		}
+
+		repo, repoName, err := findConfiguredRepository(cfg, args)
+		if err != nil {
+			return err
+		}
+		if !repo.IsCodeRepo() {
+			return fmt.Errorf("%s has no directory configured - worktrees are read from the local checkout", repoName)
+		}
+		gitRepo, err := openCodeRepo(repoName, repo.Directory)
+		if err != nil {
+			return err
+		}
+
+		worktrees, err := gitRepo.ListWorktrees()
+		if err != nil {
+			return err
+		}
+
+		width := 0
+		for _, worktree := range worktrees {
+			if len(worktree.Path) > width {
+				width = len(worktree.Path)
+			}
+		}
+		for _, worktree := range worktrees {
+			fmt.Printf("%-*s  %s\n", width, worktree.Path, describeWorktree(worktree))
+		}
+
+		logger.Debug("Worktrees command completed successfully")
+		return nil
+	},
+}
+
+// describeWorktree summarizes a worktree's checkout and state for tix
+// worktrees, e.g. "42-fix-login (issue #42, locked: in use)"
+func describeWorktree(worktree git.WorktreeInfo) string {
+	checkout := worktree.Branch
+	if checkout == "" {
+		head := worktree.Head
+		if len(head) > 7 {
+			head = head[:7]
+		}
+		checkout = "detached at " + head
+	}
+
+	var flags []string
+	if worktree.Main {
+		flags = append(flags, "main working tree")
+	} else if project, number, err := utils.BranchIssueInfo(worktree.Path, worktree.Branch); err == nil && worktree.Branch != "" {
+		issue := fmt.Sprintf("issue #%d", number)
+		if project != "" {
+			issue = fmt.Sprintf("issue %s#%d", project, number)
+		}
+		flags = append(flags, issue)
+	}
+	if worktree.Locked {
+		locked := "locked"
+		if worktree.LockReason != "" {
+			locked += ": " + worktree.LockReason
+		}
+		flags = append(flags, locked)
+	}
+	if worktree.Prunable {
+		flags = append(flags, "prunable - run 'git worktree prune'")
+	}
+
+	if len(flags) == 0 {
+		return checkout
+	}
+	return checkout + " (" + strings.Join(flags, ", ") + ")"
+}
+
+func init() {
+	rootCmd.AddCommand(worktreesCmd)
+}
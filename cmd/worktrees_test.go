@@ -0,0 +1,30 @@
+package cmd
+
+import (
+	"testing"
+
+	"github.com/tedkulp/tix/internal/git"
+)
+
+func TestDescribeWorktree(t *testing.T) {
+	tests := []struct {
+		name     string
+		worktree git.WorktreeInfo
+		want     string
+	}{
+		{name: "main working tree", worktree: git.WorktreeInfo{Branch: "main", Main: true}, want: "main (main working tree)"},
+		{name: "tix branch", worktree: git.WorktreeInfo{Branch: "feature/42-fix-login"}, want: "feature/42-fix-login (issue #42)"},
+		{name: "cross-repo branch", worktree: git.WorktreeInfo{Branch: "docs-7-fix-typo"}, want: "docs-7-fix-typo (issue docs#7)"},
+		{name: "other branch", worktree: git.WorktreeInfo{Branch: "spike"}, want: "spike"},
+		{name: "locked", worktree: git.WorktreeInfo{Branch: "12-export", Locked: true, LockReason: "in use"}, want: "12-export (issue #12, locked: in use)"},
+		{name: "detached and prunable", worktree: git.WorktreeInfo{Head: "89abcdef0123", Prunable: true}, want: "detached at 89abcde (prunable - run 'git worktree prune')"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := describeWorktree(tt.worktree); got != tt.want {
+				t.Errorf("describeWorktree() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
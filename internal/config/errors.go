@@ -0,0 +1,161 @@
+package config
+
+import (
+	"fmt"
+	"reflect"
+	"regexp"
+	"strconv"
+	"strings"
+)
+
+// DecodeError is returned by Load when settings in the config file have the
+// wrong type, with one problem per bad setting
+type DecodeError struct {
+	Path     string
+	Problems []string // e.g. `repository "app" (repositories[0]) worktree.enabled: expected true or false, got "yes"`
+}
+
+func (e *DecodeError) Error() string {
+	return fmt.Sprintf("invalid config in %s:\n  - %s", e.Path, strings.Join(e.Problems, "\n  - "))
+}
+
+// decodeErrorField finds the setting a decode error is about, quoted in the
+// error as e.g. 'repositories[0].worktree.enabled'
+var decodeErrorField = regexp.MustCompile(`'([\w.\[\]-]+)'`)
+
+// fieldPathPart matches one step of a setting path: a key or a list index
+var fieldPathPart = regexp.MustCompile(`([^.\[\]]+)|\[(\d+)\]`)
+
+// decodeError turns a failure to decode the config file into a DecodeError
+// naming each bad setting, the repository it belongs to, the expected type
+// and the value found in raw. Errors that don't name a setting are returned
+// as they are.
+func decodeError(path string, raw map[string]any, err error) error {
+	var problems []string
+	for _, line := range strings.Split(err.Error(), "\n") {
+		match := decodeErrorField.FindStringSubmatch(line)
+		if match == nil {
+			continue
+		}
+		problems = append(problems, describeBadSetting(match[1], raw))
+	}
+	if len(problems) == 0 {
+		return fmt.Errorf("failed to unmarshal config: %w", err)
+	}
+	return &DecodeError{Path: path, Problems: problems}
+}
+
+// describeBadSetting explains what's wrong with the setting at field
+func describeBadSetting(field string, raw map[string]any) string {
+	parts := fieldPathPart.FindAllStringSubmatch(field, -1)
+
+	// Settings inside a repository are named relative to it
+	name := field
+	if len(parts) > 1 && parts[0][1] == "repositories" && parts[1][2] != "" {
+		i, _ := strconv.Atoi(parts[1][2])
+		repo := fmt.Sprintf("repositories[%d]", i)
+		entry, _ := lookupRaw(raw, parts[:2]).(map[string]any)
+		if repoName, ok := entry["name"].(string); ok && repoName != "" {
+			repo = fmt.Sprintf("repository %q (%s)", repoName, repo)
+		}
+		name = repo
+		if rest := strings.TrimPrefix(field, fmt.Sprintf("repositories[%d]", i)); rest != "" {
+			name += " " + strings.TrimPrefix(rest, ".")
+		}
+	}
+
+	expected := describeType(settingType(reflect.TypeOf(Settings{}), parts))
+	value := lookupRaw(raw, parts)
+	got := fmt.Sprintf("%v", value)
+	if s, ok := value.(string); ok {
+		got = strconv.Quote(s)
+	}
+	if expected == "" {
+		return fmt.Sprintf("%s: unexpected value %s", name, got)
+	}
+	return fmt.Sprintf("%s: expected %s, got %s", name, expected, got)
+}
+
+// settingType returns the Go type of the setting at the path parts within t,
+// matching keys against mapstructure tags, or nil if there is none
+func settingType(t reflect.Type, parts [][]string) reflect.Type {
+	for _, part := range parts {
+		for t.Kind() == reflect.Ptr {
+			t = t.Elem()
+		}
+		switch {
+		case part[2] != "" && t.Kind() == reflect.Slice:
+			t = t.Elem()
+		case part[1] != "" && t.Kind() == reflect.Map:
+			t = t.Elem()
+		case part[1] != "" && t.Kind() == reflect.Struct:
+			field, ok := structField(t, part[1])
+			if !ok {
+				return nil
+			}
+			t = field.Type
+		default:
+			return nil
+		}
+	}
+	return t
+}
+
+// structField finds the field of t whose mapstructure tag is key
+func structField(t reflect.Type, key string) (reflect.StructField, bool) {
+	for i := 0; i < t.NumField(); i++ {
+		field := t.Field(i)
+		if tag, _, _ := strings.Cut(field.Tag.Get("mapstructure"), ","); tag == key {
+			return field, true
+		}
+	}
+	return reflect.StructField{}, false
+}
+
+// describeType names the kind of value a setting of type t takes
+func describeType(t reflect.Type) string {
+	if t == nil {
+		return ""
+	}
+	for t.Kind() == reflect.Ptr {
+		t = t.Elem()
+	}
+	if t == reflect.TypeOf(LabelList{}) {
+		return "a list of labels or a comma separated string"
+	}
+	switch t.Kind() {
+	case reflect.Bool:
+		return "true or false"
+	case reflect.Int, reflect.Int64:
+		return "a whole number"
+	case reflect.String:
+		return "a string"
+	case reflect.Slice:
+		return "a list"
+	case reflect.Map:
+		return "a map of names to values"
+	case reflect.Struct:
+		return "a section of settings"
+	}
+	return ""
+}
+
+// lookupRaw returns the value at the path parts in the raw config, or nil
+func lookupRaw(raw map[string]any, parts [][]string) any {
+	var value any = raw
+	for _, part := range parts {
+		switch v := value.(type) {
+		case map[string]any:
+			value = v[strings.ToLower(part[1])]
+		case []any:
+			i, err := strconv.Atoi(part[2])
+			if err != nil || i >= len(v) {
+				return nil
+			}
+			value = v[i]
+		default:
+			return nil
+		}
+	}
+	return value
+}
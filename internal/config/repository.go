@@ -1,44 +1,329 @@
 package config
 
 import (
+	"errors"
 	"fmt"
 	"os"
 	"path/filepath"
+	"reflect"
+	"sort"
+	"strconv"
 	"strings"
+	"unicode"
+	"unicode/utf8"
 
 	"github.com/go-viper/mapstructure/v2"
+	"github.com/lithammer/fuzzysearch/fuzzy"
 	"github.com/spf13/viper"
 )
 
 // WorktreeConfig represents worktree configuration
 type WorktreeConfig struct {
+	Enabled       *bool  `yaml:"enabled,omitempty" mapstructure:"enabled"` // Use worktrees without --worktree
 	Path          string `yaml:"path" mapstructure:"path"`
 	DefaultBranch string `yaml:"default_branch" mapstructure:"default_branch"`
+	PathTemplate  string `yaml:"path_template,omitempty" mapstructure:"path_template"`
+}
+
+// DefaultWorktreePathTemplate places each worktree in a directory named after its branch
+const DefaultWorktreePathTemplate = "{branch}"
+
+// WorktreePathVars holds the values substituted into a worktree path template
+type WorktreePathVars struct {
+	ID     string
+	Slug   string
+	Branch string
+	Repo   string
+}
+
+// LabelList is a list of labels that can be written in the config either as a
+// comma separated string ("bug, triage") or as a YAML list ([bug, triage])
+type LabelList []string
+
+// labelListHook decodes a comma separated string into a LabelList, so existing
+// configs keep working alongside the list form
+func labelListHook(from reflect.Type, to reflect.Type, data interface{}) (interface{}, error) {
+	if to != reflect.TypeOf(LabelList{}) || from.Kind() != reflect.String {
+		return data, nil
+	}
+	return LabelList(strings.Split(data.(string), ",")), nil
+}
+
+// SplitLabels splits a comma separated label string, trimming whitespace
+// around each label and dropping empty segments ("bug, ,ui," -> [bug ui]).
+// Spaces inside a label are kept, so "good first issue" stays one label.
+func SplitLabels(s string) []string {
+	var labels []string
+	for _, label := range strings.Split(s, ",") {
+		if label = strings.TrimSpace(label); label != "" {
+			labels = append(labels, label)
+		}
+	}
+	return labels
+}
+
+// NormalizeLabels trims whitespace, drops empty entries and removes duplicates
+// (case-insensitive, first occurrence wins) from a list of labels
+func NormalizeLabels(labels []string) []string {
+	seen := make(map[string]bool)
+	result := []string{}
+	for _, label := range labels {
+		label = strings.TrimSpace(label)
+		if label == "" || seen[strings.ToLower(label)] {
+			continue
+		}
+		seen[strings.ToLower(label)] = true
+		result = append(result, label)
+	}
+	return result
+}
+
+// LabelScope returns the scope of a GitLab scoped label, the part before the
+// last "::" (priority::high has the scope priority, and team::api::owner the
+// scope team::api). ok is false for labels that aren't scoped.
+func LabelScope(label string) (scope string, ok bool) {
+	i := strings.LastIndex(label, "::")
+	if i <= 0 || i+2 == len(label) {
+		return "", false
+	}
+	return label[:i], true
+}
+
+// ScopedLabelConflicts returns the groups of labels that collide on a scope:
+// two values of the same scope (priority::high, priority::low) or a scoped
+// label alongside a plain label named after its scope (priority,
+// priority::high). Scopes match case-insensitively, and groups are ordered by
+// the first appearance of their labels.
+func ScopedLabelConflicts(labels []string) [][]string {
+	var scopes []string
+	groups := make(map[string][]string)
+	add := func(scope, label string) {
+		key := strings.ToLower(scope)
+		if _, ok := groups[key]; !ok {
+			scopes = append(scopes, key)
+		}
+		groups[key] = append(groups[key], label)
+	}
+
+	hasScoped := make(map[string]bool)
+	for _, label := range NormalizeLabels(labels) {
+		if scope, ok := LabelScope(label); ok {
+			hasScoped[strings.ToLower(scope)] = true
+			add(scope, label)
+		} else {
+			add(label, label)
+		}
+	}
+
+	var conflicts [][]string
+	for _, scope := range scopes {
+		if hasScoped[scope] && len(groups[scope]) > 1 {
+			conflicts = append(conflicts, groups[scope])
+		}
+	}
+	return conflicts
+}
+
+// HistoryConfig controls the log of issues created by tix
+type HistoryConfig struct {
+	Disabled bool   `yaml:"disabled,omitempty" mapstructure:"disabled"`
+	Path     string `yaml:"path,omitempty" mapstructure:"path"` // Default: history.jsonl in the state directory
+}
+
+// TitleTransform normalizes the titles of new issues before they're filed and
+// before branch names are built from them. Every transform is off by default.
+type TitleTransform struct {
+	Trim               bool `yaml:"trim,omitempty" mapstructure:"trim"`                               // Strip leading and trailing whitespace
+	CollapseWhitespace bool `yaml:"collapse_whitespace,omitempty" mapstructure:"collapse_whitespace"` // Turn each run of whitespace into a single space
+	SentenceCase       bool `yaml:"sentence_case,omitempty" mapstructure:"sentence_case"`             // Capitalize the first letter, leaving the rest as typed
+}
+
+// Apply returns title with the enabled transforms applied
+func (t TitleTransform) Apply(title string) string {
+	if t.CollapseWhitespace {
+		title = strings.Join(strings.Fields(title), " ")
+	}
+	if t.Trim {
+		title = strings.TrimSpace(title)
+	}
+	if t.SentenceCase {
+		for i, r := range title {
+			if unicode.IsSpace(r) {
+				continue
+			}
+			title = title[:i] + string(unicode.ToUpper(r)) + title[i+utf8.RuneLen(r):]
+			break
+		}
+	}
+	return title
+}
+
+// AssigneeRule assigns new issues to Assignees when they carry Label, or when
+// tix create runs inside Path (relative to the code repository). A rule with
+// both needs both to match.
+type AssigneeRule struct {
+	Label     string   `yaml:"label,omitempty" mapstructure:"label"`
+	Path      string   `yaml:"path,omitempty" mapstructure:"path"`
+	Assignees []string `yaml:"assignees" mapstructure:"assignees"`
+}
+
+// Rotation strategies: RotationRoundRobin hands new issues to each member in
+// turn, RotationRandom to a random one
+const (
+	RotationRoundRobin = "round_robin"
+	RotationRandom     = "random"
+)
+
+// Rotation takes turns assigning new issues created with tix create
+// --rotation among Members, e.g. for an on-call triage rotation
+type Rotation struct {
+	Members  []string `yaml:"members" mapstructure:"members"`
+	Strategy string   `yaml:"strategy,omitempty" mapstructure:"strategy"` // round_robin (default) or random
+}
+
+// Matches reports whether the rule applies to an issue with labels created
+// from dir, the working directory relative to the code repository ("" when
+// outside it)
+func (r AssigneeRule) Matches(labels []string, dir string) bool {
+	if r.Label == "" && r.Path == "" {
+		return false
+	}
+	if r.Label != "" {
+		labeled := false
+		for _, label := range labels {
+			labeled = labeled || strings.EqualFold(label, r.Label)
+		}
+		if !labeled {
+			return false
+		}
+	}
+	if r.Path != "" {
+		path := strings.Trim(filepath.ToSlash(filepath.Clean(r.Path)), "/")
+		dir = filepath.ToSlash(dir)
+		if dir == "" || (path != "." && dir != path && !strings.HasPrefix(dir, path+"/")) {
+			return false
+		}
+	}
+	return true
+}
+
+// RemoteSlugPrefix marks a github_repo or gitlab_repo that's read from a git
+// remote of the repository's directory: "@remote:upstream" uses the project
+// path of the upstream remote's URL
+const RemoteSlugPrefix = "@remote:"
+
+// RemoteAlias returns the remote a "@remote:<name>" slug refers to; ok is
+// false for ordinary slugs
+func RemoteAlias(slug string) (remote string, ok bool) {
+	if !strings.HasPrefix(slug, RemoteSlugPrefix) {
+		return "", false
+	}
+	return strings.TrimPrefix(slug, RemoteSlugPrefix), true
 }
 
 // Repository represents a single repository configuration
 type Repository struct {
-	Name          string         `yaml:"name" mapstructure:"name"`
-	Directory     string         `yaml:"directory" mapstructure:"directory"`
-	DefaultLabels string         `yaml:"default_labels" mapstructure:"default_labels"`
-	ReadyLabel    string         `yaml:"ready_label" mapstructure:"ready_label"`
-	ReadyStatus   string         `yaml:"ready_status" mapstructure:"ready_status"`
-	UnreadyLabel  string         `yaml:"unready_label" mapstructure:"unready_label"`
-	UnreadyStatus string         `yaml:"unready_status" mapstructure:"unready_status"`
-	GithubRepo    string         `yaml:"github_repo" mapstructure:"github_repo"`
-	GitlabRepo    string         `yaml:"gitlab_repo" mapstructure:"gitlab_repo"`
-	DefaultBranch string         `yaml:"default_branch" mapstructure:"default_branch"`
-	Worktree      WorktreeConfig `yaml:"worktree,omitempty" mapstructure:"worktree"`
+	Name                  string                       `yaml:"name" mapstructure:"name"`
+	Directory             string                       `yaml:"directory" mapstructure:"directory"`
+	DefaultLabels         LabelList                    `yaml:"default_labels" mapstructure:"default_labels"`
+	ReadyLabel            string                       `yaml:"ready_label" mapstructure:"ready_label"`
+	ReadyStatus           string                       `yaml:"ready_status" mapstructure:"ready_status"`
+	UnreadyLabel          string                       `yaml:"unready_label" mapstructure:"unready_label"`
+	UnreadyStatus         string                       `yaml:"unready_status" mapstructure:"unready_status"`
+	GithubRepo            string                       `yaml:"github_repo" mapstructure:"github_repo"`
+	GitlabRepo            string                       `yaml:"gitlab_repo" mapstructure:"gitlab_repo"`
+	PrimaryProvider       string                       `yaml:"primary_provider,omitempty" mapstructure:"primary_provider"`
+	GitlabBranchID        string                       `yaml:"gitlab_branch_id,omitempty" mapstructure:"gitlab_branch_id"`
+	DefaultBranch         string                       `yaml:"default_branch" mapstructure:"default_branch"`
+	CloseKeyword          string                       `yaml:"close_keyword,omitempty" mapstructure:"close_keyword"`
+	CommitRefFormat       string                       `yaml:"commit_ref_format,omitempty" mapstructure:"commit_ref_format"`
+	BranchTypeLabels      map[string]string            `yaml:"branch_type_labels,omitempty" mapstructure:"branch_type_labels"`
+	KeywordLabelMap       map[string]string            `yaml:"keyword_label_map,omitempty" mapstructure:"keyword_label_map"`
+	LabelColorMap         map[string]string            `yaml:"label_color_map,omitempty" mapstructure:"label_color_map"`
+	LabelAliases          map[string]map[string]string `yaml:"label_aliases,omitempty" mapstructure:"label_aliases"` // Provider -> canonical label -> the provider's name for it
+	Presets               map[string]LabelList         `yaml:"presets,omitempty" mapstructure:"presets"`
+	AssigneeRules         []AssigneeRule               `yaml:"assignee_rules,omitempty" mapstructure:"assignee_rules"`
+	Rotations             map[string]Rotation          `yaml:"rotations,omitempty" mapstructure:"rotations"`
+	PromptLabels          *bool                        `yaml:"prompt_labels,omitempty" mapstructure:"prompt_labels"`
+	InteractiveLabels     bool                         `yaml:"interactive_labels,omitempty" mapstructure:"interactive_labels"`
+	BranchTemplate        string                       `yaml:"branch_template,omitempty" mapstructure:"branch_template"`
+	BranchIncludeSlug     *bool                        `yaml:"branch_include_slug,omitempty" mapstructure:"branch_include_slug"`
+	BranchMaxLength       int                          `yaml:"branch_max_length,omitempty" mapstructure:"branch_max_length"`
+	SlugTransliterate     bool                         `yaml:"slug_transliterate,omitempty" mapstructure:"slug_transliterate"`
+	DefaultConfidential   bool                         `yaml:"default_confidential,omitempty" mapstructure:"default_confidential"`
+	IssueTemplate         string                       `yaml:"issue_template,omitempty" mapstructure:"issue_template"`
+	StrictTemplates       bool                         `yaml:"strict_templates,omitempty" mapstructure:"strict_templates"`
+	StrictScopedLabels    bool                         `yaml:"strict_scoped_labels,omitempty" mapstructure:"strict_scoped_labels"`
+	RequireLabels         bool                         `yaml:"require_labels,omitempty" mapstructure:"require_labels"`
+	ConfirmNewLabels      bool                         `yaml:"confirm_new_labels,omitempty" mapstructure:"confirm_new_labels"`
+	IdempotentCreate      bool                         `yaml:"idempotent_create,omitempty" mapstructure:"idempotent_create"`
+	TitleTransform        TitleTransform               `yaml:"title_transform,omitempty" mapstructure:"title_transform"`
+	TrackUpstream         bool                         `yaml:"track_upstream,omitempty" mapstructure:"track_upstream"`
+	FFBaseBeforeCreate    bool                         `yaml:"ff_base_before_create,omitempty" mapstructure:"ff_base_before_create"`
+	CheckBranchProtection bool                         `yaml:"check_branch_protection,omitempty" mapstructure:"check_branch_protection"`
+	EstimateLabelTemplate string                       `yaml:"estimate_label_template,omitempty" mapstructure:"estimate_label_template"`
+	Protected             bool                         `yaml:"protected,omitempty" mapstructure:"protected"` // Confirm before filing issues here
+	NotifyWebhook         string                       `yaml:"notify_webhook,omitempty" mapstructure:"notify_webhook"`
+	Worktree              WorktreeConfig               `yaml:"worktree,omitempty" mapstructure:"worktree"`
 }
 
 // Settings represents the root configuration
 type Settings struct {
-	ReadyLabel    string         `yaml:"ready_label" mapstructure:"ready_label"`
-	ReadyStatus   string         `yaml:"ready_status" mapstructure:"ready_status"`
-	UnreadyLabel  string         `yaml:"unready_label" mapstructure:"unready_label"`
-	UnreadyStatus string         `yaml:"unready_status" mapstructure:"unready_status"`
-	Worktree      WorktreeConfig `yaml:"worktree,omitempty" mapstructure:"worktree"`
-	Repositories  []Repository   `yaml:"repositories" mapstructure:"repositories"`
+	ReadyLabel            string                       `yaml:"ready_label" mapstructure:"ready_label"`
+	ReadyStatus           string                       `yaml:"ready_status" mapstructure:"ready_status"`
+	UnreadyLabel          string                       `yaml:"unready_label" mapstructure:"unready_label"`
+	UnreadyStatus         string                       `yaml:"unready_status" mapstructure:"unready_status"`
+	PrimaryProvider       string                       `yaml:"primary_provider,omitempty" mapstructure:"primary_provider"`
+	GitlabBranchID        string                       `yaml:"gitlab_branch_id,omitempty" mapstructure:"gitlab_branch_id"`
+	CloseKeyword          string                       `yaml:"close_keyword,omitempty" mapstructure:"close_keyword"`
+	CommitRefFormat       string                       `yaml:"commit_ref_format,omitempty" mapstructure:"commit_ref_format"`
+	BranchTypeLabels      map[string]string            `yaml:"branch_type_labels,omitempty" mapstructure:"branch_type_labels"`
+	KeywordLabelMap       map[string]string            `yaml:"keyword_label_map,omitempty" mapstructure:"keyword_label_map"`
+	LabelColorMap         map[string]string            `yaml:"label_color_map,omitempty" mapstructure:"label_color_map"`
+	LabelAliases          map[string]map[string]string `yaml:"label_aliases,omitempty" mapstructure:"label_aliases"` // Provider -> canonical label -> the provider's name for it
+	Presets               map[string]LabelList         `yaml:"presets,omitempty" mapstructure:"presets"`
+	AssigneeRules         []AssigneeRule               `yaml:"assignee_rules,omitempty" mapstructure:"assignee_rules"`
+	Rotations             map[string]Rotation          `yaml:"rotations,omitempty" mapstructure:"rotations"`
+	PromptLabels          *bool                        `yaml:"prompt_labels,omitempty" mapstructure:"prompt_labels"`
+	InteractiveLabels     bool                         `yaml:"interactive_labels,omitempty" mapstructure:"interactive_labels"`
+	BranchTemplate        string                       `yaml:"branch_template,omitempty" mapstructure:"branch_template"`
+	BranchIncludeSlug     *bool                        `yaml:"branch_include_slug,omitempty" mapstructure:"branch_include_slug"`
+	BranchMaxLength       int                          `yaml:"branch_max_length,omitempty" mapstructure:"branch_max_length"`
+	SlugTransliterate     bool                         `yaml:"slug_transliterate,omitempty" mapstructure:"slug_transliterate"`
+	TrackUpstream         bool                         `yaml:"track_upstream,omitempty" mapstructure:"track_upstream"`
+	FFBaseBeforeCreate    bool                         `yaml:"ff_base_before_create,omitempty" mapstructure:"ff_base_before_create"`
+	CheckBranchProtection bool                         `yaml:"check_branch_protection,omitempty" mapstructure:"check_branch_protection"`
+	EstimateLabelTemplate string                       `yaml:"estimate_label_template,omitempty" mapstructure:"estimate_label_template"`
+	StrictTemplates       bool                         `yaml:"strict_templates,omitempty" mapstructure:"strict_templates"`
+	StrictScopedLabels    bool                         `yaml:"strict_scoped_labels,omitempty" mapstructure:"strict_scoped_labels"`
+	RequireLabels         bool                         `yaml:"require_labels,omitempty" mapstructure:"require_labels"`
+	ConfirmNewLabels      bool                         `yaml:"confirm_new_labels,omitempty" mapstructure:"confirm_new_labels"`
+	IdempotentCreate      bool                         `yaml:"idempotent_create,omitempty" mapstructure:"idempotent_create"`
+	TitleTransform        TitleTransform               `yaml:"title_transform,omitempty" mapstructure:"title_transform"`
+	Worktree              WorktreeConfig               `yaml:"worktree,omitempty" mapstructure:"worktree"`
+	GithubToken           string                       `yaml:"github_token,omitempty" mapstructure:"github_token"`
+	GithubTokenFile       string                       `yaml:"github_token_file,omitempty" mapstructure:"github_token_file"`
+	GitlabToken           string                       `yaml:"gitlab_token,omitempty" mapstructure:"gitlab_token"`
+	GitlabTokenFile       string                       `yaml:"gitlab_token_file,omitempty" mapstructure:"gitlab_token_file"`
+	CACertPath            string                       `yaml:"ca_cert_path,omitempty" mapstructure:"ca_cert_path"`
+	UserAgent             string                       `yaml:"user_agent,omitempty" mapstructure:"user_agent"`
+	HTTPHeaders           map[string]string            `yaml:"http_headers,omitempty" mapstructure:"http_headers"`
+	History               HistoryConfig                `yaml:"history,omitempty" mapstructure:"history"`
+	NotifyWebhook         string                       `yaml:"notify_webhook,omitempty" mapstructure:"notify_webhook"`
+	Repositories          []Repository                 `yaml:"repositories" mapstructure:"repositories"`
+}
+
+// ResolveWorktreeEnabled reports whether new branches for a repo get a
+// worktree when --worktree isn't given. Resolution order: per-repo > global >
+// false, so a repo can opt out of worktrees enabled globally.
+func (s *Settings) ResolveWorktreeEnabled(repo *Repository) bool {
+	if repo.Worktree.Enabled != nil {
+		return *repo.Worktree.Enabled
+	}
+	if s.Worktree.Enabled != nil {
+		return *s.Worktree.Enabled
+	}
+	return false
 }
 
 // ResolveWorktreePath returns the worktree base path for a repo.
@@ -53,39 +338,619 @@ func (s *Settings) ResolveWorktreePath(repo *Repository) string {
 	return filepath.Join(repo.Directory, ".worktrees")
 }
 
+// ResolveWorktreeDir returns the directory for a new worktree by rendering the
+// path template ({id}, {slug}, {branch}, {repo}) below the worktree base path.
+// Template resolution order: per-repo > global > "{branch}"
+// An error is returned if the rendered path escapes the worktree base path.
+func (s *Settings) ResolveWorktreeDir(repo *Repository, vars WorktreePathVars) (string, error) {
+	tmpl := DefaultWorktreePathTemplate
+	if repo.Worktree.PathTemplate != "" {
+		tmpl = repo.Worktree.PathTemplate
+	} else if s.Worktree.PathTemplate != "" {
+		tmpl = s.Worktree.PathTemplate
+	}
+
+	rendered := strings.NewReplacer(
+		"{id}", vars.ID,
+		"{slug}", vars.Slug,
+		"{branch}", vars.Branch,
+		"{repo}", vars.Repo,
+	).Replace(tmpl)
+
+	base := s.ResolveWorktreePath(repo)
+	if filepath.IsAbs(rendered) {
+		return "", fmt.Errorf("worktree path template %q must be relative to %s", tmpl, base)
+	}
+
+	dir := filepath.Join(base, rendered)
+	rel, err := filepath.Rel(base, dir)
+	if err != nil || rel == "." || rel == ".." || strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+		return "", fmt.Errorf("worktree path template %q escapes the worktree directory %s", tmpl, base)
+	}
+	return dir, nil
+}
+
 // ResolveDefaultBranch returns the default branch for a repo.
 // Resolution order: per-repo worktree > per-repo default branch > global worktree > "main"
 func (s *Settings) ResolveDefaultBranch(repo *Repository) string {
+	if branch := s.ConfiguredDefaultBranch(repo); branch != "" {
+		return branch
+	}
+	return "main"
+}
+
+// ConfiguredDefaultBranch returns the default branch set in config, or "" if
+// none is configured (so callers can detect it from the repository instead)
+func (s *Settings) ConfiguredDefaultBranch(repo *Repository) string {
 	if repo.Worktree.DefaultBranch != "" {
 		return repo.Worktree.DefaultBranch
 	}
 	if repo.DefaultBranch != "" {
 		return repo.DefaultBranch
 	}
-	if s.Worktree.DefaultBranch != "" {
-		return s.Worktree.DefaultBranch
+	return s.Worktree.DefaultBranch
+}
+
+// closeKeywords lists the issue-closing keywords understood by both GitHub and GitLab
+var closeKeywords = []string{"Closes", "Fixes", "Resolves"}
+
+// DefaultCloseKeyword is used when no close_keyword is configured
+const DefaultCloseKeyword = "Closes"
+
+// NormalizeCloseKeyword validates a closing keyword (case-insensitive) and
+// returns its canonical form, e.g. "fixes" -> "Fixes".
+func NormalizeCloseKeyword(keyword string) (string, error) {
+	for _, kw := range closeKeywords {
+		if strings.EqualFold(strings.TrimSpace(keyword), kw) {
+			return kw, nil
+		}
+	}
+	return "", fmt.Errorf("invalid close keyword %q: must be one of %s", keyword, strings.Join(closeKeywords, ", "))
+}
+
+// ResolveCloseKeyword returns the keyword used to link a merge request to its issue.
+// Resolution order: per-repo > global > "Closes"
+func (s *Settings) ResolveCloseKeyword(repo *Repository) (string, error) {
+	if repo.CloseKeyword != "" {
+		return NormalizeCloseKeyword(repo.CloseKeyword)
+	}
+	if s.CloseKeyword != "" {
+		return NormalizeCloseKeyword(s.CloseKeyword)
 	}
-	return "main"
+	return DefaultCloseKeyword, nil
+}
+
+// DefaultCommitRefFormat is how the commit-msg hook from tix install-hooks
+// references a branch's issue
+const DefaultCommitRefFormat = "Refs {ref}"
+
+// ResolveCommitRefFormat returns the line the commit-msg hook adds to commit
+// messages, with {id} for the issue number and {ref} for the issue reference
+// ("#12", or "group/project#12" for a cross-repo branch).
+// Resolution order: per-repo > global > "Refs {ref}"
+func (s *Settings) ResolveCommitRefFormat(repo *Repository) string {
+	if repo.CommitRefFormat != "" {
+		return repo.CommitRefFormat
+	}
+	if s.CommitRefFormat != "" {
+		return s.CommitRefFormat
+	}
+	return DefaultCommitRefFormat
+}
+
+// ResolveBranchTypeLabel returns the label mapped to a branch type (e.g. bug -> bug,
+// feature -> enhancement), or "" if the type has no mapping.
+// Resolution order: per-repo > global
+func (s *Settings) ResolveBranchTypeLabel(repo *Repository, branchType string) string {
+	branchType = strings.ToLower(branchType)
+	if label, ok := repo.BranchTypeLabels[branchType]; ok {
+		return label
+	}
+	return s.BranchTypeLabels[branchType]
+}
+
+// ResolveAssignees returns the assignees the assignee_rules give a new issue
+// with labels, created from dir (see AssigneeRule.Matches): those of every
+// matching rule, in order and without duplicates. Resolution order: per-repo
+// rules > global rules.
+func (s *Settings) ResolveAssignees(repo *Repository, labels []string, dir string) []string {
+	rules := s.AssigneeRules
+	if len(repo.AssigneeRules) > 0 {
+		rules = repo.AssigneeRules
+	}
+
+	var assignees []string
+	seen := map[string]bool{}
+	for _, rule := range rules {
+		if !rule.Matches(labels, dir) {
+			continue
+		}
+		for _, assignee := range rule.Assignees {
+			assignee = strings.TrimSpace(assignee)
+			if assignee != "" && !seen[strings.ToLower(assignee)] {
+				seen[strings.ToLower(assignee)] = true
+				assignees = append(assignees, assignee)
+			}
+		}
+	}
+	return assignees
+}
+
+// ResolveKeywordLabels returns the title keyword to label map used to suggest
+// labels (e.g. perf -> performance), with lowercased keywords.
+// Resolution order: per-repo entries > global entries
+func (s *Settings) ResolveKeywordLabels(repo *Repository) map[string]string {
+	keywords := make(map[string]string, len(s.KeywordLabelMap)+len(repo.KeywordLabelMap))
+	for keyword, label := range s.KeywordLabelMap {
+		keywords[strings.ToLower(keyword)] = label
+	}
+	for keyword, label := range repo.KeywordLabelMap {
+		keywords[strings.ToLower(keyword)] = label
+	}
+	return keywords
 }
 
+// ResolveLabelAliases returns the label_aliases entries for provider
+// (ProviderGitHub or ProviderGitLab): the names its projects use for
+// canonical labels, keyed by the lowercased canonical label.
+// Resolution order: per-repo entries > global entries
+func (s *Settings) ResolveLabelAliases(repo *Repository, provider string) map[string]string {
+	aliases := make(map[string]string)
+	for _, providers := range []map[string]map[string]string{s.LabelAliases, repo.LabelAliases} {
+		for name, labels := range providers {
+			if normalized, err := NormalizeProvider(name); err != nil || normalized != provider {
+				continue
+			}
+			for label, alias := range labels {
+				aliases[strings.ToLower(label)] = strings.TrimSpace(alias)
+			}
+		}
+	}
+	return aliases
+}
+
+// AliasLabels replaces each of labels that has an entry in aliases (see
+// ResolveLabelAliases) with its alias, dropping labels that end up
+// duplicated
+func AliasLabels(labels []string, aliases map[string]string) []string {
+	if len(aliases) == 0 {
+		return labels
+	}
+	var result []string
+	seen := make(map[string]bool)
+	for _, label := range labels {
+		if alias := aliases[strings.ToLower(label)]; alias != "" {
+			label = alias
+		}
+		if seen[strings.ToLower(label)] {
+			continue
+		}
+		seen[strings.ToLower(label)] = true
+		result = append(result, label)
+	}
+	return result
+}
+
+// DefaultLabelColor is the color of labels tix creates when label_color_map
+// is configured but none of its patterns match (GitHub's own default)
+const DefaultLabelColor = "#ededed"
+
+// ResolveLabelColor returns the color for creating label, from the
+// label_color_map pattern matching it: an exact name wins, otherwise the
+// longest pattern whose "*" wildcards match, compared case-insensitively.
+// It's DefaultLabelColor when no pattern matches and "" when no
+// label_color_map is configured, leaving the color to the provider.
+// Resolution order: per-repo entries > global entries
+func (s *Settings) ResolveLabelColor(repo *Repository, label string) (string, error) {
+	colors := make(map[string]string, len(s.LabelColorMap)+len(repo.LabelColorMap))
+	for pattern, color := range s.LabelColorMap {
+		colors[strings.ToLower(pattern)] = color
+	}
+	for pattern, color := range repo.LabelColorMap {
+		colors[strings.ToLower(pattern)] = color
+	}
+	if len(colors) == 0 {
+		return "", nil
+	}
+
+	label = strings.ToLower(label)
+	best, found := "", false
+	for pattern := range colors {
+		if pattern == label {
+			best, found = pattern, true
+			break
+		}
+		if matchLabelPattern(pattern, label) && (!found || len(pattern) > len(best) || (len(pattern) == len(best) && pattern < best)) {
+			best, found = pattern, true
+		}
+	}
+	if !found {
+		return DefaultLabelColor, nil
+	}
+
+	color := strings.TrimSpace(colors[best])
+	if !strings.HasPrefix(color, "#") {
+		color = "#" + color
+	}
+	if !isHexColor(color) {
+		return "", fmt.Errorf("invalid color %q for label_color_map pattern %s: use a hex color like #ff0000", colors[best], best)
+	}
+	return strings.ToLower(color), nil
+}
+
+// matchLabelPattern reports whether label matches pattern, where each "*"
+// stands for any run of characters
+func matchLabelPattern(pattern, label string) bool {
+	parts := strings.Split(pattern, "*")
+	if len(parts) == 1 {
+		return pattern == label
+	}
+	if !strings.HasPrefix(label, parts[0]) {
+		return false
+	}
+	label = label[len(parts[0]):]
+	for _, part := range parts[1 : len(parts)-1] {
+		i := strings.Index(label, part)
+		if i < 0 {
+			return false
+		}
+		label = label[i+len(part):]
+	}
+	return strings.HasSuffix(label, parts[len(parts)-1])
+}
+
+// isHexColor reports whether color is "#" followed by six hex digits
+func isHexColor(color string) bool {
+	if len(color) != 7 || color[0] != '#' {
+		return false
+	}
+	_, err := strconv.ParseUint(color[1:], 16, 32)
+	return err == nil
+}
+
+// ResolveLabelPreset returns the labels of the preset called name, matched
+// case-insensitively, for --preset. Per-repo presets replace global ones of
+// the same name; an unknown name is an error listing the available presets.
+func (s *Settings) ResolveLabelPreset(repo *Repository, name string) ([]string, error) {
+	presets := make(map[string]LabelList, len(s.Presets)+len(repo.Presets))
+	for preset, labels := range s.Presets {
+		presets[strings.ToLower(preset)] = labels
+	}
+	for preset, labels := range repo.Presets {
+		presets[strings.ToLower(preset)] = labels
+	}
+	if labels, ok := presets[strings.ToLower(name)]; ok {
+		return NormalizeLabels(labels), nil
+	}
+
+	if len(presets) == 0 {
+		return nil, fmt.Errorf("unknown label preset '%s' - no presets are configured", name)
+	}
+	names := make([]string, 0, len(presets))
+	for preset := range presets {
+		names = append(names, preset)
+	}
+	sort.Strings(names)
+	return nil, fmt.Errorf("unknown label preset '%s' - available presets: %s", name, strings.Join(names, ", "))
+}
+
+// ResolveRotation returns the rotation configured as name (matched
+// case-insensitively) with its strategy filled in, and the key its turns are
+// recorded under: the lowercased name, prefixed with the lowercased
+// repository name for a per-repo rotation so that repositories don't share
+// turns. Resolution order: per-repo > global.
+func (s *Settings) ResolveRotation(repo *Repository, name string) (string, Rotation, error) {
+	rotations := make(map[string]Rotation, len(s.Rotations)+len(repo.Rotations))
+	perRepo := make(map[string]bool, len(repo.Rotations))
+	for rotation, r := range s.Rotations {
+		rotations[strings.ToLower(rotation)] = r
+	}
+	for rotation, r := range repo.Rotations {
+		rotations[strings.ToLower(rotation)] = r
+		perRepo[strings.ToLower(rotation)] = true
+	}
+
+	key := strings.ToLower(name)
+	rotation, ok := rotations[key]
+	if !ok {
+		if len(rotations) == 0 {
+			return "", Rotation{}, fmt.Errorf("unknown rotation '%s' - no rotations are configured", name)
+		}
+		names := make([]string, 0, len(rotations))
+		for rotation := range rotations {
+			names = append(names, rotation)
+		}
+		sort.Strings(names)
+		return "", Rotation{}, fmt.Errorf("unknown rotation '%s' - available rotations: %s", name, strings.Join(names, ", "))
+	}
+
+	var members []string
+	for _, member := range rotation.Members {
+		if member = strings.TrimSpace(member); member != "" {
+			members = append(members, member)
+		}
+	}
+	if len(members) == 0 {
+		return "", Rotation{}, fmt.Errorf("rotation '%s' has no members", name)
+	}
+	rotation.Members = members
+
+	switch strategy := strings.ToLower(strings.TrimSpace(rotation.Strategy)); strategy {
+	case "", RotationRoundRobin:
+		rotation.Strategy = RotationRoundRobin
+	case RotationRandom:
+		rotation.Strategy = strategy
+	default:
+		return "", Rotation{}, fmt.Errorf("invalid strategy %q for rotation '%s': must be round_robin or random", rotation.Strategy, name)
+	}
+	if perRepo[key] {
+		key = strings.ToLower(repo.Name) + "/" + key
+	}
+	return key, rotation, nil
+}
+
+// ResolvePromptLabels reports whether tix create asks for labels when none
+// are given with --label. Resolution order: per-repo > global > true; with it
+// off, new issues get only the default labels.
+func (s *Settings) ResolvePromptLabels(repo *Repository) bool {
+	if repo.PromptLabels != nil {
+		return *repo.PromptLabels
+	}
+	if s.PromptLabels != nil {
+		return *s.PromptLabels
+	}
+	return true
+}
+
+// ResolveInteractiveLabels reports whether the labels prompt is pre-filled
+// with labels suggested by keyword_label_map. It is on when enabled globally
+// or for the repo; --interactive-labels on the command line takes precedence.
+func (s *Settings) ResolveInteractiveLabels(repo *Repository) bool {
+	return repo.InteractiveLabels || s.InteractiveLabels
+}
+
+// configFile is the config file set with --config; empty means use the default
+var configFile string
+
+// SetConfigFile overrides the config file read by Load (the --config flag)
+func SetConfigFile(path string) {
+	configFile = path
+}
+
+// ConfigPath returns the config file Load reads.
+// Resolution order: --config > TIX_CONFIG env var > ~/.tix.yaml > ~/.tix.yml
+// The returned file may not exist.
+func ConfigPath() (string, error) {
+	if configFile != "" {
+		return expandHomeDir(configFile), nil
+	}
+	if envFile := os.Getenv("TIX_CONFIG"); envFile != "" {
+		return expandHomeDir(envFile), nil
+	}
+
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to determine home directory: %w", err)
+	}
+	for _, name := range []string{".tix.yaml", ".tix.yml"} {
+		path := filepath.Join(home, name)
+		if _, err := os.Stat(path); err == nil {
+			return path, nil
+		}
+	}
+	return filepath.Join(home, ".tix.yml"), nil
+}
+
+// Redacted returns a copy of the settings with API tokens and webhook URLs
+// (which embed a secret) masked, safe to print
+func (s Settings) Redacted() Settings {
+	if s.GithubToken != "" {
+		s.GithubToken = redactedValue
+	}
+	if s.GitlabToken != "" {
+		s.GitlabToken = redactedValue
+	}
+	if s.NotifyWebhook != "" {
+		s.NotifyWebhook = redactedValue
+	}
+
+	repos := make([]Repository, len(s.Repositories))
+	copy(repos, s.Repositories)
+	for i := range repos {
+		if repos[i].NotifyWebhook != "" {
+			repos[i].NotifyWebhook = redactedValue
+		}
+	}
+	s.Repositories = repos
+	return s
+}
+
+// redactedValue replaces secrets in printed settings
+const redactedValue = "<redacted>"
+
+// DefaultBranchTemplate gives branches like feature/issues-123-add-export;
+// empty placeholders drop out along with their separators
+const DefaultBranchTemplate = "{type}/{prefix}-{id}-{slug}"
+
+// ResolveBranchTemplate returns the branch name template for a repo.
+// Resolution order: per-repo > global > DefaultBranchTemplate
+func (s *Settings) ResolveBranchTemplate(repo *Repository) (string, error) {
+	tmpl := DefaultBranchTemplate
+	if repo.BranchTemplate != "" {
+		tmpl = repo.BranchTemplate
+	} else if s.BranchTemplate != "" {
+		tmpl = s.BranchTemplate
+	}
+	if err := ValidateBranchTemplate(tmpl); err != nil {
+		return "", err
+	}
+	return tmpl, nil
+}
+
+// ResolveBranchIncludeSlug reports whether branch names for a repo include
+// the issue title slug. Resolution order: per-repo > global > true; with the
+// slug off, the default template gives branches named just "123".
+func (s *Settings) ResolveBranchIncludeSlug(repo *Repository) bool {
+	if repo.BranchIncludeSlug != nil {
+		return *repo.BranchIncludeSlug
+	}
+	if s.BranchIncludeSlug != nil {
+		return *s.BranchIncludeSlug
+	}
+	return true
+}
+
+// DefaultBranchMaxLength is the longest branch name tix builds unless
+// branch_max_length says otherwise
+const DefaultBranchMaxLength = 100
+
+// ResolveBranchMaxLength returns the longest branch name, in bytes, built for
+// a repo's issues; longer names lose the end of their slug.
+// Resolution order: per-repo > global > DefaultBranchMaxLength
+func (s *Settings) ResolveBranchMaxLength(repo *Repository) int {
+	if repo.BranchMaxLength > 0 {
+		return repo.BranchMaxLength
+	}
+	if s.BranchMaxLength > 0 {
+		return s.BranchMaxLength
+	}
+	return DefaultBranchMaxLength
+}
+
+// ResolveSlugTransliterate reports whether non-ASCII letters in a repo's
+// branch and worktree slugs are transliterated ("café" -> "cafe") instead of
+// kept as they are. It is on when enabled globally or for the repo.
+func (s *Settings) ResolveSlugTransliterate(repo *Repository) bool {
+	return repo.SlugTransliterate || s.SlugTransliterate
+}
+
+// ResolveTrackUpstream reports whether new branches for a repo should track
+// the remote copy of their base branch. It is on when enabled globally or for
+// the repo; --track / --track=false on the command line takes precedence.
+func (s *Settings) ResolveTrackUpstream(repo *Repository) bool {
+	return repo.TrackUpstream || s.TrackUpstream
+}
+
+// ResolveFFBaseBeforeCreate reports whether the local base branch is
+// fast-forwarded to its upstream before a repo's new branches are created.
+// It is on when enabled globally or for the repo.
+func (s *Settings) ResolveFFBaseBeforeCreate(repo *Repository) bool {
+	return repo.FFBaseBeforeCreate || s.FFBaseBeforeCreate
+}
+
+// ResolveCheckBranchProtection reports whether tix create checks the
+// protection of a repo's default branch after creating a branch. It is on
+// when enabled globally or for the repo.
+func (s *Settings) ResolveCheckBranchProtection(repo *Repository) bool {
+	return repo.CheckBranchProtection || s.CheckBranchProtection
+}
+
+// ResolveStrictTemplates reports whether unfilled {{name}} placeholders in an
+// issue description are an error (true) or only a warning. It is on when
+// enabled globally or for the repo.
+func (s *Settings) ResolveStrictTemplates(repo *Repository) bool {
+	return repo.StrictTemplates || s.StrictTemplates
+}
+
+// ResolveStrictScopedLabels reports whether conflicting GitLab scoped labels
+// on a new issue are an error (true) or only a warning. It is on when enabled
+// globally or for the repo.
+func (s *Settings) ResolveStrictScopedLabels(repo *Repository) bool {
+	return repo.StrictScopedLabels || s.StrictScopedLabels
+}
+
+// ResolveRequireLabels reports whether a new issue must have at least one
+// label once all label sources are merged. It is on when enabled globally or
+// for the repo.
+func (s *Settings) ResolveRequireLabels(repo *Repository) bool {
+	return repo.RequireLabels || s.RequireLabels
+}
+
+// ResolveConfirmNewLabels reports whether tix create must confirm, or be
+// given --create-labels, before filing an issue with labels the project
+// doesn't have yet. It is on when enabled globally or for the repo.
+func (s *Settings) ResolveConfirmNewLabels(repo *Repository) bool {
+	return repo.ConfirmNewLabels || s.ConfirmNewLabels
+}
+
+// ResolveIdempotentCreate reports whether new issues get a hidden idempotency
+// key so a retry after a lost response finds the issue instead of filing it
+// again. It is on when enabled globally or for the repo.
+func (s *Settings) ResolveIdempotentCreate(repo *Repository) bool {
+	return repo.IdempotentCreate || s.IdempotentCreate
+}
+
+// ResolveTitleTransform returns the transforms applied to a repo's new issue
+// titles. Each one is on when enabled globally or for the repo.
+func (s *Settings) ResolveTitleTransform(repo *Repository) TitleTransform {
+	return TitleTransform{
+		Trim:               repo.TitleTransform.Trim || s.TitleTransform.Trim,
+		CollapseWhitespace: repo.TitleTransform.CollapseWhitespace || s.TitleTransform.CollapseWhitespace,
+		SentenceCase:       repo.TitleTransform.SentenceCase || s.TitleTransform.SentenceCase,
+	}
+}
+
+// DefaultEstimateLabelTemplate is the label GitHub issues get for --estimate
+const DefaultEstimateLabelTemplate = "estimate/{n}"
+
+// ResolveEstimateLabel returns the label that records an estimate of n on
+// GitHub, which has no issue weights. The template is resolved per-repo >
+// global > DefaultEstimateLabelTemplate and must contain {n}.
+func (s *Settings) ResolveEstimateLabel(repo *Repository, n int) (string, error) {
+	tmpl := DefaultEstimateLabelTemplate
+	if repo.EstimateLabelTemplate != "" {
+		tmpl = repo.EstimateLabelTemplate
+	} else if s.EstimateLabelTemplate != "" {
+		tmpl = s.EstimateLabelTemplate
+	}
+	if !strings.Contains(tmpl, "{n}") {
+		return "", fmt.Errorf("invalid estimate_label_template %q - it must contain {n}", tmpl)
+	}
+	return strings.ReplaceAll(tmpl, "{n}", strconv.Itoa(n)), nil
+}
+
+// ValidateBranchTemplate checks that tix can read the issue back from branches
+// built with the template: the last path segment must start with {id} or
+// {prefix}-{id}, as in "{user}/{id}-{slug}".
+func ValidateBranchTemplate(tmpl string) error {
+	last := tmpl[strings.LastIndex(tmpl, "/")+1:]
+	if !strings.HasPrefix(last, "{id}") && !strings.HasPrefix(last, "{prefix}-{id}") {
+		return fmt.Errorf("branch_template %q must end with a segment starting with {id} or {prefix}-{id}, e.g. \"{user}/{id}-{slug}\"", tmpl)
+	}
+	return nil
+}
+
+// ErrConfigNotFound is returned (wrapped, with the path) by Load when there
+// is no config file, e.g. on a first run
+var ErrConfigNotFound = errors.New("config file not found")
+
 // Load reads the configuration from the specified file
 func Load() (*Settings, error) {
+	path, err := ConfigPath()
+	if err != nil {
+		return nil, err
+	}
+	if _, err := os.Stat(path); errors.Is(err, os.ErrNotExist) {
+		return nil, fmt.Errorf("%w: %s", ErrConfigNotFound, path)
+	}
+
 	v := viper.New()
-	v.SetConfigName(".tix")
+	v.SetConfigFile(path)
 	v.SetConfigType("yaml")
-	v.AddConfigPath("$HOME")
-
-	// Enable env var substitution
-	v.AutomaticEnv()
 
 	// Read the config file
 	if err := v.ReadInConfig(); err != nil {
 		return nil, fmt.Errorf("failed to read config file: %w", err)
 	}
+	fileValues := v.AllSettings()
+
+	// Enable env var substitution
+	v.AutomaticEnv()
 
 	var settings Settings
 	decoderConfig := &mapstructure.DecoderConfig{
 		WeaklyTypedInput: true,
+		DecodeHook:       labelListHook,
 		Result:           &settings,
 	}
 
@@ -94,8 +959,16 @@ func Load() (*Settings, error) {
 		return nil, fmt.Errorf("failed to create decoder: %w", err)
 	}
 
-	if err := decoder.Decode(v.AllSettings()); err != nil {
-		return nil, fmt.Errorf("failed to unmarshal config: %w", err)
+	raw := v.AllSettings()
+	// GITHUB_TOKEN and GITLAB_TOKEN are only the fallback for tokens the file
+	// doesn't give (see ResolveGithubToken), so they never replace its own
+	for _, key := range []string{"github_token", "gitlab_token"} {
+		if value, ok := fileValues[key]; ok {
+			raw[key] = value
+		}
+	}
+	if err := decoder.Decode(raw); err != nil {
+		return nil, decodeError(path, raw, err)
 	}
 
 	// Expand home directory in paths (only if directory is set)
@@ -109,6 +982,60 @@ func Load() (*Settings, error) {
 	return &settings, nil
 }
 
+// ResolveGithubToken returns the GitHub API token.
+// Resolution order: github_token > github_token_file > GITHUB_TOKEN env var
+func (s *Settings) ResolveGithubToken() (string, error) {
+	return resolveToken(s.GithubToken, s.GithubTokenFile, "github_token_file", "GITHUB_TOKEN")
+}
+
+// ResolveGitlabToken returns the GitLab API token.
+// Resolution order: gitlab_token > gitlab_token_file > GITLAB_TOKEN env var
+func (s *Settings) ResolveGitlabToken() (string, error) {
+	return resolveToken(s.GitlabToken, s.GitlabTokenFile, "gitlab_token_file", "GITLAB_TOKEN")
+}
+
+// ResolveHistoryPath returns the history.path setting with ~/ expanded, or an
+// empty string for the default location in the state directory
+func (s *Settings) ResolveHistoryPath() string {
+	return expandHomeDir(s.History.Path)
+}
+
+// ResolveNotifyWebhook returns the URL to notify when an issue is created, or
+// "" for none. Resolution order: per-repo > global
+func (s *Settings) ResolveNotifyWebhook(repo *Repository) string {
+	if repo.NotifyWebhook != "" {
+		return repo.NotifyWebhook
+	}
+	return s.NotifyWebhook
+}
+
+// ResolveCACertPath returns the ca_cert_path setting with ~/ expanded, or an
+// empty string when only the system CAs are trusted
+func (s *Settings) ResolveCACertPath() string {
+	return expandHomeDir(s.CACertPath)
+}
+
+// resolveToken returns the explicit token if set, otherwise the trimmed
+// contents of the token file, otherwise the environment variable
+func resolveToken(token, tokenFile, fileSetting, envVar string) (string, error) {
+	if token != "" {
+		return token, nil
+	}
+	if tokenFile != "" {
+		path := expandHomeDir(tokenFile)
+		data, err := os.ReadFile(path)
+		if err != nil {
+			return "", fmt.Errorf("failed to read %s %s: %w", fileSetting, path, err)
+		}
+		fileToken := strings.TrimSpace(string(data))
+		if fileToken == "" {
+			return "", fmt.Errorf("%s %s is empty", fileSetting, path)
+		}
+		return fileToken, nil
+	}
+	return os.Getenv(envVar), nil
+}
+
 // expandHomeDir expands the home directory in a path
 func expandHomeDir(path string) string {
 	if path == "" {
@@ -125,6 +1052,93 @@ func expandHomeDir(path string) string {
 	return path
 }
 
+// DefaultLabelList returns the repo's default labels, trimmed and de-duplicated
+func (r *Repository) DefaultLabelList() []string {
+	return NormalizeLabels(r.DefaultLabels)
+}
+
+// Provider names accepted by --provider and primary_provider
+const (
+	ProviderGitHub = "github"
+	ProviderGitLab = "gitlab"
+)
+
+// NormalizeProvider validates a provider name, ignoring case
+func NormalizeProvider(name string) (string, error) {
+	switch provider := strings.ToLower(strings.TrimSpace(name)); provider {
+	case ProviderGitHub, ProviderGitLab:
+		return provider, nil
+	}
+	return "", fmt.Errorf("invalid provider %q: must be github or gitlab", name)
+}
+
+// IsMirrored reports whether the repository is configured on both GitHub
+// and GitLab, so each run has to pick one of them
+func (r *Repository) IsMirrored() bool {
+	return r.GithubRepo != "" && r.GitlabRepo != ""
+}
+
+// Provider returns the provider the repository's issues are on; GitLab for
+// a mirrored repository that hasn't picked one with UseProvider
+func (r *Repository) Provider() string {
+	if r.GitlabRepo != "" {
+		return ProviderGitLab
+	}
+	return ProviderGitHub
+}
+
+// UseProvider makes a mirrored repository a repository of provider alone for
+// this run by dropping the other provider's slug
+func (r *Repository) UseProvider(provider string) error {
+	provider, err := NormalizeProvider(provider)
+	if err != nil {
+		return err
+	}
+	if provider == ProviderGitHub {
+		r.GitlabRepo = ""
+	} else {
+		r.GithubRepo = ""
+	}
+	return nil
+}
+
+// Values of gitlab_branch_id: the issue's number within its project, or its
+// instance-wide id
+const (
+	GitlabBranchIDIID    = "iid"
+	GitlabBranchIDGlobal = "id"
+)
+
+// ResolveGitlabBranchID returns which GitLab issue id new branch names use:
+// GitlabBranchIDIID or GitlabBranchIDGlobal.
+// Resolution order: per-repo > global > iid
+func (s *Settings) ResolveGitlabBranchID(repo *Repository) (string, error) {
+	value := repo.GitlabBranchID
+	if value == "" {
+		value = s.GitlabBranchID
+	}
+	switch normalized := strings.ToLower(strings.TrimSpace(value)); normalized {
+	case "", GitlabBranchIDIID:
+		return GitlabBranchIDIID, nil
+	case GitlabBranchIDGlobal:
+		return normalized, nil
+	}
+	return "", fmt.Errorf("invalid gitlab_branch_id %q: must be iid or id", value)
+}
+
+// ResolvePrimaryProvider returns the provider a mirrored repository uses
+// when --provider isn't given, or "" when none is configured.
+// Resolution order: per-repo > global
+func (s *Settings) ResolvePrimaryProvider(repo *Repository) (string, error) {
+	if repo.PrimaryProvider != "" {
+		return NormalizeProvider(repo.PrimaryProvider)
+	}
+	if s.PrimaryProvider != "" {
+		return NormalizeProvider(s.PrimaryProvider)
+	}
+	return "", nil
+}
+
 // IsCodeRepo returns true if the repository has a directory configured (i.e., it's a code repo)
 func (r *Repository) IsCodeRepo() bool {
 	return r.Directory != ""
@@ -148,3 +1162,41 @@ func (s *Settings) GetRepo(name string) *Repository {
 	}
 	return nil
 }
+
+// FindRepo returns the repository a name typed by the user refers to: the
+// exact name, else the only name query is a prefix of, else the only name
+// that fuzzily matches it (its letters in order, as in the interactive
+// repository filter). Everything but the exact name ignores case. A query
+// matching several repositories is an error listing them.
+func (s *Settings) FindRepo(query string) (*Repository, error) {
+	if repo := s.GetRepo(query); repo != nil {
+		return repo, nil
+	}
+
+	names := s.GetRepoNames()
+	var folded, prefixed []string
+	for _, name := range names {
+		if strings.EqualFold(name, query) {
+			folded = append(folded, name)
+		}
+		if strings.HasPrefix(strings.ToLower(name), strings.ToLower(query)) {
+			prefixed = append(prefixed, name)
+		}
+	}
+
+	candidates := folded
+	if len(candidates) == 0 {
+		candidates = prefixed
+	}
+	if len(candidates) == 0 && query != "" {
+		candidates = fuzzy.FindFold(query, names)
+	}
+
+	switch len(candidates) {
+	case 0:
+		return nil, fmt.Errorf("repository '%s' not found in config", query)
+	case 1:
+		return s.GetRepo(candidates[0]), nil
+	}
+	return nil, fmt.Errorf("repository '%s' is ambiguous - it matches %s", query, strings.Join(candidates, ", "))
+}
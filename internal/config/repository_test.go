@@ -1,6 +1,13 @@
 package config
 
-import "testing"
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
 
 func TestResolveWorktreePath(t *testing.T) {
 	tests := []struct {
@@ -85,3 +92,841 @@ func TestResolveDefaultBranch(t *testing.T) {
 		})
 	}
 }
+
+func TestResolveCloseKeyword(t *testing.T) {
+	tests := []struct {
+		name    string
+		global  string
+		perRepo string
+		want    string
+		wantErr bool
+	}{
+		{name: "default fallback", want: "Closes"},
+		{name: "global keyword set", global: "Fixes", want: "Fixes"},
+		{name: "per-repo overrides global", global: "Fixes", perRepo: "Resolves", want: "Resolves"},
+		{name: "case-insensitive", global: "fixes", want: "Fixes"},
+		{name: "invalid keyword", perRepo: "Implements", wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{CloseKeyword: tt.global}
+			repo := &Repository{CloseKeyword: tt.perRepo}
+			got, err := s.ResolveCloseKeyword(repo)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ResolveCloseKeyword() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("ResolveCloseKeyword() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveBranchTypeLabel(t *testing.T) {
+	s := &Settings{BranchTypeLabels: map[string]string{"bug": "bug", "feature": "enhancement"}}
+	repo := &Repository{BranchTypeLabels: map[string]string{"feature": "feature-request"}}
+
+	tests := []struct {
+		name       string
+		branchType string
+		want       string
+	}{
+		{name: "global mapping", branchType: "bug", want: "bug"},
+		{name: "per-repo overrides global", branchType: "feature", want: "feature-request"},
+		{name: "case-insensitive type", branchType: "BUG", want: "bug"},
+		{name: "unmapped type", branchType: "hotfix", want: ""},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := s.ResolveBranchTypeLabel(repo, tt.branchType)
+			if got != tt.want {
+				t.Errorf("ResolveBranchTypeLabel(%q) = %q, want %q", tt.branchType, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveWorktreeDir(t *testing.T) {
+	vars := WorktreePathVars{ID: "123", Slug: "add-feature", Branch: "123-add-feature", Repo: "myrepo"}
+
+	tests := []struct {
+		name    string
+		global  WorktreeConfig
+		perRepo WorktreeConfig
+		want    string
+		wantErr bool
+	}{
+		{
+			name: "default template uses branch name",
+			want: "/home/user/src/myrepo/.worktrees/123-add-feature",
+		},
+		{
+			name:   "global template with id",
+			global: WorktreeConfig{Path: "/tmp/worktrees", PathTemplate: "{id}"},
+			want:   "/tmp/worktrees/123",
+		},
+		{
+			name:    "per-repo template overrides global",
+			global:  WorktreeConfig{PathTemplate: "{id}"},
+			perRepo: WorktreeConfig{Path: "/tmp/worktrees", PathTemplate: "{repo}/{slug}"},
+			want:    "/tmp/worktrees/myrepo/add-feature",
+		},
+		{
+			name:    "template escaping the base is rejected",
+			perRepo: WorktreeConfig{PathTemplate: "../{branch}"},
+			wantErr: true,
+		},
+		{
+			name:    "template rendering to the base itself is rejected",
+			perRepo: WorktreeConfig{PathTemplate: "."},
+			wantErr: true,
+		},
+		{
+			name:    "absolute template is rejected",
+			perRepo: WorktreeConfig{PathTemplate: "/tmp/{branch}"},
+			wantErr: true,
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{Worktree: tt.global}
+			repo := &Repository{Directory: "/home/user/src/myrepo", Worktree: tt.perRepo}
+			got, err := s.ResolveWorktreeDir(repo, vars)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ResolveWorktreeDir() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("ResolveWorktreeDir() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveGithubToken(t *testing.T) {
+	dir := t.TempDir()
+	tokenFile := filepath.Join(dir, "token")
+	if err := os.WriteFile(tokenFile, []byte("  file-token\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	emptyFile := filepath.Join(dir, "empty")
+	if err := os.WriteFile(emptyFile, []byte("\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+
+	t.Setenv("GITHUB_TOKEN", "env-token")
+
+	tests := []struct {
+		name     string
+		settings Settings
+		want     string
+		wantErr  bool
+	}{
+		{name: "env var fallback", want: "env-token"},
+		{name: "token file overrides env var", settings: Settings{GithubTokenFile: tokenFile}, want: "file-token"},
+		{name: "explicit token overrides token file", settings: Settings{GithubToken: "explicit", GithubTokenFile: tokenFile}, want: "explicit"},
+		{name: "missing token file", settings: Settings{GithubTokenFile: filepath.Join(dir, "missing")}, wantErr: true},
+		{name: "empty token file", settings: Settings{GithubTokenFile: emptyFile}, wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := tt.settings.ResolveGithubToken()
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ResolveGithubToken() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("ResolveGithubToken() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestConfigPath(t *testing.T) {
+	home := t.TempDir()
+	t.Setenv("HOME", home)
+	t.Setenv("TIX_CONFIG", "")
+	defer SetConfigFile("")
+
+	got, err := ConfigPath()
+	if err != nil {
+		t.Fatalf("ConfigPath() error: %v", err)
+	}
+	if want := filepath.Join(home, ".tix.yml"); got != want {
+		t.Errorf("default ConfigPath() = %q, want %q", got, want)
+	}
+
+	yamlPath := filepath.Join(home, ".tix.yaml")
+	if err := os.WriteFile(yamlPath, []byte("repositories: []\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	if got, _ := ConfigPath(); got != yamlPath {
+		t.Errorf("ConfigPath() with ~/.tix.yaml present = %q, want %q", got, yamlPath)
+	}
+
+	t.Setenv("TIX_CONFIG", "~/configs/tix.yml")
+	if got, _ := ConfigPath(); got != filepath.Join(home, "configs", "tix.yml") {
+		t.Errorf("ConfigPath() with TIX_CONFIG = %q", got)
+	}
+
+	SetConfigFile("/etc/tix.yml")
+	if got, _ := ConfigPath(); got != "/etc/tix.yml" {
+		t.Errorf("ConfigPath() with --config = %q, want /etc/tix.yml", got)
+	}
+}
+
+func TestRedacted(t *testing.T) {
+	s := Settings{
+		GithubToken:     "ghp_secret",
+		GitlabTokenFile: "~/.gitlab-token",
+		Repositories:    []Repository{{Name: "app", NotifyWebhook: "https://hooks.slack.com/services/T0/B0/secret"}},
+	}
+	redacted := s.Redacted()
+
+	if redacted.GithubToken != redactedValue {
+		t.Errorf("GithubToken = %q, want it redacted", redacted.GithubToken)
+	}
+	if redacted.GitlabToken != "" {
+		t.Errorf("unset GitlabToken should stay empty, got %q", redacted.GitlabToken)
+	}
+	if redacted.GitlabTokenFile != "~/.gitlab-token" {
+		t.Errorf("token file paths should be kept, got %q", redacted.GitlabTokenFile)
+	}
+	if redacted.Repositories[0].NotifyWebhook != redactedValue {
+		t.Errorf("repository notify_webhook = %q, want it redacted", redacted.Repositories[0].NotifyWebhook)
+	}
+	if s.GithubToken != "ghp_secret" || s.Repositories[0].NotifyWebhook == redactedValue {
+		t.Error("Redacted() must not modify the original settings")
+	}
+}
+
+func TestResolveBranchTemplate(t *testing.T) {
+	tests := []struct {
+		name    string
+		global  string
+		perRepo string
+		want    string
+		wantErr bool
+	}{
+		{name: "default", want: DefaultBranchTemplate},
+		{name: "global", global: "{user}/{id}-{slug}", want: "{user}/{id}-{slug}"},
+		{name: "per-repo overrides global", global: "{user}/{id}-{slug}", perRepo: "{type}/{prefix}-{id}-{slug}", want: "{type}/{prefix}-{id}-{slug}"},
+		{name: "id not leading the last segment", global: "{user}-{id}-{slug}", wantErr: true},
+		{name: "no id", global: "{user}/{slug}", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{BranchTemplate: tt.global}
+			repo := &Repository{BranchTemplate: tt.perRepo}
+			got, err := s.ResolveBranchTemplate(repo)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("ResolveBranchTemplate() = %q, want error", got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("ResolveBranchTemplate() error: %v", err)
+			}
+			if got != tt.want {
+				t.Errorf("ResolveBranchTemplate() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveBranchIncludeSlug(t *testing.T) {
+	on, off := true, false
+	tests := []struct {
+		name    string
+		global  *bool
+		perRepo *bool
+		want    bool
+	}{
+		{name: "on by default", want: true},
+		{name: "global off", global: &off, want: false},
+		{name: "per-repo overrides global", global: &off, perRepo: &on, want: true},
+		{name: "per-repo off", perRepo: &off, want: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{BranchIncludeSlug: tt.global}
+			repo := &Repository{BranchIncludeSlug: tt.perRepo}
+			if got := s.ResolveBranchIncludeSlug(repo); got != tt.want {
+				t.Errorf("ResolveBranchIncludeSlug() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolvePromptLabels(t *testing.T) {
+	on, off := true, false
+	tests := []struct {
+		name    string
+		global  *bool
+		perRepo *bool
+		want    bool
+	}{
+		{name: "on by default", want: true},
+		{name: "global off", global: &off, want: false},
+		{name: "per-repo overrides global", global: &off, perRepo: &on, want: true},
+		{name: "per-repo off", perRepo: &off, want: false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{PromptLabels: tt.global}
+			repo := &Repository{PromptLabels: tt.perRepo}
+			if got := s.ResolvePromptLabels(repo); got != tt.want {
+				t.Errorf("ResolvePromptLabels() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveBranchMaxLength(t *testing.T) {
+	repo := &Repository{}
+	if got := (&Settings{}).ResolveBranchMaxLength(repo); got != DefaultBranchMaxLength {
+		t.Errorf("ResolveBranchMaxLength() = %d, want the default %d", got, DefaultBranchMaxLength)
+	}
+	cfg := &Settings{BranchMaxLength: 60}
+	if got := cfg.ResolveBranchMaxLength(repo); got != 60 {
+		t.Errorf("ResolveBranchMaxLength() = %d, want the global 60", got)
+	}
+	repo.BranchMaxLength = 40
+	if got := cfg.ResolveBranchMaxLength(repo); got != 40 {
+		t.Errorf("ResolveBranchMaxLength() = %d, want the per-repo 40", got)
+	}
+}
+
+func TestResolveWorktreeEnabled(t *testing.T) {
+	on, off := true, false
+	tests := []struct {
+		name    string
+		global  *bool
+		perRepo *bool
+		want    bool
+	}{
+		{name: "off by default", want: false},
+		{name: "global on", global: &on, want: true},
+		{name: "per-repo opts out of global", global: &on, perRepo: &off, want: false},
+		{name: "per-repo on", perRepo: &on, want: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{Worktree: WorktreeConfig{Enabled: tt.global}}
+			repo := &Repository{Worktree: WorktreeConfig{Enabled: tt.perRepo}}
+			if got := s.ResolveWorktreeEnabled(repo); got != tt.want {
+				t.Errorf("ResolveWorktreeEnabled() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveKeywordLabels(t *testing.T) {
+	s := &Settings{KeywordLabelMap: map[string]string{"bug": "bug", "Perf": "performance"}}
+	repo := &Repository{KeywordLabelMap: map[string]string{"perf": "speed", "docs": "documentation"}}
+
+	got := s.ResolveKeywordLabels(repo)
+	want := map[string]string{"bug": "bug", "perf": "speed", "docs": "documentation"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("ResolveKeywordLabels() = %v, want %v", got, want)
+	}
+}
+
+func TestResolveLabelPreset(t *testing.T) {
+	s := &Settings{Presets: map[string]LabelList{
+		"hotfix": {"bug", "priority::high", "hotfix"},
+		"docs":   {"documentation"},
+	}}
+	repo := &Repository{Presets: map[string]LabelList{"docs": {"docs", " docs", "website"}}}
+
+	got, err := s.ResolveLabelPreset(repo, "Hotfix")
+	if err != nil {
+		t.Fatalf("ResolveLabelPreset() error: %v", err)
+	}
+	if want := []string{"bug", "priority::high", "hotfix"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("ResolveLabelPreset(hotfix) = %v, want %v", got, want)
+	}
+
+	got, err = s.ResolveLabelPreset(repo, "docs")
+	if err != nil {
+		t.Fatalf("ResolveLabelPreset() error: %v", err)
+	}
+	if want := []string{"docs", "website"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("ResolveLabelPreset(docs) = %v, want the per-repo preset %v", got, want)
+	}
+
+	_, err = s.ResolveLabelPreset(repo, "release")
+	if err == nil || !strings.Contains(err.Error(), "available presets: docs, hotfix") {
+		t.Errorf("ResolveLabelPreset(release) error = %v, want the available presets listed", err)
+	}
+}
+
+func TestResolveAssignees(t *testing.T) {
+	s := &Settings{AssigneeRules: []AssigneeRule{{Label: "frontend", Assignees: []string{"alice"}}}}
+	repo := &Repository{AssigneeRules: []AssigneeRule{
+		{Label: "frontend", Assignees: []string{"bob", "carol"}},
+		{Path: "web/", Assignees: []string{"carol", "dave"}},
+		{Label: "bug", Path: "api", Assignees: []string{"erin"}},
+		{Assignees: []string{"nobody"}},
+	}}
+
+	tests := []struct {
+		name   string
+		repo   *Repository
+		labels []string
+		dir    string
+		want   []string
+	}{
+		{name: "global rule", repo: &Repository{}, labels: []string{"frontend"}, want: []string{"alice"}},
+		{name: "per-repo rules replace global", repo: repo, labels: []string{"Frontend"}, want: []string{"bob", "carol"}},
+		{name: "path rule", repo: repo, dir: "web/src", want: []string{"carol", "dave"}},
+		{name: "label and path rules combine", repo: repo, labels: []string{"frontend"}, dir: "web", want: []string{"bob", "carol", "dave"}},
+		{name: "path prefix isn't a directory match", repo: repo, dir: "website", want: nil},
+		{name: "rule with both needs both", repo: repo, labels: []string{"bug"}, dir: "web", want: []string{"carol", "dave"}},
+		{name: "both matched", repo: repo, labels: []string{"bug"}, dir: "api/v2", want: []string{"erin"}},
+		{name: "no match", repo: repo, labels: []string{"docs"}, want: nil},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := s.ResolveAssignees(tt.repo, tt.labels, tt.dir)
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ResolveAssignees(%v, %q) = %v, want %v", tt.labels, tt.dir, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveTrackUpstream(t *testing.T) {
+	tests := []struct {
+		name    string
+		global  bool
+		perRepo bool
+		want    bool
+	}{
+		{name: "off by default"},
+		{name: "global", global: true, want: true},
+		{name: "per-repo", perRepo: true, want: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{TrackUpstream: tt.global}
+			repo := &Repository{TrackUpstream: tt.perRepo}
+			if got := s.ResolveTrackUpstream(repo); got != tt.want {
+				t.Errorf("ResolveTrackUpstream() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestTitleTransform(t *testing.T) {
+	tests := []struct {
+		name      string
+		transform TitleTransform
+		title     string
+		want      string
+	}{
+		{name: "off by default", title: "  fix   login ", want: "  fix   login "},
+		{name: "trim", transform: TitleTransform{Trim: true}, title: "  fix   login \t", want: "fix   login"},
+		{name: "collapse whitespace", transform: TitleTransform{CollapseWhitespace: true}, title: "  fix \t login\n page ", want: "fix login page"},
+		{name: "sentence case", transform: TitleTransform{SentenceCase: true}, title: "fix login for SSO users", want: "Fix login for SSO users"},
+		{name: "sentence case after whitespace", transform: TitleTransform{SentenceCase: true}, title: "  élan broken", want: "  Élan broken"},
+		{name: "sentence case leaves punctuation", transform: TitleTransform{SentenceCase: true}, title: "[api] fix login", want: "[api] fix login"},
+		{name: "all", transform: TitleTransform{Trim: true, CollapseWhitespace: true, SentenceCase: true}, title: "  fix   login ", want: "Fix login"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.transform.Apply(tt.title); got != tt.want {
+				t.Errorf("Apply(%q) = %q, want %q", tt.title, got, tt.want)
+			}
+		})
+	}
+
+	cfg := &Settings{TitleTransform: TitleTransform{Trim: true}}
+	repo := &Repository{TitleTransform: TitleTransform{SentenceCase: true}}
+	if got, want := cfg.ResolveTitleTransform(repo), (TitleTransform{Trim: true, SentenceCase: true}); got != want {
+		t.Errorf("ResolveTitleTransform() = %+v, want %+v", got, want)
+	}
+}
+
+func TestResolveEstimateLabel(t *testing.T) {
+	tests := []struct {
+		name    string
+		global  string
+		perRepo string
+		want    string
+		wantErr bool
+	}{
+		{name: "default", want: "estimate/3"},
+		{name: "global", global: "size: {n}", want: "size: 3"},
+		{name: "per-repo overrides global", global: "size: {n}", perRepo: "points-{n}", want: "points-3"},
+		{name: "missing placeholder", global: "estimate", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			s := &Settings{EstimateLabelTemplate: tt.global}
+			repo := &Repository{EstimateLabelTemplate: tt.perRepo}
+			got, err := s.ResolveEstimateLabel(repo, 3)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("ResolveEstimateLabel() = %q, want error", got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("ResolveEstimateLabel() error: %v", err)
+			}
+			if got != tt.want {
+				t.Errorf("ResolveEstimateLabel() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestLoadMissingConfig(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tix.yml")
+	SetConfigFile(path)
+	defer SetConfigFile("")
+
+	_, err := Load()
+	if !errors.Is(err, ErrConfigNotFound) {
+		t.Fatalf("Load() error = %v, want ErrConfigNotFound", err)
+	}
+	if !strings.Contains(err.Error(), path) {
+		t.Errorf("Load() error %q doesn't name %s", err, path)
+	}
+}
+
+func TestLoadTokenOverridesEnvVar(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tix.yml")
+	if err := os.WriteFile(path, []byte("github_token: file-token\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	SetConfigFile(path)
+	defer SetConfigFile("")
+	t.Setenv("GITHUB_TOKEN", "env-token")
+	t.Setenv("GITLAB_TOKEN", "env-gitlab-token")
+
+	settings, err := Load()
+	if err != nil {
+		t.Fatalf("Load() error: %v", err)
+	}
+	if got, _ := settings.ResolveGithubToken(); got != "file-token" {
+		t.Errorf("ResolveGithubToken() = %q, want github_token from the file over GITHUB_TOKEN", got)
+	}
+	if got, _ := settings.ResolveGitlabToken(); got != "env-gitlab-token" {
+		t.Errorf("ResolveGitlabToken() = %q, want the GITLAB_TOKEN fallback", got)
+	}
+}
+
+func TestLoadTypeErrors(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tix.yml")
+	content := "track_upstream: maybe\nrepositories:\n  - name: app\n    github_repo: owner/app\n    worktree:\n      enabled: \"yes\"\n"
+	if err := os.WriteFile(path, []byte(content), 0600); err != nil {
+		t.Fatal(err)
+	}
+	SetConfigFile(path)
+	defer SetConfigFile("")
+
+	_, err := Load()
+	var decodeErr *DecodeError
+	if !errors.As(err, &decodeErr) {
+		t.Fatalf("Load() error = %v, want a DecodeError", err)
+	}
+	for _, want := range []string{
+		"invalid config in " + path,
+		`repository "app" (repositories[0]) worktree.enabled: expected true or false, got "yes"`,
+		`track_upstream: expected true or false, got "maybe"`,
+	} {
+		if !strings.Contains(err.Error(), want) {
+			t.Errorf("Load() error = %q, missing %q", err, want)
+		}
+	}
+}
+
+func TestLoadDefaultLabels(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "tix.yml")
+	content := `repositories:
+  - name: string-form
+    github_repo: owner/a
+    default_labels: "bug, triage,,Bug"
+  - name: list-form
+    github_repo: owner/b
+    default_labels: [bug, " triage "]
+  - name: none
+    github_repo: owner/c
+`
+	if err := os.WriteFile(path, []byte(content), 0600); err != nil {
+		t.Fatal(err)
+	}
+	SetConfigFile(path)
+	defer SetConfigFile("")
+
+	settings, err := Load()
+	if err != nil {
+		t.Fatalf("Load() error: %v", err)
+	}
+
+	want := []string{"bug", "triage"}
+	for _, name := range []string{"string-form", "list-form"} {
+		if got := settings.GetRepo(name).DefaultLabelList(); !reflect.DeepEqual(got, want) {
+			t.Errorf("%s DefaultLabelList() = %v, want %v", name, got, want)
+		}
+	}
+	if got := settings.GetRepo("none").DefaultLabelList(); len(got) != 0 {
+		t.Errorf("DefaultLabelList() without default_labels = %v, want empty", got)
+	}
+}
+
+func TestFindRepo(t *testing.T) {
+	settings := &Settings{Repositories: []Repository{
+		{Name: "backend"}, {Name: "backend-admin"}, {Name: "website"}, {Name: "mobile-app"}, {Name: "Docs"},
+	}}
+
+	tests := []struct {
+		query   string
+		want    string
+		wantErr string
+	}{
+		{query: "backend", want: "backend"},
+		{query: "web", want: "website"},
+		{query: "docs", want: "Docs"},
+		{query: "mapp", want: "mobile-app"},
+		{query: "back", wantErr: "ambiguous - it matches backend, backend-admin"},
+		{query: "be", wantErr: "ambiguous"},
+		{query: "zzz", wantErr: "repository 'zzz' not found in config"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.query, func(t *testing.T) {
+			got, err := settings.FindRepo(tt.query)
+			if tt.wantErr != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.wantErr) {
+					t.Errorf("FindRepo(%q) error = %v, want %q", tt.query, err, tt.wantErr)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("FindRepo(%q) error: %v", tt.query, err)
+			}
+			if got.Name != tt.want {
+				t.Errorf("FindRepo(%q) = %q, want %q", tt.query, got.Name, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolvePrimaryProvider(t *testing.T) {
+	tests := []struct {
+		name    string
+		global  string
+		repo    string
+		want    string
+		wantErr bool
+	}{
+		{name: "unset", want: ""},
+		{name: "global", global: "gitlab", want: "gitlab"},
+		{name: "per-repo overrides global", global: "gitlab", repo: "GitHub", want: "github"},
+		{name: "invalid", repo: "bitbucket", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			settings := &Settings{PrimaryProvider: tt.global}
+			got, err := settings.ResolvePrimaryProvider(&Repository{PrimaryProvider: tt.repo})
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ResolvePrimaryProvider() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("ResolvePrimaryProvider() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestResolveRotation(t *testing.T) {
+	settings := &Settings{Rotations: map[string]Rotation{
+		"OnCall": {Members: []string{"alice", " bob ", ""}},
+		"triage": {Members: []string{"carol"}, Strategy: "Random"},
+		"broken": {Members: []string{"dave"}, Strategy: "weighted"},
+	}}
+	repo := &Repository{Name: "App", Rotations: map[string]Rotation{"triage": {Members: []string{"erin", "frank"}}}}
+
+	key, rotation, err := settings.ResolveRotation(repo, "oncall")
+	if err != nil {
+		t.Fatalf("ResolveRotation(oncall) error: %v", err)
+	}
+	if key != "oncall" || rotation.Strategy != RotationRoundRobin || len(rotation.Members) != 2 || rotation.Members[1] != "bob" {
+		t.Errorf("ResolveRotation(oncall) = %q, %+v, want round_robin between alice and bob", key, rotation)
+	}
+
+	// Per-repo rotations replace global ones of the same name, and keep
+	// their own turns
+	if key, rotation, err := settings.ResolveRotation(repo, "triage"); err != nil || key != "app/triage" || rotation.Members[0] != "erin" || rotation.Strategy != RotationRoundRobin {
+		t.Errorf("ResolveRotation(triage) = %q, %+v, %v, want the repository's rotation under app/triage", key, rotation, err)
+	}
+
+	for name, wantErr := range map[string]string{
+		"broken":  "must be round_robin or random",
+		"missing": "available rotations: broken, oncall, triage",
+	} {
+		if _, _, err := settings.ResolveRotation(repo, name); err == nil || !strings.Contains(err.Error(), wantErr) {
+			t.Errorf("ResolveRotation(%s) error = %v, want %q", name, err, wantErr)
+		}
+	}
+}
+
+func TestResolveLabelColor(t *testing.T) {
+	settings := &Settings{LabelColorMap: map[string]string{
+		"priority::*":      "ff0000",
+		"priority::h*":     "#AA0000",
+		"*-team":           "#0000ff",
+		"area/*/frontend*": "#00ff00",
+		"broken":           "red",
+	}}
+	repo := &Repository{LabelColorMap: map[string]string{"priority::high": "#ffaa00"}}
+
+	tests := []struct {
+		label   string
+		want    string
+		wantErr bool
+	}{
+		{label: "priority::high", want: "#ffaa00"},
+		{label: "Priority::Hold", want: "#aa0000"},
+		{label: "priority::low", want: "#ff0000"},
+		{label: "web-team", want: "#0000ff"},
+		{label: "area/shop/frontend-cart", want: "#00ff00"},
+		{label: "docs", want: DefaultLabelColor},
+		{label: "broken", wantErr: true},
+	}
+	for _, tt := range tests {
+		t.Run(tt.label, func(t *testing.T) {
+			got, err := settings.ResolveLabelColor(repo, tt.label)
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("ResolveLabelColor() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if got != tt.want {
+				t.Errorf("ResolveLabelColor() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+
+	if got, err := (&Settings{}).ResolveLabelColor(&Repository{}, "docs"); err != nil || got != "" {
+		t.Errorf("ResolveLabelColor() without a map = %q, %v, want empty", got, err)
+	}
+}
+
+func TestResolveLabelAliases(t *testing.T) {
+	cfg := &Settings{LabelAliases: map[string]map[string]string{
+		"github": {"bug": "type: bug", "docs": "documentation"},
+		"gitlab": {"bug": "type::bug"},
+	}}
+	repo := &Repository{LabelAliases: map[string]map[string]string{
+		"GitHub": {"Docs": "area: docs"},
+	}}
+
+	github := cfg.ResolveLabelAliases(repo, ProviderGitHub)
+	if want := map[string]string{"bug": "type: bug", "docs": "area: docs"}; !reflect.DeepEqual(github, want) {
+		t.Errorf("GitHub aliases = %v, want %v", github, want)
+	}
+	gitlab := cfg.ResolveLabelAliases(repo, ProviderGitLab)
+	if want := map[string]string{"bug": "type::bug"}; !reflect.DeepEqual(gitlab, want) {
+		t.Errorf("GitLab aliases = %v, want %v", gitlab, want)
+	}
+
+	got := AliasLabels([]string{"Bug", "ui", "docs"}, github)
+	if want := []string{"type: bug", "ui", "area: docs"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("AliasLabels() = %q, want %q", got, want)
+	}
+}
+
+func TestNormalizeLabels(t *testing.T) {
+	tests := []struct {
+		name  string
+		input []string
+		want  []string
+	}{
+		{
+			name:  "trims and keeps order",
+			input: []string{" bug ", "enhancement"},
+			want:  []string{"bug", "enhancement"},
+		},
+		{
+			name:  "drops empty entries",
+			input: []string{"", "bug", "  "},
+			want:  []string{"bug"},
+		},
+		{
+			name:  "removes duplicates case-insensitively",
+			input: []string{"Bug", "bug", "feature", "BUG"},
+			want:  []string{"Bug", "feature"},
+		},
+		{
+			name:  "nil input",
+			input: nil,
+			want:  []string{},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := NormalizeLabels(tt.input)
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("NormalizeLabels() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestScopedLabelConflicts(t *testing.T) {
+	tests := []struct {
+		name  string
+		input []string
+		want  [][]string
+	}{
+		{
+			name:  "distinct scopes",
+			input: []string{"priority::high", "type::bug", "backend"},
+			want:  nil,
+		},
+		{
+			name:  "two values of one scope",
+			input: []string{"priority::high", "bug", "Priority::low"},
+			want:  [][]string{{"priority::high", "Priority::low"}},
+		},
+		{
+			name:  "plain label named after a scope",
+			input: []string{"priority", "priority::high"},
+			want:  [][]string{{"priority", "priority::high"}},
+		},
+		{
+			name:  "nested scopes use the last separator",
+			input: []string{"team::api::owner", "team::web", "team::api::reviewer"},
+			want:  [][]string{{"team::api::owner", "team::api::reviewer"}},
+		},
+		{
+			name:  "duplicates aren't conflicts",
+			input: []string{"priority::high", "Priority::High"},
+			want:  nil,
+		},
+		{
+			name:  "separator at either end isn't a scope",
+			input: []string{"::high", "priority::", "priority"},
+			want:  nil,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := ScopedLabelConflicts(tt.input)
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ScopedLabelConflicts() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,31 @@
+package git
+
+// Backend is the set of git operations `tix create` and `tix start` use, so
+// their branch logic can be tested against a mock instead of a real
+// repository. *Repository is the real implementation.
+type Backend interface {
+	IsClean() (bool, error)
+	Stash() error
+	StashPop() error
+	CreateBranch(name string) error
+	CreateBranchFrom(name, base string) error
+	CheckoutBranch(name string) error
+	SetUpstream(branch, remote, base string) (string, error)
+	FastForwardBranch(branch string) (FastForwardResult, error)
+	LocalBranches() ([]string, error)
+	BaseBranchCandidates() ([]string, error)
+	BranchExists(name string) bool
+	RevisionExists(rev string) bool
+	HeadCommit() (string, error)
+	ResolveCommit(rev string) (string, error)
+	ConfigValue(key string) (string, error)
+	SetConfigValue(key, value string) error
+	AddWorktree(worktreePath, branchName, baseBranch string) error
+	AddWorktreeForBranch(worktreePath, branchName string) error
+	WorktreePathForBranch(branchName string) (string, error)
+	WorktreeRegistered(path string) (registered, prunable bool, err error)
+	PruneWorktrees() error
+	DirMatchesRevision(dir, rev string) (bool, error)
+}
+
+var _ Backend = (*Repository)(nil)
@@ -3,7 +3,9 @@
 package git
 
 import (
+	"errors"
 	"fmt"
+	"net/url"
 	"os/exec"
 	"strings"
 
@@ -18,16 +20,38 @@ type Repository struct {
 	path string
 }
 
-// Open opens a Git repository at the given path
+// Open opens the Git repository at the given path. A path inside a
+// repository rather than at its root (e.g. a configured directory pointing at
+// a subdirectory) finds the enclosing repository, whose root git commands
+// then run from.
 func Open(path string) (*Repository, error) {
 	repo, err := git.PlainOpen(path)
+	if err == nil {
+		return &Repository{
+			Repository: repo,
+			path:       path,
+		}, nil
+	}
+	if !errors.Is(err, git.ErrRepositoryNotExists) {
+		return nil, fmt.Errorf("failed to open repository: %w", err)
+	}
+
+	repo, err = git.PlainOpenWithOptions(path, &git.PlainOpenOptions{DetectDotGit: true})
 	if err != nil {
 		return nil, fmt.Errorf("failed to open repository: %w", err)
 	}
+	root := path
+	if worktree, err := repo.Worktree(); err == nil {
+		root = worktree.Filesystem.Root()
+	}
+	logger.Debug("Found repository above the given directory", map[string]interface{}{
+		"directory": path,
+		"root":      root,
+	})
 
 	return &Repository{
 		Repository: repo,
-		path:       path,
+		path:       root,
 	}, nil
 }
 
@@ -69,6 +93,163 @@ func (r *Repository) CreateBranch(name string) error {
 	return nil
 }
 
+// CreateBranchFrom creates a new branch pointing at base, which may be any
+// revision git understands (branch, tag, commit)
+func (r *Repository) CreateBranchFrom(name, base string) error {
+	hash, err := r.ResolveRevision(plumbing.Revision(base))
+	if err != nil {
+		return fmt.Errorf("failed to resolve base branch %s: %w", base, err)
+	}
+
+	ref := plumbing.NewBranchReferenceName(name)
+	err = r.Storer.SetReference(plumbing.NewHashReference(ref, *hash))
+	if err != nil {
+		return fmt.Errorf("failed to create branch: %w", err)
+	}
+
+	return nil
+}
+
+// SetUpstream makes branch track the remote-tracking branch for base, so
+// `git pull` works without arguments. base may be a local branch name ("main")
+// or already include the remote ("origin/main"). It returns the upstream set.
+// Runs: git branch --set-upstream-to=<remote>/<base> <branch>
+func (r *Repository) SetUpstream(branch, remote, base string) (string, error) {
+	name := strings.TrimPrefix(base, remote+"/")
+	upstream := remote + "/" + name
+
+	_, err := r.Reference(plumbing.NewRemoteReferenceName(remote, name), false)
+	if err == plumbing.ErrReferenceNotFound {
+		return "", fmt.Errorf("remote branch %s not found - fetch it or push %s first", upstream, name)
+	}
+	if err != nil {
+		return "", fmt.Errorf("failed to look up %s: %w", upstream, err)
+	}
+
+	cmd := exec.Command("git", "branch", "--set-upstream-to="+upstream, branch)
+	cmd.Dir = r.path
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return "", fmt.Errorf("failed to set upstream: %w (output: %s)", err, strings.TrimSpace(string(output)))
+	}
+	return upstream, nil
+}
+
+// LocalBranches returns the names of all local branches
+func (r *Repository) LocalBranches() ([]string, error) {
+	iter, err := r.Branches()
+	if err != nil {
+		return nil, fmt.Errorf("failed to list branches: %w", err)
+	}
+	defer iter.Close()
+
+	var branches []string
+	err = iter.ForEach(func(ref *plumbing.Reference) error {
+		branches = append(branches, ref.Name().Short())
+		return nil
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to list branches: %w", err)
+	}
+	return branches, nil
+}
+
+// BranchExists reports whether a local branch with the given name exists
+func (r *Repository) BranchExists(name string) bool {
+	_, err := r.Reference(plumbing.NewBranchReferenceName(name), false)
+	return err == nil
+}
+
+// ValidateBranchName checks that name can be used as a branch name as given,
+// following git's rules for ref names.
+// Runs: git check-ref-format refs/heads/<name>
+func ValidateBranchName(name string) error {
+	if name == "" || name == "HEAD" || strings.HasPrefix(name, "-") {
+		return fmt.Errorf("%q is not a valid branch name", name)
+	}
+	if err := exec.Command("git", "check-ref-format", "refs/heads/"+name).Run(); err != nil {
+		return fmt.Errorf("%q is not a valid branch name", name)
+	}
+	return nil
+}
+
+// Version returns the installed git's version, e.g. "2.39.2".
+// Runs: git --version
+func Version() (string, error) {
+	out, err := exec.Command("git", "--version").Output()
+	if err != nil {
+		return "", fmt.Errorf("failed to run git --version: %w", err)
+	}
+	return strings.TrimPrefix(strings.TrimSpace(string(out)), "git version "), nil
+}
+
+// RevisionExists reports whether rev (e.g. "main" or "origin/main") resolves
+// to a commit in the repository
+func (r *Repository) RevisionExists(rev string) bool {
+	_, err := r.ResolveRevision(plumbing.Revision(rev))
+	return err == nil
+}
+
+// HasCommits reports whether any branch or ref in the repository points at a
+// commit, which a freshly initialized repository doesn't.
+// Runs: git rev-list -n 1 --all
+func (r *Repository) HasCommits() (bool, error) {
+	cmd := exec.Command("git", "rev-list", "-n", "1", "--all")
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		return false, fmt.Errorf("failed to list commits in %s: %w", r.path, err)
+	}
+	return strings.TrimSpace(string(output)) != "", nil
+}
+
+// HeadCommit returns the hash of the commit checked out in the repository's
+// directory, whether HEAD is on a branch or detached.
+// Runs: git rev-parse --verify HEAD^{commit}
+func (r *Repository) HeadCommit() (string, error) {
+	cmd := exec.Command("git", "rev-parse", "--verify", "--quiet", "HEAD^{commit}")
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("couldn't resolve HEAD in %s - does the repository have any commits?", r.path)
+	}
+	return strings.TrimSpace(string(output)), nil
+}
+
+// ResolveCommit returns the hash of the commit rev points at, e.g. the tip
+// of a branch.
+// Runs: git rev-parse --verify rev^{commit}
+func (r *Repository) ResolveCommit(rev string) (string, error) {
+	cmd := exec.Command("git", "rev-parse", "--verify", "--quiet", rev+"^{commit}")
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("couldn't resolve %s to a commit in %s", rev, r.path)
+	}
+	return strings.TrimSpace(string(output)), nil
+}
+
+// baseBranchNames are the conventional default branch names, in order of preference
+var baseBranchNames = []string{"main", "master"}
+
+// BaseBranchCandidates returns which of the conventional default branch names
+// (main, master) exist locally. More than one result means the default branch
+// is ambiguous, e.g. during a master -> main transition.
+func (r *Repository) BaseBranchCandidates() ([]string, error) {
+	var candidates []string
+	for _, name := range baseBranchNames {
+		_, err := r.Reference(plumbing.NewBranchReferenceName(name), false)
+		if err == plumbing.ErrReferenceNotFound {
+			continue
+		}
+		if err != nil {
+			return nil, fmt.Errorf("failed to look up branch %s: %w", name, err)
+		}
+		candidates = append(candidates, name)
+	}
+	return candidates, nil
+}
+
 // CheckoutBranch checks out the specified branch
 func (r *Repository) CheckoutBranch(name string) error {
 	wt, err := r.Worktree()
@@ -105,6 +286,70 @@ func GetBranchFromDir(dir string) (string, error) {
 	return branch, nil
 }
 
+// CommonDir returns the absolute path of the git directory shared by the
+// repository containing dir and all its worktrees.
+// Runs: git rev-parse --path-format=absolute --git-common-dir
+func CommonDir(dir string) (string, error) {
+	cmd := exec.Command("git", "rev-parse", "--path-format=absolute", "--git-common-dir")
+	cmd.Dir = dir
+	output, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("%s is not in a git repository", dir)
+	}
+	return resolvePath(strings.TrimSpace(string(output))), nil
+}
+
+// HooksDir returns the directory git runs the repository's hooks from,
+// following core.hooksPath. Worktrees share the main repository's hooks.
+// Runs: git rev-parse --path-format=absolute --git-path hooks
+func (r *Repository) HooksDir() (string, error) {
+	cmd := exec.Command("git", "rev-parse", "--path-format=absolute", "--git-path", "hooks")
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		return "", fmt.Errorf("failed to find the hooks directory of %s: %w", r.path, err)
+	}
+	return strings.TrimSpace(string(output)), nil
+}
+
+// RemoteURL returns the first URL configured for remoteName
+func (r *Repository) RemoteURL(remoteName string) (string, error) {
+	remote, err := r.Remote(remoteName)
+	if err != nil {
+		return "", fmt.Errorf("failed to get remote %s: %w", remoteName, err)
+	}
+	urls := remote.Config().URLs
+	if len(urls) == 0 {
+		return "", fmt.Errorf("no URLs found for remote %s", remoteName)
+	}
+	return urls[0], nil
+}
+
+// RemoteProjectPath returns the project path a remote URL points at, e.g.
+// acme/app for git@github.com:acme/app.git or group/sub/app for
+// https://gitlab.com/group/sub/app. Both URLs with a scheme and scp-like
+// user@host:path URLs are understood.
+func RemoteProjectPath(remoteURL string) (string, error) {
+	path := remoteURL
+	if i := strings.Index(remoteURL, "://"); i >= 0 {
+		u, err := url.Parse(remoteURL)
+		if err != nil {
+			return "", fmt.Errorf("invalid remote URL %q: %w", remoteURL, err)
+		}
+		path = u.Path
+	} else if i := strings.Index(remoteURL, ":"); i > 0 && !strings.Contains(remoteURL[:i], "/") {
+		path = remoteURL[i+1:]
+	} else {
+		return "", fmt.Errorf("remote URL %q is not a GitHub or GitLab URL", remoteURL)
+	}
+
+	path = strings.TrimSuffix(strings.Trim(path, "/"), ".git")
+	if !strings.Contains(path, "/") {
+		return "", fmt.Errorf("remote URL %q has no owner/project path", remoteURL)
+	}
+	return path, nil
+}
+
 // Push pushes the current branch to the remote repository
 func (r *Repository) Push(remoteName string, branchName string) error {
 	// Get remote details
@@ -155,6 +400,47 @@ func (r *Repository) DeleteBranch(name string) error {
 	return nil
 }
 
+// RenameBranch renames a local branch. Worktrees that have the branch checked
+// out are updated to the new name.
+// Runs: git branch -m <oldName> <newName>
+func (r *Repository) RenameBranch(oldName, newName string) error {
+	cmd := exec.Command("git", "branch", "-m", oldName, newName)
+	cmd.Dir = r.path
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to rename branch: %w (output: %s)", err, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
+// ConfigValue returns a git config value (e.g. "user.email") as seen from the
+// repository, including global and system config. It returns "" if unset.
+func (r *Repository) ConfigValue(key string) (string, error) {
+	cmd := exec.Command("git", "config", "--get", key)
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		// Exit status 1 means the key isn't set
+		if exitErr, ok := err.(*exec.ExitError); ok && exitErr.ExitCode() == 1 {
+			return "", nil
+		}
+		return "", fmt.Errorf("failed to read git config %s: %w", key, err)
+	}
+	return strings.TrimSpace(string(output)), nil
+}
+
+// SetConfigValue sets a git config value in the repository's own config
+// Runs: git config <key> <value>
+func (r *Repository) SetConfigValue(key, value string) error {
+	cmd := exec.Command("git", "config", key, value)
+	cmd.Dir = r.path
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to set git config %s: %w (output: %s)", key, err, strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
 // Stash saves all working directory changes (including untracked files) to the stash
 func (r *Repository) Stash() error {
 	cmd := exec.Command("git", "stash", "-u")
@@ -182,3 +468,81 @@ func (r *Repository) StashPop() error {
 	})
 	return nil
 }
+
+// FastForwardResult reports what FastForwardBranch found and did
+type FastForwardResult struct {
+	Upstream string // e.g. "origin/main"
+	Ahead    int    // Local commits the upstream doesn't have
+	Behind   int    // Upstream commits the local branch doesn't have
+	Updated  bool   // The branch was fast-forwarded
+}
+
+// FastForwardBranch fetches just branch's upstream and fast-forwards branch to
+// it when it is strictly behind; nothing is merged. A branch with commits the
+// upstream lacks is left as it is (Ahead > 0). If the branch is checked out in
+// a worktree it is updated there with --ff-only so its files follow;
+// otherwise only the ref moves.
+func (r *Repository) FastForwardBranch(branch string) (FastForwardResult, error) {
+	var result FastForwardResult
+
+	remote, err := r.ConfigValue("branch." + branch + ".remote")
+	if err != nil {
+		return result, err
+	}
+	merge, err := r.ConfigValue("branch." + branch + ".merge")
+	if err != nil {
+		return result, err
+	}
+	if remote == "" || merge == "" {
+		return result, fmt.Errorf("%s has no upstream branch - set one with 'git branch --set-upstream-to'", branch)
+	}
+
+	name := strings.TrimPrefix(merge, "refs/heads/")
+	upstreamRef := "refs/heads/" + name
+	result.Upstream = name
+	if remote != "." {
+		upstreamRef = "refs/remotes/" + remote + "/" + name
+		result.Upstream = remote + "/" + name
+
+		cmd := exec.Command("git", "fetch", "--quiet", remote, name)
+		cmd.Dir = r.path
+		if output, err := cmd.CombinedOutput(); err != nil {
+			return result, fmt.Errorf("failed to fetch %s: %w (output: %s)", result.Upstream, err, strings.TrimSpace(string(output)))
+		}
+	}
+
+	cmd := exec.Command("git", "rev-list", "--left-right", "--count", "refs/heads/"+branch+"..."+upstreamRef)
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		return result, fmt.Errorf("failed to compare %s with %s: %w", branch, result.Upstream, err)
+	}
+	if _, err := fmt.Sscan(string(output), &result.Ahead, &result.Behind); err != nil {
+		return result, fmt.Errorf("failed to compare %s with %s: unexpected output %q", branch, result.Upstream, strings.TrimSpace(string(output)))
+	}
+	if result.Ahead > 0 || result.Behind == 0 {
+		return result, nil
+	}
+
+	worktreeDir, err := r.WorktreePathForBranch(branch)
+	if err != nil {
+		return result, err
+	}
+	if worktreeDir != "" {
+		cmd = exec.Command("git", "merge", "--ff-only", "--quiet", upstreamRef)
+		cmd.Dir = worktreeDir
+	} else {
+		old, err := r.ResolveRevision(plumbing.Revision("refs/heads/" + branch))
+		if err != nil {
+			return result, fmt.Errorf("failed to resolve %s: %w", branch, err)
+		}
+		// Passing the old value makes the update fail if the branch moved meanwhile
+		cmd = exec.Command("git", "update-ref", "refs/heads/"+branch, upstreamRef, old.String())
+		cmd.Dir = r.path
+	}
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return result, fmt.Errorf("failed to fast-forward %s: %w (output: %s)", branch, err, strings.TrimSpace(string(output)))
+	}
+	result.Updated = true
+	return result, nil
+}
@@ -4,6 +4,7 @@ import (
 	"os"
 	"os/exec"
 	"path/filepath"
+	"strings"
 	"testing"
 )
 
@@ -101,3 +102,363 @@ func TestStashPopWithNoStash(t *testing.T) {
 		t.Fatal("expected StashPop() to return error when stash is empty")
 	}
 }
+
+func TestBaseBranchCandidates(t *testing.T) {
+	repo := newTestRepo(t)
+
+	run := func(args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", args...)
+		cmd.Dir = repo.path
+		if out, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+	}
+	// Normalize the initial branch name regardless of init.defaultBranch
+	run("branch", "-M", "main")
+
+	candidates, err := repo.BaseBranchCandidates()
+	if err != nil {
+		t.Fatalf("BaseBranchCandidates() error: %v", err)
+	}
+	if len(candidates) != 1 || candidates[0] != "main" {
+		t.Errorf("BaseBranchCandidates() = %v, want [main]", candidates)
+	}
+
+	run("branch", "master")
+	candidates, err = repo.BaseBranchCandidates()
+	if err != nil {
+		t.Fatalf("BaseBranchCandidates() error: %v", err)
+	}
+	if len(candidates) != 2 {
+		t.Errorf("BaseBranchCandidates() = %v, want [main master]", candidates)
+	}
+}
+
+func TestOpenFindsEnclosingRepository(t *testing.T) {
+	repo := newTestRepo(t)
+
+	subdir := filepath.Join(repo.path, "services", "api")
+	if err := os.MkdirAll(subdir, 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	found, err := Open(subdir)
+	if err != nil {
+		t.Fatalf("Open(subdir) error: %v", err)
+	}
+	want, _ := filepath.EvalSymlinks(repo.path)
+	got, _ := filepath.EvalSymlinks(found.path)
+	if got != want {
+		t.Errorf("Open(subdir) root = %q, want %q", got, want)
+	}
+
+	if _, err := Open(t.TempDir()); err == nil {
+		t.Error("expected an error for a directory outside any repository")
+	}
+}
+
+func TestRevisionExists(t *testing.T) {
+	repo := newTestRepo(t)
+
+	cmd := exec.Command("git", "branch", "-M", "main")
+	cmd.Dir = repo.path
+	if out, err := cmd.CombinedOutput(); err != nil {
+		t.Fatalf("git branch -M main: %v\n%s", err, out)
+	}
+
+	if !repo.RevisionExists("main") {
+		t.Error("expected main to exist")
+	}
+	if repo.RevisionExists("master") {
+		t.Error("expected master not to exist")
+	}
+}
+
+func TestHeadCommit(t *testing.T) {
+	repo := newTestRepo(t)
+
+	cmd := exec.Command("git", "rev-parse", "HEAD")
+	cmd.Dir = repo.path
+	out, err := cmd.Output()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	got, err := repo.HeadCommit()
+	if err != nil {
+		t.Fatalf("HeadCommit() error: %v", err)
+	}
+	if want := strings.TrimSpace(string(out)); got != want {
+		t.Errorf("HeadCommit() = %q, want %q", got, want)
+	}
+
+	empty := t.TempDir()
+	if out, err := exec.Command("git", "init", empty).CombinedOutput(); err != nil {
+		t.Fatalf("git init: %v\n%s", err, out)
+	}
+	emptyRepo, err := Open(empty)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, err := emptyRepo.HeadCommit(); err == nil {
+		t.Error("expected an error for a repository without commits")
+	}
+}
+
+func TestResolveCommit(t *testing.T) {
+	repo := newTestRepo(t)
+
+	head, err := repo.HeadCommit()
+	if err != nil {
+		t.Fatal(err)
+	}
+	cmd := exec.Command("git", "branch", "feature")
+	cmd.Dir = repo.path
+	if out, err := cmd.CombinedOutput(); err != nil {
+		t.Fatalf("git branch: %v\n%s", err, out)
+	}
+
+	got, err := repo.ResolveCommit("feature")
+	if err != nil {
+		t.Fatalf("ResolveCommit() error: %v", err)
+	}
+	if got != head {
+		t.Errorf("ResolveCommit() = %q, want %q", got, head)
+	}
+	if _, err := repo.ResolveCommit("no-such-branch"); err == nil {
+		t.Error("expected an error for a missing branch")
+	}
+}
+
+func TestRemoteProjectPath(t *testing.T) {
+	tests := []struct {
+		url     string
+		want    string
+		wantErr bool
+	}{
+		{url: "git@github.com:acme/app.git", want: "acme/app"},
+		{url: "https://gitlab.com/group/sub/app.git", want: "group/sub/app"},
+		{url: "https://gitlab.example.com/group/app/", want: "group/app"},
+		{url: "ssh://git@gitlab.example.com:2222/group/app.git", want: "group/app"},
+		{url: "/srv/git/app.git", wantErr: true},
+		{url: "https://github.com/acme", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.url, func(t *testing.T) {
+			got, err := RemoteProjectPath(tt.url)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("RemoteProjectPath() = %q, want an error", got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("RemoteProjectPath() error: %v", err)
+			}
+			if got != tt.want {
+				t.Errorf("RemoteProjectPath() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestRemoteURL(t *testing.T) {
+	repo := newTestRepo(t)
+
+	cmd := exec.Command("git", "remote", "add", "upstream", "git@gitlab.com:acme/app.git")
+	cmd.Dir = repo.path
+	if out, err := cmd.CombinedOutput(); err != nil {
+		t.Fatalf("git remote add: %v\n%s", err, out)
+	}
+
+	got, err := repo.RemoteURL("upstream")
+	if err != nil {
+		t.Fatalf("RemoteURL() error: %v", err)
+	}
+	if got != "git@gitlab.com:acme/app.git" {
+		t.Errorf("RemoteURL() = %q, want the configured URL", got)
+	}
+	if _, err := repo.RemoteURL("missing"); err == nil {
+		t.Error("expected an error for a missing remote")
+	}
+}
+
+func TestValidateBranchName(t *testing.T) {
+	for _, name := range []string{"spike", "experiments/cache-warmup", "tk/fix_1.2"} {
+		if err := ValidateBranchName(name); err != nil {
+			t.Errorf("ValidateBranchName(%q) error: %v", name, err)
+		}
+	}
+	for _, name := range []string{"", "HEAD", "-x", "a..b", "has space", "ends.lock", "trailing/"} {
+		if err := ValidateBranchName(name); err == nil {
+			t.Errorf("ValidateBranchName(%q) expected error", name)
+		}
+	}
+}
+
+func TestCreateBranchFrom(t *testing.T) {
+	repo := newTestRepo(t)
+
+	if err := repo.CreateBranchFrom("feature", "HEAD"); err != nil {
+		t.Fatalf("CreateBranchFrom() error: %v", err)
+	}
+	branches, err := repo.LocalBranches()
+	if err != nil {
+		t.Fatalf("LocalBranches() error: %v", err)
+	}
+	found := false
+	for _, b := range branches {
+		if b == "feature" {
+			found = true
+		}
+	}
+	if !found {
+		t.Errorf("expected feature branch in %v", branches)
+	}
+
+	if err := repo.CreateBranchFrom("other", "does-not-exist"); err == nil {
+		t.Error("expected error for unknown base")
+	}
+}
+
+func TestConfigValue(t *testing.T) {
+	repo := newTestRepo(t)
+
+	got, err := repo.ConfigValue("user.email")
+	if err != nil {
+		t.Fatalf("ConfigValue() error: %v", err)
+	}
+	if got != "test@test.com" {
+		t.Errorf("ConfigValue(user.email) = %q, want test@test.com", got)
+	}
+
+	got, err = repo.ConfigValue("tix.unset-key")
+	if err != nil {
+		t.Fatalf("ConfigValue() for unset key error: %v", err)
+	}
+	if got != "" {
+		t.Errorf("ConfigValue(unset) = %q, want empty", got)
+	}
+}
+
+func TestRenameBranch(t *testing.T) {
+	repo := newTestRepo(t)
+
+	if err := repo.CreateBranch("tmp-20250102150405-add-export"); err != nil {
+		t.Fatalf("CreateBranch() error: %v", err)
+	}
+	if err := repo.RenameBranch("tmp-20250102150405-add-export", "42-add-export"); err != nil {
+		t.Fatalf("RenameBranch() error: %v", err)
+	}
+
+	if err := exec.Command("git", "-C", repo.path, "rev-parse", "--verify", "refs/heads/42-add-export").Run(); err != nil {
+		t.Error("expected renamed branch 42-add-export to exist")
+	}
+	if err := exec.Command("git", "-C", repo.path, "rev-parse", "--verify", "refs/heads/tmp-20250102150405-add-export").Run(); err == nil {
+		t.Error("expected old branch name to be gone after rename")
+	}
+}
+
+func TestSetUpstream(t *testing.T) {
+	repo := newTestRepo(t)
+
+	run := func(args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", args...)
+		cmd.Dir = repo.path
+		if out, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+	}
+	// Fake a fetched origin/main without needing a real remote
+	run("remote", "add", "origin", repo.path)
+	run("update-ref", "refs/remotes/origin/main", "HEAD")
+
+	if err := repo.CreateBranchFrom("feature", "HEAD"); err != nil {
+		t.Fatalf("CreateBranchFrom() error: %v", err)
+	}
+
+	for _, base := range []string{"main", "origin/main"} {
+		upstream, err := repo.SetUpstream("feature", "origin", base)
+		if err != nil {
+			t.Fatalf("SetUpstream(%q) error: %v", base, err)
+		}
+		if upstream != "origin/main" {
+			t.Errorf("SetUpstream(%q) = %q, want origin/main", base, upstream)
+		}
+	}
+
+	merge, err := repo.ConfigValue("branch.feature.merge")
+	if err != nil {
+		t.Fatalf("ConfigValue() error: %v", err)
+	}
+	if merge != "refs/heads/main" {
+		t.Errorf("branch.feature.merge = %q, want refs/heads/main", merge)
+	}
+
+	if _, err := repo.SetUpstream("feature", "origin", "develop"); err == nil {
+		t.Error("expected error when the remote branch doesn't exist")
+	}
+}
+
+func TestFastForwardBranch(t *testing.T) {
+	origin := newTestRepo(t)
+	run := func(dir string, args ...string) {
+		t.Helper()
+		cmd := exec.Command("git", args...)
+		cmd.Dir = dir
+		if out, err := cmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+	}
+	commit := func(dir, message string) {
+		t.Helper()
+		run(dir, "-c", "user.email=test@test.com", "-c", "user.name=Test", "commit", "--allow-empty", "-m", message)
+	}
+	run(origin.path, "branch", "-M", "main")
+
+	cloneDir := filepath.Join(t.TempDir(), "clone")
+	run(origin.path, "clone", "--quiet", origin.path, cloneDir)
+	clone, err := Open(cloneDir)
+	if err != nil {
+		t.Fatalf("Open() error: %v", err)
+	}
+
+	// Checked out: updated in the worktree with --ff-only
+	commit(origin.path, "second")
+	result, err := clone.FastForwardBranch("main")
+	if err != nil {
+		t.Fatalf("FastForwardBranch() error: %v", err)
+	}
+	if !result.Updated || result.Behind != 1 || result.Upstream != "origin/main" {
+		t.Errorf("FastForwardBranch() = %+v, want updated 1 commit behind origin/main", result)
+	}
+
+	// Not checked out: only the ref moves
+	run(cloneDir, "checkout", "--quiet", "-b", "work")
+	commit(origin.path, "third")
+	if result, err = clone.FastForwardBranch("main"); err != nil || !result.Updated {
+		t.Fatalf("FastForwardBranch() = %+v, %v, want updated", result, err)
+	}
+	if !clone.RevisionExists("main") || clone.RevisionExists("main~3") {
+		t.Error("expected main to have exactly three commits after fast-forwarding")
+	}
+
+	// Local commits: left alone
+	run(cloneDir, "checkout", "--quiet", "main")
+	commit(cloneDir, "local")
+	commit(origin.path, "fourth")
+	result, err = clone.FastForwardBranch("main")
+	if err != nil {
+		t.Fatalf("FastForwardBranch() error: %v", err)
+	}
+	if result.Updated || result.Ahead != 1 || result.Behind != 1 {
+		t.Errorf("FastForwardBranch() = %+v, want 1 ahead and 1 behind, not updated", result)
+	}
+
+	if _, err := clone.FastForwardBranch("work"); err == nil {
+		t.Error("expected an error for a branch without an upstream")
+	}
+}
@@ -2,19 +2,85 @@ package git
 
 import (
 	"fmt"
+	"os"
 	"os/exec"
+	"path/filepath"
+	"strings"
 )
 
 // AddWorktree creates a new git worktree at worktreePath with a new branch branchName,
-// based on baseBranch (e.g. "main").
+// based on baseBranch (e.g. "main"). Missing parent directories of
+// worktreePath are created first, and the checkout is verified afterwards
+// (see verifyWorktree).
 // Runs: git worktree add <worktreePath> -b <branchName> <baseBranch>
 func (r *Repository) AddWorktree(worktreePath, branchName, baseBranch string) error {
+	parent := filepath.Dir(worktreePath)
+	if err := os.MkdirAll(parent, 0755); err != nil {
+		return fmt.Errorf("failed to create worktree directory %s: %w", parent, err)
+	}
+
 	cmd := exec.Command("git", "worktree", "add", worktreePath, "-b", branchName, baseBranch)
 	cmd.Dir = r.path
 	output, err := cmd.CombinedOutput()
 	if err != nil {
 		return fmt.Errorf("failed to create worktree: %s: %w", string(output), err)
 	}
+	return verifyWorktree(worktreePath, branchName)
+}
+
+// AddWorktreeForBranch creates a git worktree at worktreePath with the
+// existing branch branchName checked out, verified like AddWorktree.
+// Runs: git worktree add <worktreePath> <branchName>
+func (r *Repository) AddWorktreeForBranch(worktreePath, branchName string) error {
+	parent := filepath.Dir(worktreePath)
+	if err := os.MkdirAll(parent, 0755); err != nil {
+		return fmt.Errorf("failed to create worktree directory %s: %w", parent, err)
+	}
+
+	cmd := exec.Command("git", "worktree", "add", worktreePath, branchName)
+	cmd.Dir = r.path
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to create worktree: %s: %w", string(output), err)
+	}
+	return verifyWorktree(worktreePath, branchName)
+}
+
+// verifyWorktree checks that the worktree git just added at worktreePath is
+// usable: HEAD is branchName and points at a commit, and every tracked file
+// was checked out. git can report success while a checkout was left
+// incomplete, e.g. by sparse-checkout rules or files it couldn't write.
+func verifyWorktree(worktreePath, branchName string) error {
+	run := func(args ...string) (string, error) {
+		cmd := exec.Command("git", args...)
+		cmd.Dir = worktreePath
+		output, err := cmd.Output()
+		return strings.TrimRight(string(output), "\n"), err
+	}
+	hint := fmt.Sprintf("remove it with 'git worktree remove --force %s' and try again", worktreePath)
+
+	head, err := run("symbolic-ref", "--quiet", "HEAD")
+	if err != nil {
+		return fmt.Errorf("worktree %s was created but HEAD isn't on a branch - %s", worktreePath, hint)
+	}
+	if head != "refs/heads/"+branchName {
+		return fmt.Errorf("worktree %s was created on %s instead of %s - %s", worktreePath, strings.TrimPrefix(head, "refs/heads/"), branchName, hint)
+	}
+	if _, err := run("rev-parse", "--verify", "--quiet", "HEAD^{commit}"); err != nil {
+		return fmt.Errorf("worktree %s was created but %s doesn't point at a commit - %s", worktreePath, branchName, hint)
+	}
+
+	status, err := run("status", "--porcelain", "--untracked-files=no")
+	if err != nil {
+		return fmt.Errorf("failed to check worktree %s: %w", worktreePath, err)
+	}
+	if status != "" {
+		files := strings.Split(status, "\n")
+		if len(files) > 5 {
+			files = append(files[:5], fmt.Sprintf("... and %d more", len(files)-5))
+		}
+		return fmt.Errorf("worktree %s wasn't checked out cleanly:\n%s\n%s", worktreePath, strings.Join(files, "\n"), hint)
+	}
 	return nil
 }
 
@@ -41,3 +107,163 @@ func (r *Repository) RemoveWorktreeForce(worktreePath string) error {
 	}
 	return nil
 }
+
+// MoveWorktree moves the git worktree at oldPath to newPath, updating git's
+// worktree metadata.
+// Runs: git worktree move <oldPath> <newPath>
+func (r *Repository) MoveWorktree(oldPath, newPath string) error {
+	cmd := exec.Command("git", "worktree", "move", oldPath, newPath)
+	cmd.Dir = r.path
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to move worktree: %s: %w", string(output), err)
+	}
+	return nil
+}
+
+// WorktreeInfo describes a working tree git has registered for the repository
+type WorktreeInfo struct {
+	Name       string // Directory name of the worktree
+	Path       string
+	Branch     string // Checked out branch; "" when HEAD is detached
+	Head       string // Commit checked out
+	Main       bool   // The repository's main working tree rather than a linked one
+	Locked     bool
+	LockReason string
+	Prunable   bool // Its directory or .git file has been deleted, so 'git worktree prune' would drop it
+}
+
+// ListWorktrees returns the repository's worktrees, the main working tree
+// first.
+// Runs: git worktree list --porcelain
+func (r *Repository) ListWorktrees() ([]WorktreeInfo, error) {
+	cmd := exec.Command("git", "worktree", "list", "--porcelain")
+	cmd.Dir = r.path
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, fmt.Errorf("failed to list worktrees: %w", err)
+	}
+	return parseWorktreeList(string(output)), nil
+}
+
+// parseWorktreeList parses the output of git worktree list --porcelain
+func parseWorktreeList(output string) []WorktreeInfo {
+	var worktrees []WorktreeInfo
+	for _, entry := range strings.Split(strings.TrimSpace(output), "\n\n") {
+		lines := strings.Split(strings.TrimSpace(entry), "\n")
+		if !strings.HasPrefix(lines[0], "worktree ") {
+			continue
+		}
+		path := strings.TrimPrefix(lines[0], "worktree ")
+		info := WorktreeInfo{Name: filepath.Base(path), Path: path, Main: len(worktrees) == 0}
+		for _, line := range lines[1:] {
+			key, value, _ := strings.Cut(line, " ")
+			switch key {
+			case "HEAD":
+				info.Head = value
+			case "branch":
+				info.Branch = strings.TrimPrefix(value, "refs/heads/")
+			case "locked":
+				info.Locked = true
+				info.LockReason = value
+			case "prunable":
+				info.Prunable = true
+			}
+		}
+		worktrees = append(worktrees, info)
+	}
+	return worktrees
+}
+
+// WorktreePathForBranch returns the path of the worktree that has branchName
+// checked out, or an empty string if no worktree has it checked out.
+// Runs: git worktree list --porcelain
+func (r *Repository) WorktreePathForBranch(branchName string) (string, error) {
+	worktrees, err := r.ListWorktrees()
+	if err != nil {
+		return "", err
+	}
+	for _, worktree := range worktrees {
+		if worktree.Branch == branchName {
+			return worktree.Path, nil
+		}
+	}
+	return "", nil
+}
+
+// WorktreeRegistered reports whether git has a worktree registered at path,
+// and whether that registration is prunable because the worktree's directory
+// or its .git file has been deleted.
+// Runs: git worktree list --porcelain
+func (r *Repository) WorktreeRegistered(path string) (registered, prunable bool, err error) {
+	worktrees, err := r.ListWorktrees()
+	if err != nil {
+		return false, false, err
+	}
+	want := resolvePath(path)
+	for _, worktree := range worktrees {
+		if resolvePath(worktree.Path) == want {
+			return true, worktree.Prunable, nil
+		}
+	}
+	return false, false, nil
+}
+
+// resolvePath makes path absolute and resolves symlinks in as much of it as
+// exists, so paths git reports compare equal to the ones tix builds
+func resolvePath(path string) string {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return filepath.Clean(path)
+	}
+	if resolved, err := filepath.EvalSymlinks(abs); err == nil {
+		return resolved
+	}
+	parent, name := filepath.Split(abs)
+	if parent == abs || parent == "" {
+		return abs
+	}
+	return filepath.Join(resolvePath(filepath.Clean(parent)), name)
+}
+
+// PruneWorktrees removes git's records of worktrees whose directories are gone.
+// Runs: git worktree prune
+func (r *Repository) PruneWorktrees() error {
+	cmd := exec.Command("git", "worktree", "prune")
+	cmd.Dir = r.path
+	output, err := cmd.CombinedOutput()
+	if err != nil {
+		return fmt.Errorf("failed to prune worktrees: %s: %w", string(output), err)
+	}
+	return nil
+}
+
+// DirMatchesRevision reports whether dir holds exactly the files of rev, with
+// no modified, deleted or untracked files (ignored files don't count). It is
+// used on leftover worktree directories that git no longer knows about, so
+// the comparison uses a throwaway index instead of the repository's own.
+// Runs: git --work-tree=<dir> read-tree <rev>, then status --porcelain
+func (r *Repository) DirMatchesRevision(dir, rev string) (bool, error) {
+	tmp, err := os.MkdirTemp("", "tix-index-")
+	if err != nil {
+		return false, fmt.Errorf("failed to create temporary index: %w", err)
+	}
+	defer os.RemoveAll(tmp)
+
+	env := append(os.Environ(), "GIT_INDEX_FILE="+filepath.Join(tmp, "index"))
+	readTree := exec.Command("git", "--work-tree="+dir, "read-tree", rev)
+	readTree.Dir = r.path
+	readTree.Env = env
+	if output, err := readTree.CombinedOutput(); err != nil {
+		return false, fmt.Errorf("failed to read %s: %s: %w", rev, strings.TrimSpace(string(output)), err)
+	}
+
+	status := exec.Command("git", "--work-tree="+dir, "status", "--porcelain", "--untracked-files=all")
+	status.Dir = r.path
+	status.Env = env
+	output, err := status.Output()
+	if err != nil {
+		return false, fmt.Errorf("failed to check %s: %w", dir, err)
+	}
+	return strings.TrimSpace(string(output)) == "", nil
+}
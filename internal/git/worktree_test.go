@@ -0,0 +1,238 @@
+package git
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestWorktreePathForBranch(t *testing.T) {
+	repo := newTestRepo(t)
+
+	out, err := exec.Command("git", "-C", repo.path, "branch", "--show-current").Output()
+	if err != nil {
+		t.Fatalf("failed to get current branch: %v", err)
+	}
+	base := strings.TrimSpace(string(out))
+
+	worktreeDir := filepath.Join(t.TempDir(), "123")
+	if err := repo.AddWorktree(worktreeDir, "feature/123-add-thing", base); err != nil {
+		t.Fatalf("AddWorktree() error: %v", err)
+	}
+
+	got, err := repo.WorktreePathForBranch("feature/123-add-thing")
+	if err != nil {
+		t.Fatalf("WorktreePathForBranch() error: %v", err)
+	}
+	wantResolved, _ := filepath.EvalSymlinks(worktreeDir)
+	gotResolved, _ := filepath.EvalSymlinks(got)
+	if gotResolved != wantResolved {
+		t.Errorf("WorktreePathForBranch() = %q, want %q", got, worktreeDir)
+	}
+
+	got, err = repo.WorktreePathForBranch("no-such-branch")
+	if err != nil {
+		t.Fatalf("WorktreePathForBranch() error: %v", err)
+	}
+	if got != "" {
+		t.Errorf("WorktreePathForBranch() for unknown branch = %q, want empty", got)
+	}
+}
+
+func TestVerifyWorktree(t *testing.T) {
+	repo := newTestRepo(t)
+
+	out, err := exec.Command("git", "-C", repo.path, "branch", "--show-current").Output()
+	if err != nil {
+		t.Fatalf("failed to get current branch: %v", err)
+	}
+	base := strings.TrimSpace(string(out))
+
+	worktreeDir := filepath.Join(t.TempDir(), "42")
+	if err := repo.AddWorktree(worktreeDir, "42-add-thing", base); err != nil {
+		t.Fatalf("AddWorktree() error: %v", err)
+	}
+
+	if err := verifyWorktree(worktreeDir, "43-other"); err == nil || !strings.Contains(err.Error(), "instead of 43-other") {
+		t.Errorf("verifyWorktree() for the wrong branch error = %v, want a HEAD mismatch", err)
+	}
+
+	if err := os.Remove(filepath.Join(worktreeDir, "readme.txt")); err != nil {
+		t.Fatal(err)
+	}
+	err = verifyWorktree(worktreeDir, "42-add-thing")
+	if err == nil || !strings.Contains(err.Error(), "wasn't checked out cleanly") || !strings.Contains(err.Error(), " D readme.txt") {
+		t.Errorf("verifyWorktree() with a missing file error = %v, want the file reported", err)
+	}
+}
+
+func TestAddWorktreeCreatesParent(t *testing.T) {
+	repo := newTestRepo(t)
+
+	out, err := exec.Command("git", "-C", repo.path, "branch", "--show-current").Output()
+	if err != nil {
+		t.Fatalf("failed to get current branch: %v", err)
+	}
+	base := strings.TrimSpace(string(out))
+
+	worktreeDir := filepath.Join(t.TempDir(), "worktrees", "app", "123")
+	if err := repo.AddWorktree(worktreeDir, "123-add-thing", base); err != nil {
+		t.Fatalf("AddWorktree() with a missing parent error: %v", err)
+	}
+	if _, err := os.Stat(filepath.Join(worktreeDir, ".git")); err != nil {
+		t.Errorf("expected a worktree at %s: %v", worktreeDir, err)
+	}
+
+	notDir := filepath.Join(t.TempDir(), "file")
+	if err := os.WriteFile(notDir, []byte("x"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	err = repo.AddWorktree(filepath.Join(notDir, "124"), "124-other", base)
+	if err == nil || !strings.Contains(err.Error(), "failed to create worktree directory") {
+		t.Errorf("expected a worktree directory error, got %v", err)
+	}
+}
+
+func TestMoveWorktreeAfterRename(t *testing.T) {
+	repo := newTestRepo(t)
+
+	out, err := exec.Command("git", "-C", repo.path, "branch", "--show-current").Output()
+	if err != nil {
+		t.Fatalf("failed to get current branch: %v", err)
+	}
+	base := strings.TrimSpace(string(out))
+
+	worktrees := t.TempDir()
+	oldDir := filepath.Join(worktrees, "tmp-1-add-thing")
+	newDir := filepath.Join(worktrees, "42-add-thing")
+	if err := repo.AddWorktree(oldDir, "tmp-1-add-thing", base); err != nil {
+		t.Fatalf("AddWorktree() error: %v", err)
+	}
+
+	if err := repo.RenameBranch("tmp-1-add-thing", "42-add-thing"); err != nil {
+		t.Fatalf("RenameBranch() error: %v", err)
+	}
+	if err := repo.MoveWorktree(oldDir, newDir); err != nil {
+		t.Fatalf("MoveWorktree() error: %v", err)
+	}
+
+	got, err := repo.WorktreePathForBranch("42-add-thing")
+	if err != nil {
+		t.Fatalf("WorktreePathForBranch() error: %v", err)
+	}
+	wantResolved, _ := filepath.EvalSymlinks(newDir)
+	gotResolved, _ := filepath.EvalSymlinks(got)
+	if gotResolved != wantResolved {
+		t.Errorf("worktree for renamed branch = %q, want %q", got, newDir)
+	}
+}
+
+func TestStaleWorktreeDetection(t *testing.T) {
+	repo := newTestRepo(t)
+
+	out, err := exec.Command("git", "-C", repo.path, "branch", "--show-current").Output()
+	if err != nil {
+		t.Fatalf("failed to get current branch: %v", err)
+	}
+	base := strings.TrimSpace(string(out))
+
+	worktreeDir := filepath.Join(t.TempDir(), "42-add-thing")
+	if err := repo.AddWorktree(worktreeDir, "42-add-thing", base); err != nil {
+		t.Fatalf("AddWorktree() error: %v", err)
+	}
+	registered, prunable, err := repo.WorktreeRegistered(worktreeDir)
+	if err != nil || !registered || prunable {
+		t.Fatalf("WorktreeRegistered() = %v, %v, %v; want registered and not prunable", registered, prunable, err)
+	}
+
+	// Deleting the worktree's .git file by hand leaves a prunable record and
+	// a directory git no longer knows about
+	if err := os.Remove(filepath.Join(worktreeDir, ".git")); err != nil {
+		t.Fatal(err)
+	}
+	if _, prunable, _ = repo.WorktreeRegistered(worktreeDir); !prunable {
+		t.Error("expected a worktree without its .git file to be prunable")
+	}
+	if err := repo.PruneWorktrees(); err != nil {
+		t.Fatalf("PruneWorktrees() error: %v", err)
+	}
+	if registered, _, _ = repo.WorktreeRegistered(worktreeDir); registered {
+		t.Error("expected the pruned worktree to be unregistered")
+	}
+
+	clean, err := repo.DirMatchesRevision(worktreeDir, base)
+	if err != nil {
+		t.Fatalf("DirMatchesRevision() error: %v", err)
+	}
+	if !clean {
+		t.Error("expected the leftover checkout to match the base branch")
+	}
+
+	if err := os.WriteFile(filepath.Join(worktreeDir, "notes.txt"), []byte("wip"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if clean, _ = repo.DirMatchesRevision(worktreeDir, base); clean {
+		t.Error("expected an untracked file to make the directory unclean")
+	}
+}
+
+func TestParseWorktreeList(t *testing.T) {
+	output := `worktree /src/app
+HEAD 0123456789abcdef0123456789abcdef01234567
+branch refs/heads/main
+
+worktree /wt/app/42-fix-login
+HEAD 89abcdef0123456789abcdef0123456789abcdef
+branch refs/heads/feature/42-fix-login
+locked on a USB drive
+
+worktree /wt/app/gone
+HEAD 89abcdef0123456789abcdef0123456789abcdef
+detached
+prunable gitdir file points to non-existent location
+`
+	got := parseWorktreeList(output)
+	want := []WorktreeInfo{
+		{Name: "app", Path: "/src/app", Branch: "main", Head: "0123456789abcdef0123456789abcdef01234567", Main: true},
+		{Name: "42-fix-login", Path: "/wt/app/42-fix-login", Branch: "feature/42-fix-login", Head: "89abcdef0123456789abcdef0123456789abcdef", Locked: true, LockReason: "on a USB drive"},
+		{Name: "gone", Path: "/wt/app/gone", Head: "89abcdef0123456789abcdef0123456789abcdef", Prunable: true},
+	}
+	if len(got) != len(want) {
+		t.Fatalf("parseWorktreeList() returned %d worktrees, want %d: %+v", len(got), len(want), got)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Errorf("worktree %d = %+v, want %+v", i, got[i], want[i])
+		}
+	}
+}
+
+func TestListWorktrees(t *testing.T) {
+	repo := newTestRepo(t)
+	head, err := repo.HeadCommit()
+	if err != nil {
+		t.Fatalf("HeadCommit() error: %v", err)
+	}
+
+	worktreeDir := filepath.Join(t.TempDir(), "7-add-export")
+	if err := repo.AddWorktree(worktreeDir, "7-add-export", head); err != nil {
+		t.Fatalf("AddWorktree() error: %v", err)
+	}
+	if out, err := exec.Command("git", "-C", repo.path, "worktree", "lock", "--reason", "in use", worktreeDir).CombinedOutput(); err != nil {
+		t.Fatalf("git worktree lock: %v\n%s", err, out)
+	}
+
+	worktrees, err := repo.ListWorktrees()
+	if err != nil {
+		t.Fatalf("ListWorktrees() error: %v", err)
+	}
+	if len(worktrees) != 2 || !worktrees[0].Main || worktrees[1].Main {
+		t.Fatalf("ListWorktrees() = %+v, want the main working tree and one linked worktree", worktrees)
+	}
+	linked := worktrees[1]
+	if linked.Name != "7-add-export" || linked.Branch != "7-add-export" || linked.Head != head || !linked.Locked || linked.LockReason != "in use" || linked.Prunable {
+		t.Errorf("linked worktree = %+v, want 7-add-export locked with reason 'in use'", linked)
+	}
+}
@@ -19,25 +19,20 @@ var (
 
 	// Initialized flag to check if logger has been initialized
 	initialized bool = false
+
+	// noColor disables colored log output (see SetColor)
+	noColor bool
 )
 
 // InitLogger initializes the global logger with the given verbosity level
 func InitLogger(verboseCount int) {
-	// Set up console writer with color
-	output := zerolog.ConsoleWriter{Out: os.Stdout, TimeFormat: time.DateTime, NoColor: false}
-
 	// Set caller marshal function to show short filename and line number
 	zerolog.CallerMarshalFunc = func(pc uintptr, file string, line int) string {
 		return filepath.Base(file) + ":" + strconv.Itoa(line)
 	}
 
-	// Set level formatter to show level in uppercase
-	output.FormatLevel = func(i interface{}) string {
-		return strings.ToUpper(fmt.Sprintf("| %-6s|", i))
-	}
-
 	// Set global logger
-	Logger = zerolog.New(output).With().Timestamp().Caller().Logger()
+	Logger = zerolog.New(consoleWriter(os.Stdout)).With().Timestamp().Caller().Logger()
 
 	// Set log level based on verbose count
 	// 0: WARN (default)
@@ -167,6 +162,36 @@ func Fatal(msg string, err error, fields ...map[string]interface{}) {
 	event.Msg(msg)
 }
 
+// consoleWriter returns the colored console log format writing to out
+func consoleWriter(out io.Writer) zerolog.ConsoleWriter {
+	output := zerolog.ConsoleWriter{Out: out, TimeFormat: time.DateTime, NoColor: noColor}
+
+	// Set level formatter to show level in uppercase
+	output.FormatLevel = func(i interface{}) string {
+		return strings.ToUpper(fmt.Sprintf("| %-6s|", i))
+	}
+	return output
+}
+
+// SetColor turns colored log output on or off. It applies to the logger set
+// up by the next InitLogger or SetOutput call.
+func SetColor(enabled bool) {
+	noColor = !enabled
+}
+
+// SetOutput sends log output to out, keeping the configured level. Commands
+// use it to keep stdout free for machine-readable output.
+func SetOutput(out io.Writer) {
+	// Do nothing if logger is not initialized
+	if !initialized {
+		return
+	}
+
+	level := Logger.GetLevel()
+	Logger = zerolog.New(consoleWriter(out)).With().Timestamp().Caller().Logger().Level(level)
+	log.Logger = Logger
+}
+
 // Writer returns a writer that can be used to pipe logs
 func Writer(level zerolog.Level) io.Writer {
 	// Do nothing if logger is not initialized
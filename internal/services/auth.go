@@ -0,0 +1,36 @@
+package services
+
+import "os"
+
+// Tokens set from the configuration; when empty the GITHUB_TOKEN and
+// GITLAB_TOKEN environment variables are used.
+var (
+	configuredGithubToken string
+	configuredGitlabToken string
+)
+
+// SetGithubToken sets the token used for GitHub API calls
+func SetGithubToken(token string) {
+	configuredGithubToken = token
+}
+
+// SetGitlabToken sets the token used for GitLab API calls
+func SetGitlabToken(token string) {
+	configuredGitlabToken = token
+}
+
+// githubToken returns the configured GitHub token, falling back to GITHUB_TOKEN
+func githubToken() string {
+	if configuredGithubToken != "" {
+		return configuredGithubToken
+	}
+	return os.Getenv("GITHUB_TOKEN")
+}
+
+// gitlabToken returns the configured GitLab token, falling back to GITLAB_TOKEN
+func gitlabToken() string {
+	if configuredGitlabToken != "" {
+		return configuredGitlabToken
+	}
+	return os.Getenv("GITLAB_TOKEN")
+}
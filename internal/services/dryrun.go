@@ -0,0 +1,106 @@
+package services
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"sort"
+	"strings"
+)
+
+// ErrDryRun is returned for the first request that would change something at
+// GitHub or GitLab while dry runs are on; that request is printed instead of
+// sent. See SetDryRun.
+var ErrDryRun = errors.New("dry run: request not sent")
+
+// dryRunOut receives the requests held back by a dry run; nil sends them
+var dryRunOut io.Writer
+
+// SetDryRun prints GitHub and GitLab requests that would change something to
+// out instead of sending them; requests that only read are still sent, so
+// the payload carries IDs and usernames looked up for real. nil turns dry
+// runs off.
+func SetDryRun(out io.Writer) {
+	dryRunOut = out
+}
+
+// skipForDryRun reports whether dry runs are on, noting the skipped step in
+// the dry run output. Writes that only prepare the issue request, such as a
+// new GitLab milestone or an attachment upload, are skipped that way rather
+// than held back, so the dry run still gets to the issue payload.
+func skipForDryRun(format string, args ...interface{}) bool {
+	if dryRunOut == nil {
+		return false
+	}
+	fmt.Fprintf(dryRunOut, "(dry run: "+format+")\n", args...)
+	return true
+}
+
+// redactedHeaders carry API tokens and are never printed
+var redactedHeaders = map[string]bool{
+	"Authorization": true,
+	"Private-Token": true,
+	"Job-Token":     true,
+}
+
+// dryRunTransport holds back and prints requests other than GET and HEAD
+// while dry runs are on
+type dryRunTransport struct {
+	base http.RoundTripper
+}
+
+func (t *dryRunTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	if dryRunOut == nil || req.Method == http.MethodGet || req.Method == http.MethodHead {
+		return t.base.RoundTrip(req)
+	}
+	if req.Body != nil {
+		defer req.Body.Close()
+	}
+	if err := printRequest(dryRunOut, req); err != nil {
+		return nil, err
+	}
+	return nil, ErrDryRun
+}
+
+// printRequest writes req's method, URL, headers (with tokens redacted) and
+// body to out, indenting JSON bodies and leaving out file uploads
+func printRequest(out io.Writer, req *http.Request) error {
+	fmt.Fprintf(out, "%s %s\n", req.Method, req.URL)
+
+	names := make([]string, 0, len(req.Header))
+	for name := range req.Header {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	for _, name := range names {
+		value := req.Header.Get(name)
+		if redactedHeaders[http.CanonicalHeaderKey(name)] {
+			value = "[REDACTED]"
+		}
+		fmt.Fprintf(out, "%s: %s\n", name, value)
+	}
+
+	if req.Body == nil {
+		return nil
+	}
+	body, err := io.ReadAll(req.Body)
+	if err != nil {
+		return fmt.Errorf("failed to read request body: %w", err)
+	}
+	if len(body) == 0 {
+		return nil
+	}
+	if strings.HasPrefix(req.Header.Get("Content-Type"), "multipart/") {
+		fmt.Fprintf(out, "\n(%d bytes of file upload)\n", len(body))
+		return nil
+	}
+	var indented bytes.Buffer
+	if json.Indent(&indented, body, "", "  ") == nil {
+		body = indented.Bytes()
+	}
+	fmt.Fprintf(out, "\n%s\n", bytes.TrimRight(body, "\n"))
+	return nil
+}
@@ -0,0 +1,61 @@
+package services
+
+import (
+	"bytes"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestDryRunTransport(t *testing.T) {
+	t.Cleanup(func() { SetDryRun(nil) })
+
+	hits := 0
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		hits++
+		w.WriteHeader(http.StatusOK)
+	}))
+	defer server.Close()
+
+	var out bytes.Buffer
+	SetDryRun(&out)
+	client := httpClient()
+
+	// Reads still go out
+	resp, err := client.Get(server.URL + "/user")
+	if err != nil {
+		t.Fatalf("Get() error: %v", err)
+	}
+	resp.Body.Close()
+
+	req, err := http.NewRequest(http.MethodPost, server.URL+"/repos/acme/app/issues", strings.NewReader(`{"title":"Fix login","labels":["bug"]}`))
+	if err != nil {
+		t.Fatal(err)
+	}
+	req.Header.Set("Authorization", "Bearer ghp_secret")
+	req.Header.Set("Content-Type", "application/json")
+	_, err = client.Do(req)
+	if !errors.Is(err, ErrDryRun) {
+		t.Fatalf("Do() error = %v, want ErrDryRun", err)
+	}
+
+	if hits != 1 {
+		t.Errorf("server got %d requests, want only the GET", hits)
+	}
+	printed := out.String()
+	for _, want := range []string{
+		"POST " + server.URL + "/repos/acme/app/issues\n",
+		"Authorization: [REDACTED]\n",
+		"Content-Type: application/json\n",
+		"\n{\n  \"title\": \"Fix login\",\n  \"labels\": [\n    \"bug\"\n  ]\n}\n",
+	} {
+		if !strings.Contains(printed, want) {
+			t.Errorf("dry run output missing %q:\n%s", want, printed)
+		}
+	}
+	if strings.Contains(printed, "ghp_secret") || strings.Contains(printed, "/user") {
+		t.Errorf("dry run output leaks the token or shows the GET:\n%s", printed)
+	}
+}
@@ -6,10 +6,11 @@ import (
 	"bytes"
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"io"
 	"net/http"
-	"os"
+	"strconv"
 	"strings"
 
 	"github.com/google/go-github/v62/github"
@@ -19,9 +20,10 @@ import (
 
 // GithubProject represents a GitHub repository
 type GithubProject struct {
-	client *github.Client
-	owner  string
-	repo   string
+	client      *github.Client
+	owner       string
+	repo        string
+	currentUser *github.User // Cached by CurrentUser
 }
 
 // GithubIssue represents a GitHub issue
@@ -44,9 +46,9 @@ type GithubPullRequest struct {
 
 // NewGithubProject creates a new GitHub project client
 func NewGithubProject(repoName string) (*GithubProject, error) {
-	token := os.Getenv("GITHUB_TOKEN")
+	token := githubToken()
 	if token == "" {
-		return nil, fmt.Errorf("GITHUB_TOKEN environment variable is required")
+		return nil, fmt.Errorf("GITHUB_TOKEN environment variable (or github_token/github_token_file setting) is required")
 	}
 
 	parts := strings.Split(repoName, "/")
@@ -58,7 +60,8 @@ func NewGithubProject(repoName string) (*GithubProject, error) {
 		&oauth2.Token{AccessToken: token},
 	)
 
-	client := github.NewClient(oauth2.NewClient(context.Background(), ts))
+	ctx := context.WithValue(context.Background(), oauth2.HTTPClient, githubHTTPClient())
+	client := github.NewClient(oauth2.NewClient(ctx, ts))
 
 	return &GithubProject{
 		client: client,
@@ -67,41 +70,208 @@ func NewGithubProject(repoName string) (*GithubProject, error) {
 	}, nil
 }
 
-// CreateIssue creates a new issue in the repository
-func (p *GithubProject) CreateIssue(title, labels string, selfAssign bool, milestoneTitle ...string) (*GithubIssue, error) {
-	labelSlice := strings.Split(labels, ",")
-	for i, label := range labelSlice {
-		labelSlice[i] = strings.TrimSpace(label)
+// CurrentUser returns the user the GitHub token belongs to. It is looked up
+// on first use and cached, so the token is only sent when something needs it.
+func (p *GithubProject) CurrentUser() (*github.User, error) {
+	if p.currentUser != nil {
+		return p.currentUser, nil
+	}
+
+	user, resp, err := p.client.Users.Get(context.Background(), "")
+	if err != nil {
+		if resp != nil && resp.StatusCode == 401 {
+			return nil, tokenRejectedError("GitHub")
+		}
+		return nil, fmt.Errorf("failed to get current user: %w", err)
 	}
+	p.currentUser = user
+	return user, nil
+}
+
+// ValidateToken checks that GitHub accepts the API token, reusing the cached
+// current user when it has already been looked up
+func (p *GithubProject) ValidateToken() error {
+	_, err := p.CurrentUser()
+	return err
+}
 
+// CreateIssue creates a new issue in the repository
+func (p *GithubProject) CreateIssue(params IssueParams) (*GithubIssue, error) {
 	issue := &github.IssueRequest{
-		Title:  &title,
-		Labels: &labelSlice,
+		Title: &params.Title,
+	}
+	if labels := issueLabels(params); len(labels) > 0 {
+		issue.Labels = &labels
 	}
 
-	// Self-assign if requested
-	if selfAssign {
-		user, _, err := p.client.Users.Get(context.Background(), "")
-		if err != nil {
-			return nil, fmt.Errorf("failed to get current user: %w", err)
+	if params.Body != "" {
+		issue.Body = &params.Body
+	}
+
+	// Quick actions are a GitLab feature; GitHub would show them as plain text
+	if len(params.QuickActions) > 0 {
+		logger.Warn("GitHub doesn't support quick actions - ignoring --quick-action", map[string]interface{}{
+			"quick_actions": params.QuickActions,
+		})
+	}
+	if hasQuickActions(params.Body) {
+		logger.Warn("Issue description contains GitLab quick actions, which GitHub shows as plain text", map[string]interface{}{
+			"repo": p.owner + "/" + p.repo,
+		})
+	}
+
+	if params.Confidential {
+		logger.Warn("GitHub doesn't support confidential issues - creating a regular issue", map[string]interface{}{
+			"repo": p.owner + "/" + p.repo,
+		})
+	}
+
+	if params.Weight != nil {
+		logger.Warn("GitHub doesn't support issue weights - use an estimate label instead", map[string]interface{}{
+			"repo":   p.owner + "/" + p.repo,
+			"weight": *params.Weight,
+		})
+	}
+
+	// GitHub's API has no way to upload files for an issue
+	if len(params.Attachments) > 0 {
+		logger.Warn("GitHub doesn't support uploading attachments through the API - ignoring --attach", map[string]interface{}{
+			"attachments": params.Attachments,
+		})
+	}
+
+	// Assign to the requested users (including yourself)
+	if assignees := issueAssignees(params); len(assignees) > 0 {
+		for i, assignee := range assignees {
+			if assignee == "me" {
+				user, err := p.CurrentUser()
+				if err != nil {
+					return nil, err
+				}
+				assignees[i] = user.GetLogin()
+			}
 		}
-		issue.Assignees = &[]string{*user.Login}
+		issue.Assignees = &assignees
 	}
 
-	// Note: milestoneTitle parameter is ignored for GitHub issues since we're
+	// Note: MilestoneTitle is ignored for GitHub issues since we're
 	// only implementing GitLab milestone support per requirements
 
-	result, _, err := p.client.Issues.Create(context.Background(), p.owner, p.repo, issue)
+	result, resp, err := p.client.Issues.Create(context.Background(), p.owner, p.repo, issue)
 	if err != nil {
-		return nil, fmt.Errorf("failed to create issue: %w", err)
+		if scopeErr := p.tokenScopeError(err); scopeErr != nil {
+			return nil, scopeErr
+		}
+		statusCode := 0
+		if resp != nil {
+			statusCode = resp.StatusCode
+		}
+		return nil, issueCreateError("GitHub", p.owner+"/"+p.repo, statusCode, err)
+	}
+
+	// The issue exists by now, so failing to close or lock it is only a warning
+	if params.Closed {
+		closed := "closed"
+		if _, _, err := p.client.Issues.Edit(context.Background(), p.owner, p.repo, result.GetNumber(), &github.IssueRequest{State: &closed}); err != nil {
+			logger.Warn("Issue was created but couldn't be closed", map[string]interface{}{
+				"issue": result.GetNumber(),
+				"error": err.Error(),
+			})
+		}
+	}
+	if params.Lock {
+		if _, err := p.client.Issues.Lock(context.Background(), p.owner, p.repo, result.GetNumber(), nil); err != nil {
+			logger.Warn("Issue was created but couldn't be locked", map[string]interface{}{
+				"issue": result.GetNumber(),
+				"error": err.Error(),
+			})
+		}
 	}
 
 	return &GithubIssue{
-		Number: *result.Number,
-		Title:  *result.Title,
+		Number:  *result.Number,
+		Title:   *result.Title,
+		HTMLURL: result.GetHTMLURL(),
 	}, nil
 }
 
+// fineGrainedTokenMessage is GitHub's 403 message when a fine-grained personal
+// access token hasn't been granted the permission a request needs
+const fineGrainedTokenMessage = "Resource not accessible by personal access token"
+
+// tokenScopeError turns GitHub's generic 403 for a fine-grained token that
+// lacks access to the repository into guidance on the permission to grant.
+// It returns nil for any other error.
+func (p *GithubProject) tokenScopeError(err error) error {
+	var errResp *github.ErrorResponse
+	if !errors.As(err, &errResp) || errResp.Response == nil || errResp.Response.StatusCode != http.StatusForbidden {
+		return nil
+	}
+	if !strings.Contains(errResp.Message, fineGrainedTokenMessage) {
+		return nil
+	}
+	return fmt.Errorf("your GitHub fine-grained token can't access issues in %s/%s - edit the token to include this repository and grant \"Issues: Read and write\" under Repository permissions", p.owner, p.repo)
+}
+
+// CheckIssue validates an issue against the API without creating it: the
+// token must be accepted and the repository found, and every label and
+// assignee must exist. It returns the problems found.
+func (p *GithubProject) CheckIssue(params IssueParams) ([]string, error) {
+	ctx := context.Background()
+	repoName := p.owner + "/" + p.repo
+
+	if err := p.ValidateToken(); err != nil {
+		return nil, err
+	}
+	if _, resp, err := p.client.Repositories.Get(ctx, p.owner, p.repo); err != nil {
+		if resp != nil && (resp.StatusCode == 401 || resp.StatusCode == 404) {
+			return nil, issueCreateError("GitHub", repoName, resp.StatusCode, err)
+		}
+		return nil, fmt.Errorf("failed to look up repository %s: %w", repoName, err)
+	}
+
+	var problems []string
+	for _, label := range issueLabels(params) {
+		_, resp, err := p.client.Issues.GetLabel(ctx, p.owner, p.repo, label)
+		if err != nil {
+			if resp != nil && resp.StatusCode == 404 {
+				problems = append(problems, fmt.Sprintf("label %q doesn't exist in %s (GitHub would create it)", label, repoName))
+				continue
+			}
+			return nil, fmt.Errorf("failed to look up label %s: %w", label, err)
+		}
+	}
+
+	for _, assignee := range issueAssignees(params) {
+		if assignee == "me" {
+			user, err := p.CurrentUser()
+			if err != nil {
+				return nil, err
+			}
+			assignee = user.GetLogin()
+		}
+		ok, _, err := p.client.Issues.IsAssignee(ctx, p.owner, p.repo, assignee)
+		if err != nil {
+			return nil, fmt.Errorf("failed to check assignee %s: %w", assignee, err)
+		}
+		if !ok {
+			problems = append(problems, fmt.Sprintf("%s can't be assigned issues in %s", assignee, repoName))
+		}
+	}
+
+	if len(params.QuickActions) > 0 {
+		problems = append(problems, "GitHub doesn't support quick actions - they would be ignored")
+	}
+	if params.Confidential {
+		problems = append(problems, "GitHub doesn't support confidential issues - a regular issue would be created")
+	}
+	if len(params.Attachments) > 0 {
+		problems = append(problems, "GitHub doesn't support uploading attachments - they would be ignored")
+	}
+
+	return problems, nil
+}
+
 // GetOpenPullRequestsForIssue returns all open pull requests related to an issue
 func (p *GithubProject) GetOpenPullRequestsForIssue(issueNumber int) ([]*GithubPullRequest, error) {
 	ctx := context.Background()
@@ -224,9 +394,9 @@ func (p *GithubProject) CreatePullRequest(title, sourceBranch, targetBranch stri
 
 // EnableAutoMerge enables auto-merge for a pull request via GitHub's GraphQL API
 func (p *GithubProject) EnableAutoMerge(prNodeID string) error {
-	token := os.Getenv("GITHUB_TOKEN")
+	token := githubToken()
 	if token == "" {
-		return fmt.Errorf("GITHUB_TOKEN environment variable is required")
+		return fmt.Errorf("GITHUB_TOKEN environment variable (or github_token/github_token_file setting) is required")
 	}
 
 	query := `mutation($input: EnablePullRequestAutoMergeInput!) {
@@ -245,6 +415,12 @@ func (p *GithubProject) EnableAutoMerge(prNodeID string) error {
 		},
 	}
 
+	return p.executeGraphQL(token, payload, nil)
+}
+
+// executeGraphQL posts a query to GitHub's GraphQL API and decodes the "data"
+// field of the response into data (if non-nil)
+func (p *GithubProject) executeGraphQL(token string, payload map[string]interface{}, data interface{}) error {
 	jsonData, err := json.Marshal(payload)
 	if err != nil {
 		return fmt.Errorf("failed to marshal GraphQL request: %w", err)
@@ -257,7 +433,7 @@ func (p *GithubProject) EnableAutoMerge(prNodeID string) error {
 	req.Header.Set("Authorization", "bearer "+token)
 	req.Header.Set("Content-Type", "application/json")
 
-	client := &http.Client{}
+	client := githubHTTPClient()
 	resp, err := client.Do(req)
 	if err != nil {
 		return fmt.Errorf("failed to execute GraphQL request: %w", err)
@@ -265,6 +441,7 @@ func (p *GithubProject) EnableAutoMerge(prNodeID string) error {
 	defer resp.Body.Close()
 
 	var result struct {
+		Data   json.RawMessage `json:"data"`
 		Errors []struct {
 			Message string `json:"message"`
 		} `json:"errors"`
@@ -276,19 +453,190 @@ func (p *GithubProject) EnableAutoMerge(prNodeID string) error {
 		return fmt.Errorf("GraphQL error: %s", result.Errors[0].Message)
 	}
 
+	if data != nil && len(result.Data) > 0 {
+		if err := json.Unmarshal(result.Data, data); err != nil {
+			return fmt.Errorf("failed to decode GraphQL data: %w", err)
+		}
+	}
+
 	return nil
 }
 
+// TransferIssue transfers an issue to another repository ("owner/repo") via
+// GitHub's GraphQL API and returns the issue in its new repository
+func (p *GithubProject) TransferIssue(issueNumber int, target string) (*GithubIssue, error) {
+	token := githubToken()
+	if token == "" {
+		return nil, fmt.Errorf("GITHUB_TOKEN environment variable (or github_token/github_token_file setting) is required")
+	}
+
+	parts := strings.Split(target, "/")
+	if len(parts) != 2 || parts[0] == "" || parts[1] == "" {
+		return nil, fmt.Errorf("invalid repository name format: %s", target)
+	}
+
+	ctx := context.Background()
+	issue, _, err := p.client.Issues.Get(ctx, p.owner, p.repo, issueNumber)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get issue #%d: %w", issueNumber, err)
+	}
+	targetRepo, _, err := p.client.Repositories.Get(ctx, parts[0], parts[1])
+	if err != nil {
+		return nil, fmt.Errorf("failed to find repository %s: %w", target, err)
+	}
+
+	query := `mutation($input: TransferIssueInput!) {
+		transferIssue(input: $input) {
+			issue { number title url }
+		}
+	}`
+
+	payload := map[string]interface{}{
+		"query": query,
+		"variables": map[string]interface{}{
+			"input": map[string]interface{}{
+				"issueId":      issue.GetNodeID(),
+				"repositoryId": targetRepo.GetNodeID(),
+			},
+		},
+	}
+
+	var data struct {
+		TransferIssue struct {
+			Issue struct {
+				Number int    `json:"number"`
+				Title  string `json:"title"`
+				URL    string `json:"url"`
+			} `json:"issue"`
+		} `json:"transferIssue"`
+	}
+	if err := p.executeGraphQL(token, payload, &data); err != nil {
+		return nil, fmt.Errorf("failed to transfer issue #%d to %s: %w", issueNumber, target, err)
+	}
+
+	moved := data.TransferIssue.Issue
+	return &GithubIssue{
+		Number:  moved.Number,
+		Title:   moved.Title,
+		HTMLURL: moved.URL,
+	}, nil
+}
+
+// GithubProjectBoard is a GitHub Projects (v2) board owned by a user or organization
+type GithubProjectBoard struct {
+	ID     string
+	Number int
+	Title  string
+}
+
+// FindProjectBoard looks up a Projects (v2) board owned by the repository
+// owner, by number (e.g. "3") or by title (case-insensitive)
+func (p *GithubProject) FindProjectBoard(ref string) (*GithubProjectBoard, error) {
+	token := githubToken()
+	if token == "" {
+		return nil, fmt.Errorf("GITHUB_TOKEN environment variable (or github_token/github_token_file setting) is required")
+	}
+
+	query := `query($owner: String!) {
+		repositoryOwner(login: $owner) {
+			... on ProjectV2Owner {
+				projectsV2(first: 100) {
+					nodes { id number title }
+				}
+			}
+		}
+	}`
+
+	payload := map[string]interface{}{
+		"query": query,
+		"variables": map[string]interface{}{
+			"owner": p.owner,
+		},
+	}
+
+	var data struct {
+		RepositoryOwner *struct {
+			ProjectsV2 struct {
+				Nodes []GithubProjectBoard `json:"nodes"`
+			} `json:"projectsV2"`
+		} `json:"repositoryOwner"`
+	}
+	if err := p.executeGraphQL(token, payload, &data); err != nil {
+		return nil, fmt.Errorf("failed to list projects for %s: %w", p.owner, err)
+	}
+	if data.RepositoryOwner == nil {
+		return nil, fmt.Errorf("GitHub user or organization %s not found", p.owner)
+	}
+
+	if board := matchProjectBoard(data.RepositoryOwner.ProjectsV2.Nodes, ref); board != nil {
+		return board, nil
+	}
+	return nil, fmt.Errorf("project %q not found for %s", ref, p.owner)
+}
+
+// matchProjectBoard returns the board whose number or title matches ref
+func matchProjectBoard(boards []GithubProjectBoard, ref string) *GithubProjectBoard {
+	ref = strings.TrimSpace(ref)
+	if number, err := strconv.Atoi(strings.TrimPrefix(ref, "#")); err == nil {
+		for i := range boards {
+			if boards[i].Number == number {
+				return &boards[i]
+			}
+		}
+	}
+	for i := range boards {
+		if strings.EqualFold(boards[i].Title, ref) {
+			return &boards[i]
+		}
+	}
+	return nil
+}
+
+// AddIssueToProjectBoard adds an issue to a Projects (v2) board found by
+// number or title
+func (p *GithubProject) AddIssueToProjectBoard(issueNumber int, projectRef string) (*GithubProjectBoard, error) {
+	board, err := p.FindProjectBoard(projectRef)
+	if err != nil {
+		return nil, err
+	}
+
+	issue, _, err := p.client.Issues.Get(context.Background(), p.owner, p.repo, issueNumber)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get issue: %w", err)
+	}
+
+	query := `mutation($input: AddProjectV2ItemByIdInput!) {
+		addProjectV2ItemById(input: $input) {
+			item { id }
+		}
+	}`
+
+	payload := map[string]interface{}{
+		"query": query,
+		"variables": map[string]interface{}{
+			"input": map[string]interface{}{
+				"projectId": board.ID,
+				"contentId": issue.GetNodeID(),
+			},
+		},
+	}
+
+	if err := p.executeGraphQL(githubToken(), payload, nil); err != nil {
+		return nil, fmt.Errorf("failed to add issue to project %q: %w", board.Title, err)
+	}
+	return board, nil
+}
+
 // GetPullRequestDiff returns the diff of a pull request
 func (p *GithubProject) GetPullRequestDiff(prNumber int) (string, error) {
 	// Get the diff using the raw API
-	token := os.Getenv("GITHUB_TOKEN")
+	token := githubToken()
 	if token == "" {
-		return "", fmt.Errorf("GITHUB_TOKEN environment variable is required")
+		return "", fmt.Errorf("GITHUB_TOKEN environment variable (or github_token/github_token_file setting) is required")
 	}
 
 	// Create HTTP client with token
-	client := &http.Client{}
+	client := githubHTTPClient()
 	diffURL := fmt.Sprintf("https://api.github.com/repos/%s/%s/pulls/%d", p.owner, p.repo, prNumber)
 	req, err := http.NewRequest("GET", diffURL, nil)
 	if err != nil {
@@ -394,6 +742,9 @@ func (p *GithubProject) GetIssue(issueNumber int) (*GithubIssue, error) {
 	ctx := context.Background()
 	issue, _, err := p.client.Issues.Get(ctx, p.owner, p.repo, issueNumber)
 	if err != nil {
+		if scopeErr := p.tokenScopeError(err); scopeErr != nil {
+			return nil, scopeErr
+		}
 		return nil, fmt.Errorf("failed to get issue: %w", err)
 	}
 
@@ -416,6 +767,133 @@ func (p *GithubProject) GetIssue(issueNumber int) (*GithubIssue, error) {
 	}, nil
 }
 
+// ListIssues pages through the repository's open issues (pull requests are
+// skipped), calling fn for each until opts.Limit issues have been seen
+func (p *GithubProject) ListIssues(opts ListIssuesOptions, fn func(*GithubIssue)) error {
+	listOpts := &github.IssueListByRepoOptions{
+		State:       "open",
+		Since:       opts.Since,
+		ListOptions: github.ListOptions{PerPage: listPageSize(opts.Limit)},
+	}
+
+	seen := 0
+	for {
+		issues, resp, err := p.client.Issues.ListByRepo(context.Background(), p.owner, p.repo, listOpts)
+		if err != nil {
+			return fmt.Errorf("failed to list issues: %w", err)
+		}
+
+		for _, issue := range issues {
+			// The issues endpoint also returns pull requests
+			if issue.IsPullRequest() {
+				continue
+			}
+
+			var labels []string
+			for _, label := range issue.Labels {
+				labels = append(labels, label.GetName())
+			}
+			fn(&GithubIssue{
+				Number:         issue.GetNumber(),
+				Title:          issue.GetTitle(),
+				Labels:         labels,
+				HTMLURL:        issue.GetHTMLURL(),
+				MilestoneTitle: issue.GetMilestone().GetTitle(),
+			})
+
+			seen++
+			if opts.Limit > 0 && seen >= opts.Limit {
+				return nil
+			}
+		}
+
+		if resp.NextPage == 0 {
+			return nil
+		}
+		listOpts.Page = resp.NextPage
+	}
+}
+
+// recentIssueScan is how many of the newest issues FindIssueByBody looks at
+const recentIssueScan = 50
+
+// FindIssueByBody returns the newest issue (open or closed) whose body
+// contains text, looking only at the most recent issues so a match is found
+// even before the search index has caught up
+func (p *GithubProject) FindIssueByBody(text string) (*GithubIssue, error) {
+	issues, _, err := p.client.Issues.ListByRepo(context.Background(), p.owner, p.repo, &github.IssueListByRepoOptions{
+		State:       "all",
+		Sort:        "created",
+		Direction:   "desc",
+		ListOptions: github.ListOptions{PerPage: recentIssueScan},
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to list recent issues: %w", err)
+	}
+
+	for _, issue := range issues {
+		if issue.IsPullRequest() || !strings.Contains(issue.GetBody(), text) {
+			continue
+		}
+		var labels []string
+		for _, label := range issue.Labels {
+			labels = append(labels, label.GetName())
+		}
+		return &GithubIssue{
+			Number:         issue.GetNumber(),
+			Title:          issue.GetTitle(),
+			Labels:         labels,
+			HTMLURL:        issue.GetHTMLURL(),
+			MilestoneTitle: issue.GetMilestone().GetTitle(),
+		}, nil
+	}
+	return nil, nil
+}
+
+// LabelExists reports whether the repository has label
+func (p *GithubProject) LabelExists(label string) (bool, error) {
+	_, resp, err := p.client.Issues.GetLabel(context.Background(), p.owner, p.repo, label)
+	if err != nil {
+		if resp != nil && resp.StatusCode == 404 {
+			return false, nil
+		}
+		return false, fmt.Errorf("failed to look up label %s: %w", label, err)
+	}
+	return true, nil
+}
+
+// CreateLabel creates label with color ("#ff0000"; GitHub stores it without
+// the "#")
+func (p *GithubProject) CreateLabel(label, color string) error {
+	color = strings.TrimPrefix(color, "#")
+	_, _, err := p.client.Issues.CreateLabel(context.Background(), p.owner, p.repo, &github.Label{Name: &label, Color: &color})
+	if err != nil {
+		return fmt.Errorf("failed to create label %s: %w", label, err)
+	}
+	return nil
+}
+
+// BranchProtection reports whether branch is protected and whether its rules
+// block direct pushes: required reviews, required status checks or push
+// restrictions. Reading the rules needs admin access, so without it a
+// protected branch is assumed to block pushes.
+func (p *GithubProject) BranchProtection(branch string) (*BranchProtection, error) {
+	ctx := context.Background()
+	b, _, err := p.client.Repositories.GetBranch(ctx, p.owner, p.repo, branch, 1)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get branch %s: %w", branch, err)
+	}
+	if !b.GetProtected() {
+		return &BranchProtection{}, nil
+	}
+
+	protection := &BranchProtection{Protected: true, PushBlocked: true}
+	if rules, _, err := p.client.Repositories.GetBranchProtection(ctx, p.owner, p.repo, branch); err == nil {
+		protection.PushBlocked = rules.RequiredPullRequestReviews != nil || rules.RequiredStatusChecks != nil || rules.Restrictions != nil
+	}
+	return protection, nil
+}
+
 // AddLabelsToIssue adds labels to an existing issue
 func (p *GithubProject) AddLabelsToIssue(issueNumber int, labels []string) error {
 	ctx := context.Background()
@@ -546,9 +1024,65 @@ func (p *GitHubProvider) GetIssue(issueNumber int) (*IssueResult, error) {
 		Labels:         issue.Labels,
 		MilestoneID:    0, // GitHub uses a different milestone format
 		MilestoneTitle: issue.MilestoneTitle,
+		URL:            issue.HTMLURL,
+	}, nil
+}
+
+// MoveIssue implements the IssueMover interface
+func (p *GitHubProvider) MoveIssue(issueNumber int, target string) (*IssueResult, error) {
+	issue, err := p.project.TransferIssue(issueNumber, target)
+	if err != nil {
+		return nil, err
+	}
+	return &IssueResult{
+		Number: issue.Number,
+		Title:  issue.Title,
+		URL:    issue.HTMLURL,
 	}, nil
 }
 
+// CheckIssue implements the IssueChecker interface
+func (p *GitHubProvider) CheckIssue(params IssueParams) ([]string, error) {
+	return p.project.CheckIssue(params)
+}
+
+// FindIssueByBody implements the IssueFinder interface
+func (p *GitHubProvider) FindIssueByBody(text string) (*IssueResult, error) {
+	issue, err := p.project.FindIssueByBody(text)
+	if err != nil || issue == nil {
+		return nil, err
+	}
+	return &IssueResult{
+		Number:         issue.Number,
+		Title:          issue.Title,
+		Labels:         issue.Labels,
+		MilestoneTitle: issue.MilestoneTitle,
+		URL:            issue.HTMLURL,
+	}, nil
+}
+
+// CurrentUsername implements the UserResolver interface
+func (p *GitHubProvider) CurrentUsername() (string, error) {
+	user, err := p.project.CurrentUser()
+	if err != nil {
+		return "", err
+	}
+	return user.GetLogin(), nil
+}
+
+// ListIssues implements the SCMProvider interface
+func (p *GitHubProvider) ListIssues(opts ListIssuesOptions, fn func(IssueResult)) error {
+	return p.project.ListIssues(opts, func(issue *GithubIssue) {
+		fn(IssueResult{
+			Number:         issue.Number,
+			Title:          issue.Title,
+			Labels:         issue.Labels,
+			MilestoneTitle: issue.MilestoneTitle,
+			URL:            issue.HTMLURL,
+		})
+	})
+}
+
 // GetURL returns the GitHub URL for the repo
 func (p *GitHubProvider) GetURL() string {
 	return fmt.Sprintf("https://github.com/%s/%s", p.project.owner, p.project.repo)
@@ -561,7 +1095,7 @@ func (p *GitHubProvider) GetCrossRepoIssueRef(issueNumber int) string {
 
 // CreateIssue implements the SCMProvider interface
 func (p *GitHubProvider) CreateIssue(params IssueParams) (*IssueResult, error) {
-	issue, err := p.project.CreateIssue(params.Title, params.Labels, params.SelfAssign, params.MilestoneTitle)
+	issue, err := p.project.CreateIssue(params)
 	if err != nil {
 		return nil, err
 	}
@@ -570,14 +1104,39 @@ func (p *GitHubProvider) CreateIssue(params IssueParams) (*IssueResult, error) {
 		Number: issue.Number,
 		Title:  issue.Title,
 		Labels: issue.Labels,
+		URL:    issue.HTMLURL,
 	}, nil
 }
 
+// AddIssueToProjectBoard implements the ProjectBoardProvider interface
+func (p *GitHubProvider) AddIssueToProjectBoard(issueNumber int, project string) (string, error) {
+	board, err := p.project.AddIssueToProjectBoard(issueNumber, project)
+	if err != nil {
+		return "", err
+	}
+	return board.Title, nil
+}
+
 // AddLabelsToIssue implements the SCMProvider interface
 func (p *GitHubProvider) AddLabelsToIssue(issueNumber int, labels []string) error {
 	return p.project.AddLabelsToIssue(issueNumber, labels)
 }
 
+// LabelExists implements the LabelCreator interface
+func (p *GitHubProvider) LabelExists(label string) (bool, error) {
+	return p.project.LabelExists(label)
+}
+
+// CreateLabel implements the LabelCreator interface
+func (p *GitHubProvider) CreateLabel(label, color string) error {
+	return p.project.CreateLabel(label, color)
+}
+
+// BranchProtection implements the BranchProtectionChecker interface
+func (p *GitHubProvider) BranchProtection(branch string) (*BranchProtection, error) {
+	return p.project.BranchProtection(branch)
+}
+
 // RemoveLabelsFromIssue implements the SCMProvider interface
 func (p *GitHubProvider) RemoveLabelsFromIssue(issueNumber int, labels []string) error {
 	return p.project.RemoveLabelsFromIssue(issueNumber, labels)
@@ -0,0 +1,232 @@
+package services
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/google/go-github/v62/github"
+)
+
+func TestMatchProjectBoard(t *testing.T) {
+	boards := []GithubProjectBoard{
+		{ID: "PVT_1", Number: 1, Title: "Roadmap"},
+		{ID: "PVT_2", Number: 7, Title: "Sprint 12"},
+	}
+
+	tests := []struct {
+		name   string
+		ref    string
+		wantID string
+	}{
+		{name: "by number", ref: "7", wantID: "PVT_2"},
+		{name: "by number with hash", ref: "#1", wantID: "PVT_1"},
+		{name: "by title case-insensitive", ref: "sprint 12", wantID: "PVT_2"},
+		{name: "not found", ref: "Backlog", wantID: ""},
+		{name: "unknown number", ref: "42", wantID: ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := matchProjectBoard(boards, tt.ref)
+			if tt.wantID == "" {
+				if got != nil {
+					t.Errorf("matchProjectBoard(%q) = %+v, want nil", tt.ref, got)
+				}
+				return
+			}
+			if got == nil || got.ID != tt.wantID {
+				t.Errorf("matchProjectBoard(%q) = %+v, want ID %s", tt.ref, got, tt.wantID)
+			}
+		})
+	}
+}
+
+// newTestGithubProject returns a project whose API calls go to handler
+func newTestGithubProject(t *testing.T, handler http.HandlerFunc) *GithubProject {
+	t.Helper()
+	srv := httptest.NewServer(handler)
+	t.Cleanup(srv.Close)
+
+	client := github.NewClient(nil)
+	baseURL, err := url.Parse(srv.URL + "/")
+	if err != nil {
+		t.Fatal(err)
+	}
+	client.BaseURL = baseURL
+	return &GithubProject{client: client, owner: "owner", repo: "repo"}
+}
+
+func TestGithubCurrentUserCached(t *testing.T) {
+	calls := 0
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		calls++
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`{"login":"octocat"}`))
+	})
+
+	for i := 0; i < 2; i++ {
+		user, err := p.CurrentUser()
+		if err != nil {
+			t.Fatalf("CurrentUser() error: %v", err)
+		}
+		if user.GetLogin() != "octocat" {
+			t.Errorf("CurrentUser() login = %q, want octocat", user.GetLogin())
+		}
+	}
+	if err := p.ValidateToken(); err != nil {
+		t.Fatalf("ValidateToken() error: %v", err)
+	}
+	if calls != 1 {
+		t.Errorf("expected 1 API call, got %d", calls)
+	}
+}
+
+func TestGithubProviderCurrentUsername(t *testing.T) {
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`{"login":"octocat"}`))
+	})
+
+	username, err := (&GitHubProvider{project: p}).CurrentUsername()
+	if err != nil {
+		t.Fatalf("CurrentUsername() error: %v", err)
+	}
+	if username != "octocat" {
+		t.Errorf("CurrentUsername() = %q, want octocat", username)
+	}
+}
+
+func TestGithubValidateTokenRejected(t *testing.T) {
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		http.Error(w, `{"message":"Bad credentials"}`, http.StatusUnauthorized)
+	})
+
+	err := p.ValidateToken()
+	if err == nil {
+		t.Fatal("expected error for a rejected token")
+	}
+	if !strings.Contains(err.Error(), "rejected the API token") {
+		t.Errorf("unexpected error: %v", err)
+	}
+}
+
+func TestGithubCreateIssueLabels(t *testing.T) {
+	var got github.IssueRequest
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		if err := json.NewDecoder(r.Body).Decode(&got); err != nil {
+			t.Errorf("failed to decode request: %v", err)
+		}
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`{"number":1,"title":"Add export"}`))
+	})
+
+	if _, err := p.CreateIssue(IssueParams{Title: "Add export", Labels: "bug, ,good first issue,"}); err != nil {
+		t.Fatalf("CreateIssue() error: %v", err)
+	}
+	want := []string{"bug", "good first issue"}
+	if got.Labels == nil || !reflect.DeepEqual(*got.Labels, want) {
+		t.Errorf("CreateIssue() sent labels %v, want %v", got.Labels, want)
+	}
+
+	got = github.IssueRequest{}
+	if _, err := p.CreateIssue(IssueParams{Title: "Add export"}); err != nil {
+		t.Fatalf("CreateIssue() error: %v", err)
+	}
+	if got.Labels != nil {
+		t.Errorf("CreateIssue() without labels sent %v, want none", *got.Labels)
+	}
+}
+
+func TestGithubCreateIssueClosedAndLocked(t *testing.T) {
+	var calls []string
+	var state string
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		calls = append(calls, r.Method+" "+r.URL.Path)
+		if r.Method == http.MethodPatch {
+			var req github.IssueRequest
+			_ = json.NewDecoder(r.Body).Decode(&req)
+			state = req.GetState()
+		}
+		w.Header().Set("Content-Type", "application/json")
+		if r.Method == http.MethodPut {
+			w.WriteHeader(http.StatusNoContent)
+			return
+		}
+		_, _ = w.Write([]byte(`{"number":7,"title":"Old outage"}`))
+	})
+
+	if _, err := p.CreateIssue(IssueParams{Title: "Old outage", Closed: true, Lock: true}); err != nil {
+		t.Fatalf("CreateIssue() error: %v", err)
+	}
+	want := []string{"POST /repos/owner/repo/issues", "PATCH /repos/owner/repo/issues/7", "PUT /repos/owner/repo/issues/7/lock"}
+	if !reflect.DeepEqual(calls, want) {
+		t.Errorf("API calls = %q, want %q", calls, want)
+	}
+	if state != "closed" {
+		t.Errorf("issue state = %q, want closed", state)
+	}
+}
+
+func TestGithubFineGrainedTokenError(t *testing.T) {
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusForbidden)
+		_, _ = w.Write([]byte(`{"message":"Resource not accessible by personal access token"}`))
+	})
+
+	_, err := p.CreateIssue(IssueParams{Title: "Add export"})
+	if err == nil || !strings.Contains(err.Error(), "Issues: Read and write") || !strings.Contains(err.Error(), "owner/repo") {
+		t.Errorf("CreateIssue() error = %v, want fine-grained token guidance for owner/repo", err)
+	}
+
+	_, err = p.GetIssue(1)
+	if err == nil || !strings.Contains(err.Error(), "Issues: Read and write") {
+		t.Errorf("GetIssue() error = %v, want fine-grained token guidance", err)
+	}
+}
+
+func TestGithubForbiddenWithoutScopeMessage(t *testing.T) {
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		w.WriteHeader(http.StatusForbidden)
+		_, _ = w.Write([]byte(`{"message":"Must have admin rights to Repository."}`))
+	})
+
+	_, err := p.CreateIssue(IssueParams{Title: "Add export"})
+	if err == nil || !strings.Contains(err.Error(), "you don't have permission") {
+		t.Errorf("CreateIssue() error = %v, want the generic permission error", err)
+	}
+}
+
+func TestGithubFindIssueByBody(t *testing.T) {
+	var query url.Values
+	p := newTestGithubProject(t, func(w http.ResponseWriter, r *http.Request) {
+		query = r.URL.Query()
+		w.Header().Set("Content-Type", "application/json")
+		_, _ = w.Write([]byte(`[
+			{"number": 9, "title": "Add export", "body": "<!-- tix-idempotency-key: abc -->", "pull_request": {"url": "x"}},
+			{"number": 8, "title": "Add export", "body": "Details\n\n<!-- tix-idempotency-key: abc -->", "html_url": "https://github.com/owner/repo/issues/8"},
+			{"number": 7, "title": "Add export", "body": "<!-- tix-idempotency-key: abc -->"}
+		]`))
+	})
+
+	issue, err := p.FindIssueByBody("<!-- tix-idempotency-key: abc -->")
+	if err != nil {
+		t.Fatalf("FindIssueByBody() error: %v", err)
+	}
+	if issue == nil || issue.Number != 8 {
+		t.Fatalf("FindIssueByBody() = %+v, want the newest issue #8 (pull requests skipped)", issue)
+	}
+	if query.Get("state") != "all" || query.Get("sort") != "created" || query.Get("direction") != "desc" {
+		t.Errorf("unexpected list query: %v", query)
+	}
+
+	if issue, err := p.FindIssueByBody("<!-- tix-idempotency-key: missing -->"); err != nil || issue != nil {
+		t.Errorf("FindIssueByBody() for an unknown key = %+v, %v, want nil, nil", issue, err)
+	}
+}
@@ -6,6 +6,7 @@ import (
 	"fmt"
 	"net/http"
 	"os"
+	"path/filepath"
 	"strings"
 
 	"github.com/tedkulp/tix/internal/logger"
@@ -14,8 +15,9 @@ import (
 
 // GitlabProject represents a GitLab repository
 type GitlabProject struct {
-	client *gitlab.Client
-	pid    string
+	client      *gitlab.Client
+	pid         string
+	currentUser *gitlab.User // Cached by CurrentUser
 }
 
 // GraphQL structures for issue status update
@@ -33,6 +35,7 @@ type GraphQLResponse struct {
 
 // GitlabIssue represents a GitLab issue
 type GitlabIssue struct {
+	ID             int // Instance-wide id; IID is the number within the project
 	IID            int
 	Title          string
 	Labels         []string
@@ -66,12 +69,12 @@ type GitLabProvider struct {
 
 // NewGitlabProject creates a new GitLab project client
 func NewGitlabProject(repoName string) (*GitlabProject, error) {
-	token := os.Getenv("GITLAB_TOKEN")
+	token := gitlabToken()
 	if token == "" {
-		return nil, fmt.Errorf("GITLAB_TOKEN environment variable is required")
+		return nil, fmt.Errorf("GITLAB_TOKEN environment variable (or gitlab_token/gitlab_token_file setting) is required")
 	}
 
-	client, err := gitlab.NewClient(token)
+	client, err := gitlab.NewClient(token, gitlab.WithHTTPClient(httpClient()))
 	if err != nil {
 		return nil, fmt.Errorf("failed to create GitLab client: %w", err)
 	}
@@ -82,12 +85,35 @@ func NewGitlabProject(repoName string) (*GitlabProject, error) {
 	}, nil
 }
 
-// GetMilestoneID returns the ID of a milestone by title
+// GetMilestoneID returns the ID of a milestone by title, creating it at
+// project level when neither the project nor its groups have it
 func (p *GitlabProject) GetMilestoneID(title string) (int, error) {
 	if title == "" {
 		return 0, nil
 	}
 
+	id, err := p.findMilestoneID(title)
+	if err != nil || id > 0 {
+		return id, err
+	}
+
+	// Otherwise, create the milestone at project level
+	if skipForDryRun("milestone %q doesn't exist and would be created", title) {
+		return 0, nil
+	}
+	milestone, _, err := p.client.Milestones.CreateMilestone(p.pid, &gitlab.CreateMilestoneOptions{
+		Title: &title,
+	})
+	if err != nil {
+		return 0, fmt.Errorf("failed to create milestone: %w", err)
+	}
+
+	return milestone.ID, nil
+}
+
+// findMilestoneID looks up a milestone by title in the project and then its
+// ancestor groups. It returns 0 if there is none.
+func (p *GitlabProject) findMilestoneID(title string) (int, error) {
 	// List project milestones to find the one with matching title
 	milestones, _, err := p.client.Milestones.ListMilestones(p.pid, &gitlab.ListMilestonesOptions{
 		Title: &title,
@@ -105,77 +131,249 @@ func (p *GitlabProject) GetMilestoneID(title string) (int, error) {
 		return milestones[0].ID, nil
 	}
 
-	// Get project details to find the group
+	groupID, err := p.projectGroupID()
+	if err != nil || groupID == 0 {
+		return 0, err
+	}
+	return p.findGroupMilestoneID(groupID, title)
+}
+
+// projectGroupID returns the ID of the group the project belongs to, or 0 for
+// a project in a user namespace
+func (p *GitlabProject) projectGroupID() (int, error) {
 	project, _, err := p.client.Projects.GetProject(p.pid, nil)
 	if err != nil {
 		return 0, fmt.Errorf("failed to get project details: %w", err)
 	}
+	if project.Namespace == nil || project.Namespace.Kind != "group" {
+		return 0, nil
+	}
+	return project.Namespace.ID, nil
+}
 
-	// Check if project belongs to a group
-	// d
-	if project.Namespace != nil && project.Namespace.Kind == "group" {
-		// Get the group ID
-		groupID := project.Namespace.ID
+// listGroupMilestones lists the active milestones of a group and its
+// ancestor groups, only those titled title when it is set
+func (p *GitlabProject) listGroupMilestones(groupID int, title string) ([]*gitlab.GroupMilestone, error) {
+	opt := &gitlab.ListGroupMilestonesOptions{
+		IncludeAncestors: gitlab.Ptr(true),
+	}
+	if title != "" {
+		opt.Title = &title
+	} else {
+		opt.State = gitlab.Ptr("active")
+	}
 
-		// Set include ancestors to true to check all parent groups
-		includeAncestors := true
+	milestones, _, err := p.client.GroupMilestones.ListGroupMilestones(groupID, opt)
+	if err != nil {
+		return nil, fmt.Errorf("failed to list group milestones: %w", err)
+	}
 
-		// List group milestones to find the one with matching title
-		groupMilestones, _, err := p.client.GroupMilestones.ListGroupMilestones(groupID, &gitlab.ListGroupMilestonesOptions{
-			Title:            &title,
-			IncludeAncestors: &includeAncestors,
-		})
-		if err != nil {
-			return 0, fmt.Errorf("failed to list group milestones: %w", err)
+	logger.Debug("Group milestones found", map[string]any{
+		"group_id":   groupID,
+		"milestones": milestones,
+	})
+	return milestones, nil
+}
+
+// findGroupMilestoneID looks up a milestone by title in a group and its
+// ancestors. It returns 0 if there is none.
+func (p *GitlabProject) findGroupMilestoneID(groupID int, title string) (int, error) {
+	milestones, err := p.listGroupMilestones(groupID, title)
+	if err != nil {
+		return 0, err
+	}
+	if len(milestones) > 0 {
+		return milestones[0].ID, nil
+	}
+	return 0, nil
+}
+
+// GetGroupMilestoneID returns the ID of a group-level milestone by title.
+// Unlike GetMilestoneID it never falls back to the project or creates one:
+// the project must be in a group, and the group (or an ancestor) must have
+// the milestone. The error for a missing one lists the active milestones.
+func (p *GitlabProject) GetGroupMilestoneID(title string) (int, error) {
+	groupID, err := p.projectGroupID()
+	if err != nil {
+		return 0, err
+	}
+	if groupID == 0 {
+		return 0, fmt.Errorf("project %s isn't in a group, so it has no group milestones", p.pid)
+	}
+
+	id, err := p.findGroupMilestoneID(groupID, title)
+	if err != nil || id > 0 {
+		return id, err
+	}
+
+	active, err := p.listGroupMilestones(groupID, "")
+	if err != nil {
+		return 0, err
+	}
+	if len(active) == 0 {
+		return 0, fmt.Errorf("group milestone %q not found - the groups of %s have no active milestones", title, p.pid)
+	}
+	titles := make([]string, len(active))
+	for i, milestone := range active {
+		titles[i] = milestone.Title
+	}
+	return 0, fmt.Errorf("group milestone %q not found for %s (active: %s)", title, p.pid, strings.Join(titles, ", "))
+}
+
+// CurrentUser returns the user the GitLab token belongs to. It is looked up
+// on first use and cached, so the token is only sent when something needs it.
+func (p *GitlabProject) CurrentUser() (*gitlab.User, error) {
+	if p.currentUser != nil {
+		return p.currentUser, nil
+	}
+
+	user, resp, err := p.client.Users.CurrentUser()
+	if err != nil {
+		if resp != nil && resp.StatusCode == 401 {
+			return nil, tokenRejectedError("GitLab")
 		}
+		return nil, fmt.Errorf("failed to get current user: %w", err)
+	}
+	p.currentUser = user
+	return user, nil
+}
 
-		logger.Debug("Group milestones found", map[string]any{
-			"group_id":   groupID,
-			"milestones": groupMilestones,
-		})
+// ValidateToken checks that GitLab accepts the API token, reusing the cached
+// current user when it has already been looked up
+func (p *GitlabProject) ValidateToken() error {
+	_, err := p.CurrentUser()
+	return err
+}
+
+// resolveAssigneeIDs looks up the user IDs for a list of usernames, where
+// "me" is the current user. Every unknown username is reported in one error,
+// and a user named twice (e.g. "me" and their username) is assigned once.
+func (p *GitlabProject) resolveAssigneeIDs(assignees []string) ([]int, error) {
+	var ids []int
+	var notFound []string
+	seen := make(map[int]bool)
+	for _, assignee := range assignees {
+		var id int
+		if assignee == "me" {
+			user, err := p.CurrentUser()
+			if err != nil {
+				return nil, err
+			}
+			id = user.ID
+		} else {
+			username := assignee
+			users, _, err := p.client.Users.ListUsers(&gitlab.ListUsersOptions{Username: &username})
+			if err != nil {
+				return nil, fmt.Errorf("failed to look up user %s: %w", assignee, err)
+			}
+			if len(users) == 0 {
+				notFound = append(notFound, assignee)
+				continue
+			}
+			id = users[0].ID
+		}
 
-		// Return the ID if found at group level
-		if len(groupMilestones) > 0 {
-			return groupMilestones[0].ID, nil
+		if !seen[id] {
+			seen[id] = true
+			ids = append(ids, id)
 		}
 	}
 
-	// Otherwise, create the milestone at project level
-	milestone, _, err := p.client.Milestones.CreateMilestone(p.pid, &gitlab.CreateMilestoneOptions{
-		Title: &title,
-	})
-	if err != nil {
-		return 0, fmt.Errorf("failed to create milestone: %w", err)
+	switch len(notFound) {
+	case 0:
+		return ids, nil
+	case 1:
+		return nil, fmt.Errorf("GitLab user %s not found", notFound[0])
+	default:
+		return nil, fmt.Errorf("GitLab users not found: %s", strings.Join(notFound, ", "))
 	}
+}
 
-	return milestone.ID, nil
+// CheckIssue validates an issue against the API without creating it: the
+// token must be accepted and the project found, and every label, assignee and
+// the milestone must exist. It returns the problems found.
+func (p *GitlabProject) CheckIssue(params IssueParams) ([]string, error) {
+	if err := p.ValidateToken(); err != nil {
+		return nil, err
+	}
+	if _, resp, err := p.client.Projects.GetProject(p.pid, nil); err != nil {
+		if resp != nil && (resp.StatusCode == 401 || resp.StatusCode == 404) {
+			return nil, issueCreateError("GitLab", p.pid, resp.StatusCode, err)
+		}
+		return nil, fmt.Errorf("failed to look up project %s: %w", p.pid, err)
+	}
+
+	var problems []string
+	for _, label := range issueLabels(params) {
+		_, resp, err := p.client.Labels.GetLabel(p.pid, label)
+		if err != nil {
+			if resp != nil && resp.StatusCode == 404 {
+				problems = append(problems, fmt.Sprintf("label %q doesn't exist in %s (GitLab would create it)", label, p.pid))
+				continue
+			}
+			return nil, fmt.Errorf("failed to look up label %s: %w", label, err)
+		}
+	}
+
+	for _, assignee := range issueAssignees(params) {
+		if _, err := p.resolveAssigneeIDs([]string{assignee}); err != nil {
+			problems = append(problems, err.Error())
+		}
+	}
+
+	if params.MilestoneTitle != "" && params.GroupMilestone {
+		if _, err := p.GetGroupMilestoneID(params.MilestoneTitle); err != nil {
+			problems = append(problems, err.Error())
+		}
+	} else if params.MilestoneTitle != "" {
+		id, err := p.findMilestoneID(params.MilestoneTitle)
+		if err != nil {
+			return nil, err
+		}
+		if id == 0 {
+			problems = append(problems, fmt.Sprintf("milestone %q doesn't exist in %s (it would be created)", params.MilestoneTitle, p.pid))
+		}
+	}
+
+	return problems, nil
 }
 
 // CreateIssue creates a new issue in the repository
-func (p *GitlabProject) CreateIssue(title, labels string, selfAssign bool, milestoneTitle ...string) (*GitlabIssue, error) {
-	labelSlice := strings.Split(labels, ",")
-	for i, label := range labelSlice {
-		labelSlice[i] = strings.TrimSpace(label)
+func (p *GitlabProject) CreateIssue(params IssueParams) (*GitlabIssue, error) {
+	opt := &gitlab.CreateIssueOptions{
+		Title: &params.Title,
+	}
+	if labels := issueLabels(params); len(labels) > 0 {
+		labelsOpt := gitlab.LabelOptions(labels)
+		opt.Labels = &labelsOpt
 	}
 
-	labelsOpt := gitlab.LabelOptions(labelSlice)
-	opt := &gitlab.CreateIssueOptions{
-		Title:  &title,
-		Labels: &labelsOpt,
+	if params.Confidential {
+		opt.Confidential = gitlab.Ptr(true)
 	}
 
-	// Self-assign if requested
-	if selfAssign {
-		user, _, err := p.client.Users.CurrentUser()
+	if params.Weight != nil {
+		opt.Weight = params.Weight
+	}
+
+	// Assign to the requested users (including yourself)
+	if assignees := issueAssignees(params); len(assignees) > 0 {
+		assigneeIDs, err := p.resolveAssigneeIDs(assignees)
 		if err != nil {
-			return nil, fmt.Errorf("failed to get current user: %w", err)
+			return nil, err
 		}
-		opt.AssigneeIDs = &[]int{user.ID}
+		opt.AssigneeIDs = &assigneeIDs
 	}
 
 	// Add milestone if provided
-	if len(milestoneTitle) > 0 && milestoneTitle[0] != "" {
-		milestoneID, err := p.GetMilestoneID(milestoneTitle[0])
+	if params.MilestoneTitle != "" && params.GroupMilestone {
+		milestoneID, err := p.GetGroupMilestoneID(params.MilestoneTitle)
+		if err != nil {
+			return nil, err
+		}
+		opt.MilestoneID = &milestoneID
+	} else if params.MilestoneTitle != "" {
+		milestoneID, err := p.GetMilestoneID(params.MilestoneTitle)
 		if err != nil {
 			return nil, fmt.Errorf("failed to get milestone ID: %w", err)
 		}
@@ -185,17 +383,74 @@ func (p *GitlabProject) CreateIssue(title, labels string, selfAssign bool, miles
 		}
 	}
 
-	result, _, err := p.client.Issues.CreateIssue(p.pid, opt)
+	// Upload attachments last, so a bad assignee or milestone doesn't leave
+	// orphaned uploads behind
+	attachmentRefs, err := p.uploadAttachments(params.Attachments)
+	if err != nil {
+		return nil, err
+	}
+	if description := appendQuickActions(appendAttachments(params.Body, attachmentRefs), params.QuickActions); description != "" {
+		opt.Description = &description
+	}
+
+	result, resp, err := p.client.Issues.CreateIssue(p.pid, opt)
 	if err != nil {
-		return nil, fmt.Errorf("failed to create issue: %w", err)
+		statusCode := 0
+		if resp != nil {
+			statusCode = resp.StatusCode
+		}
+		return nil, issueCreateError("GitLab", p.pid, statusCode, err)
+	}
+
+	// GitLab can't create an issue closed or locked, so both are applied
+	// after; the issue exists by now, so a failure is only a warning
+	if params.Closed || params.Lock {
+		update := &gitlab.UpdateIssueOptions{}
+		if params.Closed {
+			update.StateEvent = gitlab.Ptr("close")
+		}
+		if params.Lock {
+			update.DiscussionLocked = gitlab.Ptr(true)
+		}
+		if _, _, err := p.client.Issues.UpdateIssue(p.pid, result.IID, update); err != nil {
+			logger.Warn("Issue was created but couldn't be closed or locked", map[string]interface{}{
+				"issue": result.IID,
+				"error": err.Error(),
+			})
+		}
 	}
 
 	return &GitlabIssue{
-		IID:   result.IID,
-		Title: result.Title,
+		ID:     result.ID,
+		IID:    result.IID,
+		Title:  result.Title,
+		WebURL: result.WebURL,
 	}, nil
 }
 
+// uploadAttachments uploads each file to the project and returns the markdown
+// references GitLab generates for them
+func (p *GitlabProject) uploadAttachments(paths []string) ([]string, error) {
+	refs := make([]string, 0, len(paths))
+	for _, path := range paths {
+		if skipForDryRun("%s would be uploaded", path) {
+			refs = append(refs, fmt.Sprintf("[%s](/uploads/dry-run/%s)", filepath.Base(path), filepath.Base(path)))
+			continue
+		}
+		f, err := os.Open(path)
+		if err != nil {
+			return nil, fmt.Errorf("failed to open attachment: %w", err)
+		}
+		uploaded, _, err := p.client.Projects.UploadFile(p.pid, f, filepath.Base(path))
+		f.Close()
+		if err != nil {
+			return nil, fmt.Errorf("failed to upload attachment %s: %w", path, err)
+		}
+		refs = append(refs, uploaded.Markdown)
+	}
+	return refs, nil
+}
+
 // GetOpenMergeRequestsByBranch returns all open merge requests for a specific source branch
 func (p *GitlabProject) GetOpenMergeRequestsByBranch(branchName string) ([]*GitlabMergeRequest, error) {
 	// Use GitLab's API to get MRs by source branch
@@ -265,6 +520,7 @@ func (p *GitlabProject) GetIssue(issueNumber int) (*GitlabIssue, error) {
 	}
 
 	return &GitlabIssue{
+		ID:             issue.ID,
 		IID:            issue.IID,
 		Title:          issue.Title,
 		Labels:         issue.Labels,
@@ -274,6 +530,113 @@ func (p *GitlabProject) GetIssue(issueNumber int) (*GitlabIssue, error) {
 	}, nil
 }
 
+// ListIssues pages through the project's open issues, calling fn for each
+// until opts.Limit issues have been seen
+func (p *GitlabProject) ListIssues(opts ListIssuesOptions, fn func(*GitlabIssue)) error {
+	listOpts := &gitlab.ListProjectIssuesOptions{
+		State:       gitlab.Ptr("opened"),
+		ListOptions: gitlab.ListOptions{PerPage: listPageSize(opts.Limit)},
+	}
+	if !opts.Since.IsZero() {
+		listOpts.UpdatedAfter = &opts.Since
+	}
+
+	seen := 0
+	for {
+		issues, resp, err := p.client.Issues.ListProjectIssues(p.pid, listOpts)
+		if err != nil {
+			return fmt.Errorf("failed to list issues: %w", err)
+		}
+
+		for _, issue := range issues {
+			var milestoneID int
+			var milestoneTitle string
+			if issue.Milestone != nil {
+				milestoneID = issue.Milestone.ID
+				milestoneTitle = issue.Milestone.Title
+			}
+			fn(&GitlabIssue{
+				ID:             issue.ID,
+				IID:            issue.IID,
+				Title:          issue.Title,
+				Labels:         issue.Labels,
+				MilestoneID:    milestoneID,
+				MilestoneTitle: milestoneTitle,
+				WebURL:         issue.WebURL,
+			})
+
+			seen++
+			if opts.Limit > 0 && seen >= opts.Limit {
+				return nil
+			}
+		}
+
+		if resp.NextPage == 0 {
+			return nil
+		}
+		listOpts.Page = resp.NextPage
+	}
+}
+
+// FindIssueByBody returns the newest issue (open or closed) whose
+// description contains text, looking only at the most recent issues
+func (p *GitlabProject) FindIssueByBody(text string) (*GitlabIssue, error) {
+	issues, _, err := p.client.Issues.ListProjectIssues(p.pid, &gitlab.ListProjectIssuesOptions{
+		OrderBy:     gitlab.Ptr("created_at"),
+		Sort:        gitlab.Ptr("desc"),
+		ListOptions: gitlab.ListOptions{PerPage: recentIssueScan},
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to list recent issues: %w", err)
+	}
+
+	for _, issue := range issues {
+		if !strings.Contains(issue.Description, text) {
+			continue
+		}
+		var milestoneID int
+		var milestoneTitle string
+		if issue.Milestone != nil {
+			milestoneID = issue.Milestone.ID
+			milestoneTitle = issue.Milestone.Title
+		}
+		return &GitlabIssue{
+			ID:             issue.ID,
+			IID:            issue.IID,
+			Title:          issue.Title,
+			Labels:         issue.Labels,
+			MilestoneID:    milestoneID,
+			MilestoneTitle: milestoneTitle,
+			WebURL:         issue.WebURL,
+		}, nil
+	}
+	return nil, nil
+}
+
+// MoveIssue moves an issue to another project ("group/project") and returns
+// the issue in its new project
+func (p *GitlabProject) MoveIssue(issueIID int, target string) (*GitlabIssue, error) {
+	targetProject, _, err := p.client.Projects.GetProject(target, nil)
+	if err != nil {
+		return nil, fmt.Errorf("failed to find project %s: %w", target, err)
+	}
+
+	moved, _, err := p.client.Issues.MoveIssue(p.pid, issueIID, &gitlab.MoveIssueOptions{
+		ToProjectID: gitlab.Ptr(targetProject.ID),
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to move issue #%d to %s: %w", issueIID, target, err)
+	}
+
+	return &GitlabIssue{
+		ID:     moved.ID,
+		IID:    moved.IID,
+		Title:  moved.Title,
+		Labels: moved.Labels,
+		WebURL: moved.WebURL,
+	}, nil
+}
+
 // CreateMergeRequest creates a new merge request in the repository
 func (p *GitlabProject) CreateMergeRequest(title, sourceBranch, targetBranch string, issueIID int, options CreateMergeRequestOptions, descriptionOverride string) (*GitlabMergeRequest, error) {
 	// Add "Draft:" prefix if it's a draft MR
@@ -373,7 +736,7 @@ func (p *GitlabProject) GetMergeRequestDiff(mrIID int) (string, error) {
 
 // UpdateMergeRequestDescription updates the description of a merge request
 func (p *GitlabProject) UpdateMergeRequestDescription(mrIID int, description string) error {
-	// Keep the "Closes #X" reference if it exists
+	// Keep the "Closes #X" (or Fixes/Resolves) reference if it exists
 	existingMR, _, err := p.client.MergeRequests.GetMergeRequest(p.pid, mrIID, nil)
 	if err != nil {
 		return fmt.Errorf("failed to get merge request: %w", err)
@@ -384,7 +747,8 @@ func (p *GitlabProject) UpdateMergeRequestDescription(mrIID int, description str
 	lines := strings.Split(existingMR.Description, "\n")
 	issueRef := ""
 	for _, line := range lines {
-		if strings.HasPrefix(line, "Closes #") || strings.HasPrefix(line, "Related to #") {
+		if strings.HasPrefix(line, "Closes #") || strings.HasPrefix(line, "Fixes #") ||
+			strings.HasPrefix(line, "Resolves #") || strings.HasPrefix(line, "Related to #") {
 			issueRef = line
 			break
 		}
@@ -433,6 +797,40 @@ func (p *GitlabProject) UpdateIssueTitle(issueIID int, title string) error {
 	return nil
 }
 
+// LabelExists reports whether the project has label
+func (p *GitlabProject) LabelExists(label string) (bool, error) {
+	_, resp, err := p.client.Labels.GetLabel(p.pid, label)
+	if err != nil {
+		if resp != nil && resp.StatusCode == 404 {
+			return false, nil
+		}
+		return false, fmt.Errorf("failed to look up label %s: %w", label, err)
+	}
+	return true, nil
+}
+
+// CreateLabel creates label in the project with color ("#ff0000")
+func (p *GitlabProject) CreateLabel(label, color string) error {
+	_, _, err := p.client.Labels.CreateLabel(p.pid, &gitlab.CreateLabelOptions{
+		Name:  gitlab.Ptr(label),
+		Color: gitlab.Ptr(color),
+	})
+	if err != nil {
+		return fmt.Errorf("failed to create label %s: %w", label, err)
+	}
+	return nil
+}
+
+// BranchProtection reports whether branch is protected and whether the
+// authenticated user is kept from pushing to it directly
+func (p *GitlabProject) BranchProtection(branch string) (*BranchProtection, error) {
+	b, _, err := p.client.Branches.GetBranch(p.pid, branch)
+	if err != nil {
+		return nil, fmt.Errorf("failed to get branch %s: %w", branch, err)
+	}
+	return &BranchProtection{Protected: b.Protected, PushBlocked: b.Protected && !b.CanPush}, nil
+}
+
 // AddLabelsToIssue adds labels to an existing issue
 func (p *GitlabProject) AddLabelsToIssue(issueIID int, labels []string) error {
 	// Get current issue to preserve existing labels
@@ -510,9 +908,9 @@ func (p *GitlabProject) UpdateIssueStatus(issueIID int, status string) error {
 		return nil
 	}
 
-	token := os.Getenv("GITLAB_TOKEN")
+	token := gitlabToken()
 	if token == "" {
-		return fmt.Errorf("GITLAB_TOKEN environment variable is required")
+		return fmt.Errorf("GITLAB_TOKEN environment variable (or gitlab_token/gitlab_token_file setting) is required")
 	}
 
 	// First, get the issue's global ID using REST API
@@ -638,9 +1036,9 @@ func (p *GitlabProject) executeGraphQLRequest(request GraphQLRequest) error {
 
 // executeGraphQLRequestWithResponse executes a GraphQL request and returns the response
 func (p *GitlabProject) executeGraphQLRequestWithResponse(request GraphQLRequest) (map[string]any, error) {
-	token := os.Getenv("GITLAB_TOKEN")
+	token := gitlabToken()
 	if token == "" {
-		return nil, fmt.Errorf("GITLAB_TOKEN environment variable is required")
+		return nil, fmt.Errorf("GITLAB_TOKEN environment variable (or gitlab_token/gitlab_token_file setting) is required")
 	}
 
 	// Make GraphQL request
@@ -659,7 +1057,7 @@ func (p *GitlabProject) executeGraphQLRequestWithResponse(request GraphQLRequest
 	req.Header.Set("Authorization", "Bearer "+token)
 
 	// Make the request
-	client := &http.Client{}
+	client := httpClient()
 	resp, err := client.Do(req)
 	if err != nil {
 		return nil, fmt.Errorf("failed to execute GraphQL request: %w", err)
@@ -867,13 +1265,74 @@ func (p *GitLabProvider) GetIssue(issueNumber int) (*IssueResult, error) {
 
 	return &IssueResult{
 		Number:         issue.IID,
+		ID:             issue.ID,
 		Title:          issue.Title,
 		Labels:         issue.Labels,
 		MilestoneID:    issue.MilestoneID,
 		MilestoneTitle: issue.MilestoneTitle,
+		URL:            issue.WebURL,
 	}, nil
 }
 
+// MoveIssue implements the IssueMover interface
+func (p *GitLabProvider) MoveIssue(issueNumber int, target string) (*IssueResult, error) {
+	issue, err := p.project.MoveIssue(issueNumber, target)
+	if err != nil {
+		return nil, err
+	}
+	return &IssueResult{
+		Number: issue.IID,
+		Title:  issue.Title,
+		Labels: issue.Labels,
+		URL:    issue.WebURL,
+	}, nil
+}
+
+// CheckIssue implements the IssueChecker interface
+func (p *GitLabProvider) CheckIssue(params IssueParams) ([]string, error) {
+	return p.project.CheckIssue(params)
+}
+
+// FindIssueByBody implements the IssueFinder interface
+func (p *GitLabProvider) FindIssueByBody(text string) (*IssueResult, error) {
+	issue, err := p.project.FindIssueByBody(text)
+	if err != nil || issue == nil {
+		return nil, err
+	}
+	return &IssueResult{
+		Number:         issue.IID,
+		ID:             issue.ID,
+		Title:          issue.Title,
+		Labels:         issue.Labels,
+		MilestoneID:    issue.MilestoneID,
+		MilestoneTitle: issue.MilestoneTitle,
+		URL:            issue.WebURL,
+	}, nil
+}
+
+// CurrentUsername implements the UserResolver interface
+func (p *GitLabProvider) CurrentUsername() (string, error) {
+	user, err := p.project.CurrentUser()
+	if err != nil {
+		return "", err
+	}
+	return user.Username, nil
+}
+
+// ListIssues implements the SCMProvider interface
+func (p *GitLabProvider) ListIssues(opts ListIssuesOptions, fn func(IssueResult)) error {
+	return p.project.ListIssues(opts, func(issue *GitlabIssue) {
+		fn(IssueResult{
+			Number:         issue.IID,
+			Title:          issue.Title,
+			Labels:         issue.Labels,
+			MilestoneID:    issue.MilestoneID,
+			MilestoneTitle: issue.MilestoneTitle,
+			URL:            issue.WebURL,
+		})
+	})
+}
+
 // GetURL returns the GitLab URL for the repo
 func (p *GitLabProvider) GetURL() string {
 	return fmt.Sprintf("https://gitlab.com/%s", p.project.pid)
@@ -886,20 +1345,17 @@ func (p *GitLabProvider) GetCrossRepoIssueRef(issueNumber int) string {
 
 // CreateIssue implements the SCMProvider interface
 func (p *GitLabProvider) CreateIssue(params IssueParams) (*IssueResult, error) {
-	var milestoneTitle string
-	if params.MilestoneTitle != "" {
-		milestoneTitle = params.MilestoneTitle
-	}
-
-	issue, err := p.project.CreateIssue(params.Title, params.Labels, params.SelfAssign, milestoneTitle)
+	issue, err := p.project.CreateIssue(params)
 	if err != nil {
 		return nil, err
 	}
 
 	return &IssueResult{
 		Number: issue.IID,
+		ID:     issue.ID,
 		Title:  issue.Title,
 		Labels: issue.Labels,
+		URL:    issue.WebURL,
 	}, nil
 }
 
@@ -908,6 +1364,21 @@ func (p *GitLabProvider) AddLabelsToIssue(issueNumber int, labels []string) erro
 	return p.project.AddLabelsToIssue(issueNumber, labels)
 }
 
+// LabelExists implements the LabelCreator interface
+func (p *GitLabProvider) LabelExists(label string) (bool, error) {
+	return p.project.LabelExists(label)
+}
+
+// CreateLabel implements the LabelCreator interface
+func (p *GitLabProvider) CreateLabel(label, color string) error {
+	return p.project.CreateLabel(label, color)
+}
+
+// BranchProtection implements the BranchProtectionChecker interface
+func (p *GitLabProvider) BranchProtection(branch string) (*BranchProtection, error) {
+	return p.project.BranchProtection(branch)
+}
+
 // RemoveLabelsFromIssue implements the SCMProvider interface
 func (p *GitLabProvider) RemoveLabelsFromIssue(issueNumber int, labels []string) error {
 	return p.project.RemoveLabelsFromIssue(issueNumber, labels)
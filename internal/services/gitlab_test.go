@@ -0,0 +1,251 @@
+package services
+
+import (
+	"encoding/json"
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+
+	gitlab "gitlab.com/gitlab-org/api/client-go"
+)
+
+// newTestGitlabProject returns a project whose API calls go to handler
+func newTestGitlabProject(t *testing.T, handler http.HandlerFunc) *GitlabProject {
+	t.Helper()
+	srv := httptest.NewServer(handler)
+	t.Cleanup(srv.Close)
+
+	client, err := gitlab.NewClient("token", gitlab.WithBaseURL(srv.URL))
+	if err != nil {
+		t.Fatal(err)
+	}
+	return &GitlabProject{client: client, pid: "group/project"}
+}
+
+func TestGitlabResolveAssigneeIDs(t *testing.T) {
+	userIDs := map[string]int{"me": 1, "alice": 2, "bob": 3}
+	p := newTestGitlabProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		switch r.URL.Path {
+		case "/api/v4/user":
+			_ = json.NewEncoder(w).Encode(map[string]any{"id": 1, "username": "me"})
+		case "/api/v4/users":
+			users := []map[string]any{}
+			if id, ok := userIDs[r.URL.Query().Get("username")]; ok {
+				users = append(users, map[string]any{"id": id})
+			}
+			_ = json.NewEncoder(w).Encode(users)
+		default:
+			http.NotFound(w, r)
+		}
+	})
+
+	ids, err := p.resolveAssigneeIDs([]string{"me", "alice", "bob", "me"})
+	if err != nil {
+		t.Fatalf("resolveAssigneeIDs() error: %v", err)
+	}
+	if want := []int{1, 2, 3}; !reflect.DeepEqual(ids, want) {
+		t.Errorf("resolveAssigneeIDs() = %v, want %v", ids, want)
+	}
+
+	_, err = p.resolveAssigneeIDs([]string{"alice", "carol", "dave"})
+	if err == nil || err.Error() != "GitLab users not found: carol, dave" {
+		t.Errorf("expected one error naming every unknown user, got %v", err)
+	}
+
+	_, err = p.resolveAssigneeIDs([]string{"carol"})
+	if err == nil || err.Error() != "GitLab user carol not found" {
+		t.Errorf("unexpected error for one unknown user: %v", err)
+	}
+}
+
+func TestGitlabGetGroupMilestoneID(t *testing.T) {
+	namespace := map[string]any{"id": 7, "kind": "group"}
+	p := newTestGitlabProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		switch r.URL.Path {
+		case "/api/v4/projects/group/project":
+			_ = json.NewEncoder(w).Encode(map[string]any{"id": 1, "namespace": namespace})
+		case "/api/v4/groups/7/milestones":
+			milestones := []map[string]any{{"id": 11, "title": "Q3"}, {"id": 12, "title": "Q4"}}
+			if title := r.URL.Query().Get("title"); title != "" {
+				var matching []map[string]any
+				for _, m := range milestones {
+					if m["title"] == title {
+						matching = append(matching, m)
+					}
+				}
+				milestones = matching
+			}
+			_ = json.NewEncoder(w).Encode(milestones)
+		default:
+			http.NotFound(w, r)
+		}
+	})
+
+	id, err := p.GetGroupMilestoneID("Q4")
+	if err != nil {
+		t.Fatalf("GetGroupMilestoneID() error: %v", err)
+	}
+	if id != 12 {
+		t.Errorf("GetGroupMilestoneID() = %d, want 12", id)
+	}
+
+	_, err = p.GetGroupMilestoneID("Q1")
+	if err == nil || !strings.Contains(err.Error(), "(active: Q3, Q4)") {
+		t.Errorf("expected a not found error listing the active milestones, got %v", err)
+	}
+
+	namespace = map[string]any{"id": 3, "kind": "user"}
+	_, err = p.GetGroupMilestoneID("Q4")
+	if err == nil || !strings.Contains(err.Error(), "isn't in a group") {
+		t.Errorf("expected an error for a project outside a group, got %v", err)
+	}
+}
+
+func TestGitlabCreateIssueAttachments(t *testing.T) {
+	screenshot := filepath.Join(t.TempDir(), "screenshot.png")
+	if err := os.WriteFile(screenshot, []byte("png"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	var uploaded, description string
+	p := newTestGitlabProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		switch r.URL.Path {
+		case "/api/v4/projects/group/project/uploads":
+			if _, header, err := r.FormFile("file"); err == nil {
+				uploaded = header.Filename
+			}
+			_ = json.NewEncoder(w).Encode(map[string]any{"markdown": "![screenshot](/uploads/abc/screenshot.png)"})
+		case "/api/v4/projects/group/project/issues":
+			var body map[string]any
+			_ = json.NewDecoder(r.Body).Decode(&body)
+			description, _ = body["description"].(string)
+			_ = json.NewEncoder(w).Encode(map[string]any{"iid": 5, "title": "Crash on save"})
+		default:
+			http.NotFound(w, r)
+		}
+	})
+
+	_, err := p.CreateIssue(IssueParams{
+		Title:        "Crash on save",
+		Body:         "Steps to reproduce",
+		Attachments:  []string{screenshot},
+		QuickActions: []string{"/label ~bug"},
+	})
+	if err != nil {
+		t.Fatalf("CreateIssue() error: %v", err)
+	}
+	if uploaded != "screenshot.png" {
+		t.Errorf("uploaded file name = %q, want screenshot.png", uploaded)
+	}
+	want := "Steps to reproduce\n\n![screenshot](/uploads/abc/screenshot.png)\n\n/label ~bug"
+	if description != want {
+		t.Errorf("description = %q, want %q", description, want)
+	}
+}
+
+func TestGitlabCreateIssueDryRun(t *testing.T) {
+	t.Cleanup(func() { SetDryRun(nil) })
+	screenshot := filepath.Join(t.TempDir(), "screenshot.png")
+	if err := os.WriteFile(screenshot, []byte("png"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	var posts []string
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		if r.Method != http.MethodGet {
+			posts = append(posts, r.URL.Path)
+		}
+		// No milestone exists, in the project or its groups
+		switch r.URL.Path {
+		case "/api/v4/projects/group/project":
+			_ = json.NewEncoder(w).Encode(map[string]any{"id": 1, "namespace": map[string]any{"id": 2, "kind": "user"}})
+		default:
+			_ = json.NewEncoder(w).Encode([]any{})
+		}
+	}))
+	defer srv.Close()
+	client, err := gitlab.NewClient("token", gitlab.WithBaseURL(srv.URL), gitlab.WithHTTPClient(httpClient()))
+	if err != nil {
+		t.Fatal(err)
+	}
+	p := &GitlabProject{client: client, pid: "group/project"}
+
+	var out strings.Builder
+	SetDryRun(&out)
+	_, err = p.CreateIssue(IssueParams{Title: "Crash on save", MilestoneTitle: "2026.Q4", Attachments: []string{screenshot}})
+	if !errors.Is(err, ErrDryRun) {
+		t.Fatalf("CreateIssue() error = %v, want ErrDryRun", err)
+	}
+	if len(posts) != 0 {
+		t.Errorf("dry run sent %v", posts)
+	}
+	printed := out.String()
+	for _, want := range []string{
+		`milestone "2026.Q4" doesn't exist and would be created`,
+		screenshot + " would be uploaded",
+		"POST " + srv.URL + "/api/v4/projects/group%2Fproject/issues\n",
+		`"title": "Crash on save"`,
+	} {
+		if !strings.Contains(printed, want) {
+			t.Errorf("dry run output missing %q:\n%s", want, printed)
+		}
+	}
+}
+
+func TestGitlabCreateIssueClosedAndLocked(t *testing.T) {
+	var update map[string]any
+	p := newTestGitlabProject(t, func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "application/json")
+		switch r.Method + " " + r.URL.Path {
+		case "POST /api/v4/projects/group/project/issues":
+			_ = json.NewEncoder(w).Encode(map[string]any{"iid": 5, "title": "Old outage"})
+		case "PUT /api/v4/projects/group/project/issues/5":
+			_ = json.NewDecoder(r.Body).Decode(&update)
+			_ = json.NewEncoder(w).Encode(map[string]any{"iid": 5, "title": "Old outage", "state": "closed"})
+		default:
+			http.NotFound(w, r)
+		}
+	})
+
+	if _, err := p.CreateIssue(IssueParams{Title: "Old outage", Closed: true, Lock: true}); err != nil {
+		t.Fatalf("CreateIssue() error: %v", err)
+	}
+	if update["state_event"] != "close" || update["discussion_locked"] != true {
+		t.Errorf("issue update = %v, want it closed and locked", update)
+	}
+}
+
+func TestGitlabFindIssueByBody(t *testing.T) {
+	p := newTestGitlabProject(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/v4/projects/group/project/issues" || r.URL.Query().Get("order_by") != "created_at" {
+			http.NotFound(w, r)
+			return
+		}
+		w.Header().Set("Content-Type", "application/json")
+		_ = json.NewEncoder(w).Encode([]map[string]any{
+			{"iid": 4, "title": "Other", "description": "Unrelated"},
+			{"iid": 3, "title": "Add export", "description": "Details\n\n<!-- tix-idempotency-key: abc -->", "web_url": "https://gitlab.com/group/project/-/issues/3"},
+		})
+	})
+
+	issue, err := p.FindIssueByBody("<!-- tix-idempotency-key: abc -->")
+	if err != nil {
+		t.Fatalf("FindIssueByBody() error: %v", err)
+	}
+	if issue == nil || issue.IID != 3 {
+		t.Fatalf("FindIssueByBody() = %+v, want issue #3", issue)
+	}
+
+	if issue, err := p.FindIssueByBody("<!-- tix-idempotency-key: missing -->"); err != nil || issue != nil {
+		t.Errorf("FindIssueByBody() for an unknown key = %+v, %v, want nil, nil", issue, err)
+	}
+}
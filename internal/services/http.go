@@ -0,0 +1,115 @@
+package services
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"fmt"
+	"net/http"
+	"os"
+	"time"
+
+	"github.com/tedkulp/tix/internal/version"
+)
+
+// caCertPool holds the system roots plus the certificates from ca_cert_path;
+// nil means only the system roots are trusted
+var caCertPool *x509.CertPool
+
+// SetCACertFile trusts the PEM certificates in path, in addition to the
+// system roots, for GitHub and GitLab API calls. An empty path goes back to
+// the system roots only.
+func SetCACertFile(path string) error {
+	if path == "" {
+		caCertPool = nil
+		return nil
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return fmt.Errorf("failed to read ca_cert_path %s: %w", path, err)
+	}
+
+	pool, err := x509.SystemCertPool()
+	if err != nil || pool == nil {
+		pool = x509.NewCertPool()
+	}
+	if !pool.AppendCertsFromPEM(data) {
+		return fmt.Errorf("ca_cert_path %s contains no valid PEM certificates", path)
+	}
+	caCertPool = pool
+	return nil
+}
+
+// userAgent is sent with every request and extraHeaders with GitHub and
+// GitLab requests; see SetRequestHeaders
+var (
+	userAgent    string
+	extraHeaders map[string]string
+)
+
+// SetRequestHeaders sets the User-Agent sent with GitHub, GitLab and webhook
+// requests, and the extra headers sent with GitHub and GitLab requests only,
+// so gateway headers never reach a third-party webhook. An empty userAgent
+// goes back to tix/<version>.
+func SetRequestHeaders(ua string, headers map[string]string) {
+	userAgent = ua
+	extraHeaders = headers
+}
+
+// headerTransport sets the configured User-Agent on each request, replacing
+// the defaults of the GitHub and GitLab clients, and the extra headers
+// unless userAgentOnly is set
+type headerTransport struct {
+	base          http.RoundTripper
+	userAgentOnly bool
+}
+
+func (t *headerTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	req = req.Clone(req.Context())
+	ua := userAgent
+	if ua == "" {
+		ua = "tix/" + version.Version
+	}
+	req.Header.Set("User-Agent", ua)
+	if !t.userAgentOnly {
+		for name, value := range extraHeaders {
+			req.Header.Set(name, value)
+		}
+	}
+	return t.base.RoundTrip(req)
+}
+
+// baseTransport returns a transport taking proxies from HTTPS_PROXY,
+// HTTP_PROXY and NO_PROXY and trusting the certificates from SetCACertFile
+func baseTransport() *http.Transport {
+	transport := http.DefaultTransport.(*http.Transport).Clone()
+	transport.Proxy = http.ProxyFromEnvironment
+	if caCertPool != nil {
+		transport.TLSClientConfig = &tls.Config{
+			RootCAs:    caCertPool,
+			MinVersion: tls.VersionTLS12,
+		}
+	}
+	return transport
+}
+
+// httpClient returns the HTTP client for provider API calls, on
+// baseTransport and carrying the headers from SetRequestHeaders. While dry runs are on, requests that would change
+// something are printed instead of sent (see SetDryRun).
+func httpClient() *http.Client {
+	return &http.Client{Transport: &headerTransport{base: &dryRunTransport{base: baseTransport()}}}
+}
+
+// CheckReachable reports whether the server at rawURL answers HTTP requests,
+// going through the same proxy and TLS settings as API calls. Any response,
+// whatever its status, counts as reachable.
+func CheckReachable(rawURL string) error {
+	client := httpClient()
+	client.Timeout = 10 * time.Second
+	resp, err := client.Head(rawURL)
+	if err != nil {
+		return err
+	}
+	resp.Body.Close()
+	return nil
+}
@@ -0,0 +1,101 @@
+package services
+
+import (
+	"encoding/pem"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/version"
+)
+
+func TestSetCACertFile(t *testing.T) {
+	t.Cleanup(func() { _ = SetCACertFile("") })
+
+	server := httptest.NewTLSServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusNoContent)
+	}))
+	defer server.Close()
+
+	// The test server's self-signed certificate is only trusted once loaded
+	if _, err := httpClient().Get(server.URL); err == nil {
+		t.Fatal("expected the test server's certificate to be rejected without ca_cert_path")
+	}
+
+	dir := t.TempDir()
+	certPath := filepath.Join(dir, "ca.pem")
+	certPEM := pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: server.Certificate().Raw})
+	if err := os.WriteFile(certPath, certPEM, 0600); err != nil {
+		t.Fatal(err)
+	}
+	if err := SetCACertFile(certPath); err != nil {
+		t.Fatalf("SetCACertFile() error: %v", err)
+	}
+	resp, err := httpClient().Get(server.URL)
+	if err != nil {
+		t.Fatalf("request with ca_cert_path error: %v", err)
+	}
+	resp.Body.Close()
+
+	badPath := filepath.Join(dir, "bad.pem")
+	if err := os.WriteFile(badPath, []byte("not a certificate"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	if err := SetCACertFile(badPath); err == nil || !strings.Contains(err.Error(), "no valid PEM certificates") {
+		t.Errorf("expected an invalid PEM error, got %v", err)
+	}
+	if err := SetCACertFile(filepath.Join(dir, "missing.pem")); err == nil || !strings.Contains(err.Error(), "failed to read ca_cert_path") {
+		t.Errorf("expected a read error, got %v", err)
+	}
+}
+
+func TestSetRequestHeaders(t *testing.T) {
+	t.Cleanup(func() { SetRequestHeaders("", nil) })
+
+	var got http.Header
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		got = r.Header.Clone()
+		w.WriteHeader(http.StatusNoContent)
+	}))
+	defer server.Close()
+
+	get := func() {
+		t.Helper()
+		resp, err := httpClient().Get(server.URL)
+		if err != nil {
+			t.Fatalf("request error: %v", err)
+		}
+		resp.Body.Close()
+	}
+
+	get()
+	if ua := got.Get("User-Agent"); ua != "tix/"+version.Version {
+		t.Errorf("default User-Agent = %q, want tix/%s", ua, version.Version)
+	}
+
+	SetRequestHeaders("acme-tix/1.0", map[string]string{"X-Gateway-Team": "platform"})
+	get()
+	if ua := got.Get("User-Agent"); ua != "acme-tix/1.0" {
+		t.Errorf("User-Agent = %q, want acme-tix/1.0", ua)
+	}
+	if team := got.Get("X-Gateway-Team"); team != "platform" {
+		t.Errorf("X-Gateway-Team = %q, want platform", team)
+	}
+}
+
+func TestCheckReachable(t *testing.T) {
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusUnauthorized)
+	}))
+	if err := CheckReachable(server.URL); err != nil {
+		t.Errorf("CheckReachable() error: %v, want any response to count as reachable", err)
+	}
+
+	server.Close()
+	if err := CheckReachable(server.URL); err == nil {
+		t.Error("expected an error once the server is gone")
+	}
+}
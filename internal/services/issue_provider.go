@@ -32,7 +32,12 @@ func NewGitLabIssueProvider(repo string) (*GitLabIssueProvider, error) {
 
 // CreateIssue implements the IssueProvider interface for GitLab
 func (p *GitLabIssueProvider) CreateIssue(title, labels string, selfAssign bool, milestone string) (*IssueCreationResult, error) {
-	issue, err := p.project.CreateIssue(title, labels, selfAssign, milestone)
+	issue, err := p.project.CreateIssue(IssueParams{
+		Title:          title,
+		Labels:         labels,
+		SelfAssign:     selfAssign,
+		MilestoneTitle: milestone,
+	})
 	if err != nil {
 		return nil, err
 	}
@@ -64,7 +69,11 @@ func NewGitHubIssueProvider(repo string) (*GitHubIssueProvider, error) {
 // CreateIssue implements the IssueProvider interface for GitHub
 // Note: GitHub doesn't use the milestone parameter
 func (p *GitHubIssueProvider) CreateIssue(title, labels string, selfAssign bool, milestone string) (*IssueCreationResult, error) {
-	issue, err := p.project.CreateIssue(title, labels, selfAssign)
+	issue, err := p.project.CreateIssue(IssueParams{
+		Title:      title,
+		Labels:     labels,
+		SelfAssign: selfAssign,
+	})
 	if err != nil {
 		return nil, err
 	}
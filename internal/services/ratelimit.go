@@ -0,0 +1,129 @@
+package services
+
+import (
+	"fmt"
+	"net/http"
+	"strconv"
+	"time"
+
+	"github.com/tedkulp/tix/internal/logger"
+)
+
+// MaxRateLimitWait is the longest --wait waits for GitHub's rate limit to
+// reset; a later reset fails straight away
+const MaxRateLimitWait = 5 * time.Minute
+
+// rateLimitWait is how long a GitHub request that hit the rate limit may
+// wait for it to reset before retrying; 0 fails straight away. See
+// SetRateLimitWait.
+var rateLimitWait time.Duration
+
+// SetRateLimitWait makes GitHub requests rejected by the rate limit wait up to
+// max for it to reset and then retry once. 0 turns waiting off.
+func SetRateLimitWait(max time.Duration) {
+	rateLimitWait = max
+}
+
+// RateLimitError reports that a GitHub request was rejected because the API
+// rate limit is used up
+type RateLimitError struct {
+	Reset     time.Time // When requests are allowed again
+	Secondary bool      // GitHub's secondary limit on bursts of requests rather than the hourly quota
+}
+
+func (e *RateLimitError) Error() string {
+	kind := "rate limit"
+	if e.Secondary {
+		kind = "secondary rate limit"
+	}
+	msg := fmt.Sprintf("GitHub %s exceeded; resets at %s", kind, e.Reset.Local().Format("15:04:05"))
+
+	wait := time.Until(e.Reset).Round(time.Second)
+	if wait > 0 {
+		msg += fmt.Sprintf(" (in %s)", wait)
+	}
+	if rateLimitWait == 0 && wait <= MaxRateLimitWait {
+		msg += " - rerun with --wait to wait for it"
+	}
+	return msg
+}
+
+// rateLimitReset reports whether resp is a rate limit rejection and, if so,
+// when the limit resets. The hourly quota is read from X-RateLimit-Remaining
+// and X-RateLimit-Reset; the secondary limit sends Retry-After instead.
+func rateLimitReset(resp *http.Response, now time.Time) (reset time.Time, secondary bool, limited bool) {
+	if resp.StatusCode != http.StatusForbidden && resp.StatusCode != http.StatusTooManyRequests {
+		return time.Time{}, false, false
+	}
+	if seconds, err := strconv.Atoi(resp.Header.Get("Retry-After")); err == nil {
+		return now.Add(time.Duration(seconds) * time.Second), true, true
+	}
+	if resp.Header.Get("X-RateLimit-Remaining") != "0" {
+		return time.Time{}, false, false
+	}
+	unix, err := strconv.ParseInt(resp.Header.Get("X-RateLimit-Reset"), 10, 64)
+	if err != nil {
+		return now, false, true
+	}
+	return time.Unix(unix, 0), false, true
+}
+
+// githubHTTPClient returns the HTTP client for GitHub API calls: httpClient,
+// with rate limit rejections handled by rateLimitTransport
+func githubHTTPClient() *http.Client {
+	client := httpClient()
+	client.Transport = &rateLimitTransport{base: client.Transport}
+	return client
+}
+
+// rateLimitTransport turns GitHub's rate limit rejections into a
+// RateLimitError, first waiting for the reset and retrying once when it is
+// within rateLimitWait
+type rateLimitTransport struct {
+	base http.RoundTripper
+}
+
+func (t *rateLimitTransport) RoundTrip(req *http.Request) (*http.Response, error) {
+	resp, err := t.base.RoundTrip(req)
+	if err != nil {
+		return resp, err
+	}
+	reset, secondary, limited := rateLimitReset(resp, time.Now())
+	if !limited {
+		return resp, nil
+	}
+	resp.Body.Close()
+
+	wait := time.Until(reset)
+	canRetry := req.Body == nil || req.GetBody != nil
+	if rateLimitWait == 0 || wait > rateLimitWait || !canRetry {
+		return nil, &RateLimitError{Reset: reset, Secondary: secondary}
+	}
+
+	logger.Warn("GitHub rate limit exceeded, waiting for it to reset", map[string]interface{}{
+		"wait": wait.Round(time.Second).String(),
+	})
+	timer := time.NewTimer(wait)
+	defer timer.Stop()
+	select {
+	case <-req.Context().Done():
+		return nil, req.Context().Err()
+	case <-timer.C:
+	}
+
+	retry := req.Clone(req.Context())
+	if req.GetBody != nil {
+		if retry.Body, err = req.GetBody(); err != nil {
+			return nil, err
+		}
+	}
+	resp, err = t.base.RoundTrip(retry)
+	if err != nil {
+		return resp, err
+	}
+	if reset, secondary, limited := rateLimitReset(resp, time.Now()); limited {
+		resp.Body.Close()
+		return nil, &RateLimitError{Reset: reset, Secondary: secondary}
+	}
+	return resp, nil
+}
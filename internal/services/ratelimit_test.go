@@ -0,0 +1,82 @@
+package services
+
+import (
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestRateLimitReset(t *testing.T) {
+	now := time.Unix(1700000000, 0)
+	tests := []struct {
+		name          string
+		status        int
+		headers       map[string]string
+		wantLimited   bool
+		wantSecondary bool
+		wantReset     time.Time
+	}{
+		{name: "quota used up", status: 403, headers: map[string]string{"X-RateLimit-Remaining": "0", "X-RateLimit-Reset": "1700000600"}, wantLimited: true, wantReset: time.Unix(1700000600, 0)},
+		{name: "429 quota", status: 429, headers: map[string]string{"X-RateLimit-Remaining": "0", "X-RateLimit-Reset": "1700000060"}, wantLimited: true, wantReset: time.Unix(1700000060, 0)},
+		{name: "secondary limit", status: 403, headers: map[string]string{"Retry-After": "30", "X-RateLimit-Remaining": "4000"}, wantLimited: true, wantSecondary: true, wantReset: now.Add(30 * time.Second)},
+		{name: "permission denied", status: 403, headers: map[string]string{"X-RateLimit-Remaining": "4000"}},
+		{name: "success", status: 200, headers: map[string]string{"X-RateLimit-Remaining": "0"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			resp := &http.Response{StatusCode: tt.status, Header: http.Header{}}
+			for name, value := range tt.headers {
+				resp.Header.Set(name, value)
+			}
+			reset, secondary, limited := rateLimitReset(resp, now)
+			if limited != tt.wantLimited || secondary != tt.wantSecondary || !reset.Equal(tt.wantReset) {
+				t.Errorf("rateLimitReset() = %v, %v, %v, want %v, %v, %v", reset, secondary, limited, tt.wantReset, tt.wantSecondary, tt.wantLimited)
+			}
+		})
+	}
+}
+
+func TestRateLimitTransport(t *testing.T) {
+	t.Cleanup(func() { SetRateLimitWait(0) })
+
+	hits := 0
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		hits++
+		if hits == 1 {
+			w.Header().Set("X-RateLimit-Remaining", "0")
+			w.Header().Set("X-RateLimit-Reset", strconv.FormatInt(time.Now().Unix(), 10))
+			w.WriteHeader(http.StatusForbidden)
+			return
+		}
+		w.WriteHeader(http.StatusNoContent)
+	}))
+	defer server.Close()
+	client := &http.Client{Transport: &rateLimitTransport{base: http.DefaultTransport}}
+
+	SetRateLimitWait(0)
+	_, err := client.Get(server.URL)
+	var rateErr *RateLimitError
+	if !errors.As(err, &rateErr) {
+		t.Fatalf("Get() error = %v, want a RateLimitError", err)
+	}
+	if !strings.Contains(rateErr.Error(), "GitHub rate limit exceeded; resets at") || !strings.Contains(rateErr.Error(), "--wait") {
+		t.Errorf("unexpected message: %s", rateErr.Error())
+	}
+
+	// With --wait the request waits for the reset and is retried
+	hits = 0
+	SetRateLimitWait(MaxRateLimitWait)
+	resp, err := client.Post(server.URL, "application/json", strings.NewReader(`{"title":"Fix login"}`))
+	if err != nil {
+		t.Fatalf("Post() with waiting error: %v", err)
+	}
+	resp.Body.Close()
+	if resp.StatusCode != http.StatusNoContent || hits != 2 {
+		t.Errorf("got status %d after %d requests, want the retry to succeed", resp.StatusCode, hits)
+	}
+}
@@ -3,7 +3,9 @@ package services
 import (
 	"fmt"
 	"strings"
+	"time"
 
+	"github.com/tedkulp/tix/internal/config"
 	"github.com/tedkulp/tix/internal/logger"
 )
 
@@ -27,7 +29,17 @@ type IssueParams struct {
 	Title          string
 	Labels         string
 	SelfAssign     bool
+	Assignees      []string // Usernames; "me"/"@me" means the authenticated user
 	MilestoneTitle string
+	GroupMilestone bool // GitLab only: MilestoneTitle is looked up in the project's groups only and never created
+	Body           string
+	QuickActions   []string          // GitLab only, e.g. "/label ~bug"; GitHub warns and drops them
+	Confidential   bool              // GitLab only; GitHub warns and creates a regular issue
+	Weight         *int              // GitLab only; on GitHub estimates are set as a label instead
+	Attachments    []string          // GitLab only: files uploaded and linked from the description; GitHub warns and drops them
+	Closed         bool              // Close the issue right after creating it, e.g. to backfill finished work
+	Lock           bool              // Lock the issue's conversation (GitHub) or discussion (GitLab) after creating it
+	LabelAliases   map[string]string // Lowercased canonical label -> this provider's name for it (label_aliases)
 }
 
 // SCMProvider represents a source code management system (GitHub, GitLab, etc.)
@@ -48,6 +60,10 @@ type SCMProvider interface {
 	// GetIssue returns an issue by its number
 	GetIssue(issueNumber int) (*IssueResult, error)
 
+	// ListIssues fetches open issues page by page, calling fn for each one as
+	// its page arrives, until opts.Limit issues have been seen
+	ListIssues(opts ListIssuesOptions, fn func(IssueResult)) error
+
 	// AddLabelsToIssue adds labels to an existing issue
 	AddLabelsToIssue(issueNumber int, labels []string) error
 
@@ -66,6 +82,15 @@ type SCMProvider interface {
 	GetCrossRepoIssueRef(issueNumber int) string
 }
 
+// ProjectBoardProvider is implemented by providers that can add issues to a
+// project board (GitHub Projects). GitLab issue boards are driven by labels,
+// so the GitLab provider doesn't implement it.
+type ProjectBoardProvider interface {
+	// AddIssueToProjectBoard adds an issue to the board with the given number
+	// or title and returns the board's title
+	AddIssueToProjectBoard(issueNumber int, project string) (string, error)
+}
+
 // RequestResult represents a merge/pull request result
 type RequestResult struct {
 	ID      int
@@ -75,13 +100,35 @@ type RequestResult struct {
 	Squash  bool
 }
 
+// ListIssuesOptions filters and caps the issues returned by ListIssues
+type ListIssuesOptions struct {
+	Limit int       // Maximum number of issues; 0 means no limit
+	Since time.Time // Only issues updated at or after this time; zero means no filter
+}
+
+// DefaultListLimit is the number of issues listed when no limit is given
+const DefaultListLimit = 30
+
+// maxPerPage is the largest page size both GitHub and GitLab accept
+const maxPerPage = 100
+
+// listPageSize returns the page size to request for a listing capped at limit
+func listPageSize(limit int) int {
+	if limit > 0 && limit < maxPerPage {
+		return limit
+	}
+	return maxPerPage
+}
+
 // IssueResult represents an issue from either system
 type IssueResult struct {
 	Number         int
+	ID             int // GitLab's instance-wide issue id, where Number is the project's iid; 0 for GitHub
 	Title          string
 	Labels         []string
 	MilestoneID    int
 	MilestoneTitle string
+	URL            string // Web URL of the issue, when the provider returns one
 }
 
 // GitPusher is the subset of git repository behavior that CreateMergeRequest
@@ -106,6 +153,8 @@ type CreateMergeRequestParams struct {
 	IssueProvider SCMProvider
 	// CrossRepoIssueRef is optional - if set, used in MR description instead of simple "#123"
 	CrossRepoIssueRef string
+	// CloseKeyword is optional - if set, used instead of "Closes" to link the issue
+	CloseKeyword string
 }
 
 // buildRequestTitle builds the merge/pull request title from the issue.
@@ -120,6 +169,186 @@ func buildRequestTitle(issueNumber int, crossRepoRef, issueTitle string) string
 	return fmt.Sprintf("%s: %s", issueRef, issueTitle)
 }
 
+// buildRequestDescription builds the merge/pull request description that links
+// the issue so it is closed on merge, e.g. "Fixes #225" or
+// "Closes group/project#225". An empty string is returned for the same-repo
+// default, leaving the provider to fill in "Closes #225".
+func buildRequestDescription(closeKeyword string, issueNumber int, crossRepoRef string) string {
+	keyword := closeKeyword
+	if keyword == "" {
+		keyword = "Closes"
+	}
+	if crossRepoRef != "" {
+		return fmt.Sprintf("%s %s", keyword, crossRepoRef)
+	}
+	if closeKeyword != "" {
+		return fmt.Sprintf("%s #%d", keyword, issueNumber)
+	}
+	return ""
+}
+
+// IsSelfAssignee reports whether an assignee is the "me"/"@me" shorthand for
+// the authenticated user
+func IsSelfAssignee(assignee string) bool {
+	a := strings.ToLower(strings.TrimSpace(assignee))
+	return a == "me" || a == "@me"
+}
+
+// issueAssignees merges SelfAssign and Assignees into a de-duplicated list of
+// usernames (without a leading "@"). The authenticated user is returned as "me".
+func issueAssignees(params IssueParams) []string {
+	var result []string
+	seen := map[string]bool{}
+	add := func(name string) {
+		key := strings.ToLower(name)
+		if name == "" || seen[key] {
+			return
+		}
+		seen[key] = true
+		result = append(result, name)
+	}
+
+	if params.SelfAssign {
+		add("me")
+	}
+	for _, assignee := range params.Assignees {
+		if IsSelfAssignee(assignee) {
+			add("me")
+			continue
+		}
+		add(strings.TrimPrefix(strings.TrimSpace(assignee), "@"))
+	}
+	return result
+}
+
+// IssueMover is implemented by providers that can move an issue to another
+// project (GitLab) or transfer it to another repository (GitHub)
+type IssueMover interface {
+	// MoveIssue moves the issue to target ("group/project" or "owner/repo")
+	// and returns the issue at its new location
+	MoveIssue(issueNumber int, target string) (*IssueResult, error)
+}
+
+// IssueChecker is implemented by providers that can validate an issue against
+// the API without creating it (tix create --check-remote)
+type IssueChecker interface {
+	// CheckIssue returns the problems found with params. An error means the
+	// check itself couldn't run, e.g. a rejected token or unknown project.
+	CheckIssue(params IssueParams) ([]string, error)
+}
+
+// UserResolver is implemented by providers that can report which user the
+// API token authenticates as (tix whoami)
+type UserResolver interface {
+	// CurrentUsername returns the username of the authenticated user
+	CurrentUsername() (string, error)
+}
+
+// IssueFinder is implemented by providers that can look for a recently
+// created issue by text in its description (idempotent_create)
+type IssueFinder interface {
+	// FindIssueByBody returns the most recent issue whose description
+	// contains text, or nil when none of the recent issues do
+	FindIssueByBody(text string) (*IssueResult, error)
+}
+
+// LabelCreator is implemented by providers that can create a label with a
+// chosen color before an issue uses it (label_color_map)
+type LabelCreator interface {
+	// LabelExists reports whether the project already has label
+	LabelExists(label string) (bool, error)
+	// CreateLabel creates label with color, a hex code such as "#ff0000"
+	CreateLabel(label, color string) error
+}
+
+// BranchProtection describes how a branch is protected on the provider
+type BranchProtection struct {
+	Protected   bool // The branch has protection rules
+	PushBlocked bool // The rules keep the authenticated user from pushing to it directly
+}
+
+// BranchProtectionChecker is implemented by providers that can report how a
+// branch is protected (check_branch_protection)
+type BranchProtectionChecker interface {
+	// BranchProtection returns the protection of branch
+	BranchProtection(branch string) (*BranchProtection, error)
+}
+
+// issueLabels splits the comma separated labels of params, dropping blanks,
+// and translates them with params.LabelAliases. Both providers use it so they
+// send identical label lists.
+func issueLabels(params IssueParams) []string {
+	return config.AliasLabels(config.SplitLabels(params.Labels), params.LabelAliases)
+}
+
+// issueCreateError turns an HTTP error status from an issue creation call into
+// guidance for the most common misconfigurations. Other statuses are returned
+// as a plain wrapped error.
+func issueCreateError(forge, project string, statusCode int, err error) error {
+	switch statusCode {
+	case 401:
+		return tokenRejectedError(forge)
+	case 403:
+		return fmt.Errorf("you don't have permission to create issues in %s", project)
+	case 404:
+		return fmt.Errorf("project %s not found on %s (or your token can't access it)", project, forge)
+	}
+	return fmt.Errorf("failed to create issue: %w", err)
+}
+
+// tokenRejectedError reports a 401 from the forge's API
+func tokenRejectedError(forge string) error {
+	return fmt.Errorf("%s rejected the API token - check your %s token", forge, strings.ToLower(forge))
+}
+
+// appendAttachments appends markdown references to uploaded files to body,
+// one per line
+func appendAttachments(body string, refs []string) string {
+	if len(refs) == 0 {
+		return body
+	}
+	if body == "" {
+		return strings.Join(refs, "\n")
+	}
+	return strings.TrimRight(body, "\n") + "\n\n" + strings.Join(refs, "\n")
+}
+
+// appendQuickActions adds GitLab quick actions to an issue description, one per
+// line after the body, so GitLab applies them server-side. A missing leading
+// "/" is added ("label ~bug" -> "/label ~bug").
+func appendQuickActions(body string, actions []string) string {
+	var lines []string
+	for _, action := range actions {
+		action = strings.TrimSpace(action)
+		if action == "" {
+			continue
+		}
+		if !strings.HasPrefix(action, "/") {
+			action = "/" + action
+		}
+		lines = append(lines, action)
+	}
+	if len(lines) == 0 {
+		return body
+	}
+	if body == "" {
+		return strings.Join(lines, "\n")
+	}
+	return strings.TrimRight(body, "\n") + "\n\n" + strings.Join(lines, "\n")
+}
+
+// hasQuickActions reports whether a description contains lines that GitLab
+// would treat as quick actions
+func hasQuickActions(body string) bool {
+	for _, line := range strings.Split(body, "\n") {
+		line = strings.TrimSpace(line)
+		if len(line) > 1 && line[0] == '/' && line[1] >= 'a' && line[1] <= 'z' {
+			return true
+		}
+	}
+	return false
+}
+
 // CreateMergeRequest contains the common flow for creating a merge/pull request
 func CreateMergeRequest(params CreateMergeRequestParams) (*RequestResult, error) {
 	// Check if there's already an open request for this issue
@@ -183,10 +412,7 @@ func CreateMergeRequest(params CreateMergeRequestParams) (*RequestResult, error)
 
 	// Create request params
 	// Use cross-repo reference if provided, otherwise use default format
-	description := ""
-	if params.CrossRepoIssueRef != "" {
-		description = fmt.Sprintf("Closes %s", params.CrossRepoIssueRef)
-	}
+	description := buildRequestDescription(params.CloseKeyword, params.IssueNumber, params.CrossRepoIssueRef)
 
 	mrParams := MergeRequestParams{
 		Title:              requestTitle,
@@ -2,6 +2,7 @@ package services
 
 import (
 	"errors"
+	"reflect"
 	"testing"
 )
 
@@ -60,6 +61,130 @@ func TestBuildRequestTitle(t *testing.T) {
 	}
 }
 
+func TestBuildRequestDescription(t *testing.T) {
+	tests := []struct {
+		name         string
+		closeKeyword string
+		issueNumber  int
+		crossRepoRef string
+		want         string
+	}{
+		{
+			name:        "same repo default leaves description to the provider",
+			issueNumber: 225,
+			want:        "",
+		},
+		{
+			name:         "same repo with configured keyword",
+			closeKeyword: "Fixes",
+			issueNumber:  225,
+			want:         "Fixes #225",
+		},
+		{
+			name:         "cross repo defaults to Closes",
+			issueNumber:  225,
+			crossRepoRef: "group/project#225",
+			want:         "Closes group/project#225",
+		},
+		{
+			name:         "cross repo with configured keyword",
+			closeKeyword: "Resolves",
+			issueNumber:  225,
+			crossRepoRef: "owner/repo#225",
+			want:         "Resolves owner/repo#225",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := buildRequestDescription(tt.closeKeyword, tt.issueNumber, tt.crossRepoRef)
+			if got != tt.want {
+				t.Errorf("buildRequestDescription(%q, %d, %q) = %q, want %q",
+					tt.closeKeyword, tt.issueNumber, tt.crossRepoRef, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestIssueAssignees(t *testing.T) {
+	tests := []struct {
+		name   string
+		params IssueParams
+		want   []string
+	}{
+		{name: "nobody", params: IssueParams{}, want: nil},
+		{name: "self assign", params: IssueParams{SelfAssign: true}, want: []string{"me"}},
+		{name: "me shorthand", params: IssueParams{Assignees: []string{"@me"}}, want: []string{"me"}},
+		{name: "self assign and me deduplicated", params: IssueParams{SelfAssign: true, Assignees: []string{"ME"}}, want: []string{"me"}},
+		{name: "usernames", params: IssueParams{Assignees: []string{"@alice", "bob", "Alice"}}, want: []string{"alice", "bob"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := issueAssignees(tt.params)
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("issueAssignees() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestIssueCreateError(t *testing.T) {
+	apiErr := errors.New("api error")
+	tests := []struct {
+		name       string
+		forge      string
+		statusCode int
+		want       string
+	}{
+		{name: "unauthorized", forge: "GitLab", statusCode: 401, want: "GitLab rejected the API token - check your gitlab token"},
+		{name: "forbidden", forge: "GitHub", statusCode: 403, want: "you don't have permission to create issues in group/project"},
+		{name: "not found", forge: "GitLab", statusCode: 404, want: "project group/project not found on GitLab (or your token can't access it)"},
+		{name: "other status", forge: "GitHub", statusCode: 500, want: "failed to create issue: api error"},
+		{name: "no response", forge: "GitHub", statusCode: 0, want: "failed to create issue: api error"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := issueCreateError(tt.forge, "group/project", tt.statusCode, apiErr)
+			if got.Error() != tt.want {
+				t.Errorf("issueCreateError() = %q, want %q", got.Error(), tt.want)
+			}
+		})
+	}
+}
+
+func TestAppendQuickActions(t *testing.T) {
+	tests := []struct {
+		name    string
+		body    string
+		actions []string
+		want    string
+	}{
+		{name: "no actions", body: "Details", want: "Details"},
+		{name: "actions only", actions: []string{"/assign @me", "label ~bug"}, want: "/assign @me\n/label ~bug"},
+		{name: "body and actions", body: "Details\n", actions: []string{"/milestone %Sprint"}, want: "Details\n\n/milestone %Sprint"},
+		{name: "blank actions skipped", body: "Details", actions: []string{"  "}, want: "Details"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := appendQuickActions(tt.body, tt.actions); got != tt.want {
+				t.Errorf("appendQuickActions() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestHasQuickActions(t *testing.T) {
+	if !hasQuickActions("Some text\n/label ~bug") {
+		t.Error("expected quick action to be detected")
+	}
+	if hasQuickActions("See /usr/bin for details\n// not an action") {
+		t.Error("expected no quick actions")
+	}
+}
+
 func TestCreateMergeRequest_SameRepo(t *testing.T) {
 	pusher := &fakePusher{}
 	provider := &mockSCMProvider{
@@ -233,3 +358,29 @@ func TestOpenURL_NotImplemented(t *testing.T) {
 		t.Error("expected OpenURL to return an error in this context")
 	}
 }
+
+func TestListPageSize(t *testing.T) {
+	tests := map[int]int{0: maxPerPage, 10: 10, maxPerPage: maxPerPage, 250: maxPerPage}
+	for limit, want := range tests {
+		if got := listPageSize(limit); got != want {
+			t.Errorf("listPageSize(%d) = %d, want %d", limit, got, want)
+		}
+	}
+}
+
+func TestIssueLabels(t *testing.T) {
+	got := issueLabels(IssueParams{Labels: " bug, ,enhancement ,"})
+	want := []string{"bug", "enhancement"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("issueLabels() = %v, want %v", got, want)
+	}
+	if got := issueLabels(IssueParams{}); got != nil {
+		t.Errorf("issueLabels() with no labels = %v, want nil", got)
+	}
+
+	got = issueLabels(IssueParams{Labels: "Bug,docs,defect", LabelAliases: map[string]string{"bug": "type::bug", "defect": "type::bug"}})
+	want = []string{"type::bug", "docs"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("issueLabels() with aliases = %v, want %v", got, want)
+	}
+}
@@ -42,7 +42,10 @@ func (m *mockSCMProvider) AddLabelsToIssue(_ int, _ []string) error      { retur
 func (m *mockSCMProvider) RemoveLabelsFromIssue(_ int, _ []string) error { return nil }
 func (m *mockSCMProvider) UpdateIssueStatus(_ int, _ string) error       { return nil }
 func (m *mockSCMProvider) GetURL() string                                { return "" }
-func (m *mockSCMProvider) GetCrossRepoIssueRef(_ int) string             { return m.crossRepoRef }
+func (m *mockSCMProvider) ListIssues(_ ListIssuesOptions, _ func(IssueResult)) error {
+	return nil
+}
+func (m *mockSCMProvider) GetCrossRepoIssueRef(_ int) string { return m.crossRepoRef }
 
 func TestGetWorkflowStatus_NoMR(t *testing.T) {
 	provider := &mockSCMProvider{
@@ -0,0 +1,76 @@
+package services
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"time"
+)
+
+// webhookTimeout bounds a notification so a slow endpoint can't hold up the command
+const webhookTimeout = 10 * time.Second
+
+// webhookClient returns the HTTP client for notify_webhook calls. It sends
+// only the User-Agent from SetRequestHeaders, since webhooks usually go to a
+// third party such as Slack.
+func webhookClient() *http.Client {
+	return &http.Client{
+		Transport: &headerTransport{base: baseTransport(), userAgentOnly: true},
+		Timeout:   webhookTimeout,
+	}
+}
+
+// IssueCreatedPayload is the JSON body POSTed to notify_webhook after an
+// issue is created. Text and Content carry a ready-made message for Slack
+// and Teams ("text") and Discord ("content") incoming webhooks.
+type IssueCreatedPayload struct {
+	Event       string `json:"event"` // Always "issue_created"
+	Repo        string `json:"repo"`  // Config name of the issue repository
+	Provider    string `json:"provider"`
+	IssueNumber int    `json:"issue_number"`
+	Title       string `json:"title"`
+	URL         string `json:"url,omitempty"`
+	Branch      string `json:"branch,omitempty"`
+	Text        string `json:"text"`
+	Content     string `json:"content"`
+}
+
+// NewIssueCreatedPayload builds the notification for a created issue
+func NewIssueCreatedPayload(repo, provider string, issueNumber int, title, url, branch string) IssueCreatedPayload {
+	message := fmt.Sprintf("Created issue #%d in %s: %s", issueNumber, repo, title)
+	if url != "" {
+		message += " " + url
+	}
+	return IssueCreatedPayload{
+		Event:       "issue_created",
+		Repo:        repo,
+		Provider:    provider,
+		IssueNumber: issueNumber,
+		Title:       title,
+		URL:         url,
+		Branch:      branch,
+		Text:        message,
+		Content:     message,
+	}
+}
+
+// PostWebhook POSTs payload as JSON to url. Any response other than 2xx is
+// an error.
+func PostWebhook(url string, payload any) error {
+	body, err := json.Marshal(payload)
+	if err != nil {
+		return fmt.Errorf("failed to encode webhook payload: %w", err)
+	}
+
+	resp, err := webhookClient().Post(url, "application/json", bytes.NewReader(body))
+	if err != nil {
+		return fmt.Errorf("failed to send webhook: %w", err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	if resp.StatusCode < http.StatusOK || resp.StatusCode >= http.StatusMultipleChoices {
+		return fmt.Errorf("webhook returned %s", resp.Status)
+	}
+	return nil
+}
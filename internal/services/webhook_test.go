@@ -0,0 +1,62 @@
+package services
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestPostWebhook(t *testing.T) {
+	var got IssueCreatedPayload
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if ct := r.Header.Get("Content-Type"); ct != "application/json" {
+			t.Errorf("Content-Type = %q, want application/json", ct)
+		}
+		if err := json.NewDecoder(r.Body).Decode(&got); err != nil {
+			t.Errorf("failed to decode payload: %v", err)
+		}
+		if got.Title == "fail" {
+			w.WriteHeader(http.StatusBadRequest)
+		}
+	}))
+	defer srv.Close()
+
+	payload := NewIssueCreatedPayload("backend", "github", 42, "Add export", "https://github.com/acme/backend/issues/42", "42-add-export")
+	if err := PostWebhook(srv.URL, payload); err != nil {
+		t.Fatalf("PostWebhook() error: %v", err)
+	}
+	if got != payload {
+		t.Errorf("webhook received %+v, want %+v", got, payload)
+	}
+	if want := "Created issue #42 in backend: Add export https://github.com/acme/backend/issues/42"; got.Text != want {
+		t.Errorf("Text = %q, want %q", got.Text, want)
+	}
+
+	err := PostWebhook(srv.URL, NewIssueCreatedPayload("backend", "github", 1, "fail", "", ""))
+	if err == nil || !strings.Contains(err.Error(), "400") {
+		t.Errorf("expected an error for a 400 response, got %v", err)
+	}
+}
+
+func TestPostWebhookLeavesOutHTTPHeaders(t *testing.T) {
+	t.Cleanup(func() { SetRequestHeaders("", nil) })
+	SetRequestHeaders("acme-tix/1.0", map[string]string{"X-Gateway-Team": "platform"})
+
+	var got http.Header
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		got = r.Header.Clone()
+	}))
+	defer srv.Close()
+
+	if err := PostWebhook(srv.URL, NewIssueCreatedPayload("backend", "github", 42, "Add export", "", "")); err != nil {
+		t.Fatalf("PostWebhook() error: %v", err)
+	}
+	if ua := got.Get("User-Agent"); ua != "acme-tix/1.0" {
+		t.Errorf("User-Agent = %q, want acme-tix/1.0", ua)
+	}
+	if team := got.Get("X-Gateway-Team"); team != "" {
+		t.Errorf("X-Gateway-Team = %q, want http_headers left out of webhook requests", team)
+	}
+}
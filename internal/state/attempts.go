@@ -0,0 +1,115 @@
+package state
+
+import (
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// AttemptTTL is how long an unconfirmed create attempt is kept. A retry after
+// that files a new issue instead of looking for the old one.
+const AttemptTTL = 24 * time.Hour
+
+// CreateAttempt is an issue creation whose request was sent but whose
+// response never arrived, kept with idempotent_create so a retry can look for
+// the issue before filing it again
+type CreateAttempt struct {
+	Key       string    `json:"key"`  // Idempotency key hidden in the issue description
+	Repo      string    `json:"repo"` // Config name of the repository the issue is filed in
+	Title     string    `json:"title"`
+	CreatedAt time.Time `json:"created_at"`
+}
+
+// NewAttemptKey returns a random idempotency key for a create attempt
+func NewAttemptKey() (string, error) {
+	b := make([]byte, 8)
+	if _, err := rand.Read(b); err != nil {
+		return "", fmt.Errorf("failed to generate idempotency key: %w", err)
+	}
+	return hex.EncodeToString(b), nil
+}
+
+// AttemptsPath returns the path of the create attempt file
+func AttemptsPath() (string, error) {
+	dir, err := Dir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "attempts.json"), nil
+}
+
+// LoadAttempts returns the recorded create attempts that are younger than
+// AttemptTTL (empty if there are none)
+func LoadAttempts() ([]CreateAttempt, error) {
+	path, err := AttemptsPath()
+	if err != nil {
+		return nil, err
+	}
+
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return []CreateAttempt{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read create attempts: %w", err)
+	}
+
+	var attempts []CreateAttempt
+	if err := json.Unmarshal(data, &attempts); err != nil {
+		return nil, fmt.Errorf("failed to parse create attempts in %s: %w", path, err)
+	}
+
+	fresh := attempts[:0]
+	for _, attempt := range attempts {
+		if time.Since(attempt.CreatedAt) < AttemptTTL {
+			fresh = append(fresh, attempt)
+		}
+	}
+	return fresh, nil
+}
+
+// SaveAttempts replaces the recorded create attempts
+func SaveAttempts(attempts []CreateAttempt) error {
+	path, err := AttemptsPath()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0700); err != nil {
+		return fmt.Errorf("failed to create state directory: %w", err)
+	}
+
+	data, err := json.MarshalIndent(attempts, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode create attempts: %w", err)
+	}
+	if err := os.WriteFile(path, data, 0600); err != nil {
+		return fmt.Errorf("failed to write create attempts: %w", err)
+	}
+	return nil
+}
+
+// FindAttempt returns the recorded attempt to create title in repo, if any
+func FindAttempt(attempts []CreateAttempt, repo, title string) *CreateAttempt {
+	for i := range attempts {
+		if attempts[i].Repo == repo && attempts[i].Title == title {
+			return &attempts[i]
+		}
+	}
+	return nil
+}
+
+// RemoveAttempt returns attempts without the one with key
+func RemoveAttempt(attempts []CreateAttempt, key string) []CreateAttempt {
+	kept := make([]CreateAttempt, 0, len(attempts))
+	for _, attempt := range attempts {
+		if attempt.Key != key {
+			kept = append(kept, attempt)
+		}
+	}
+	return kept
+}
@@ -0,0 +1,50 @@
+package state
+
+import (
+	"testing"
+	"time"
+)
+
+func TestAttemptsRoundTrip(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+
+	attempts, err := LoadAttempts()
+	if err != nil {
+		t.Fatalf("LoadAttempts() on empty state error: %v", err)
+	}
+	if len(attempts) != 0 {
+		t.Fatalf("expected no attempts, got %d", len(attempts))
+	}
+
+	key, err := NewAttemptKey()
+	if err != nil {
+		t.Fatalf("NewAttemptKey() error: %v", err)
+	}
+	if len(key) != 16 {
+		t.Errorf("NewAttemptKey() = %q, want 16 hex characters", key)
+	}
+
+	recent := CreateAttempt{Key: key, Repo: "app", Title: "Add export", CreatedAt: time.Now()}
+	expired := CreateAttempt{Key: "old", Repo: "app", Title: "Fix login", CreatedAt: time.Now().Add(-AttemptTTL - time.Minute)}
+	if err := SaveAttempts([]CreateAttempt{recent, expired}); err != nil {
+		t.Fatalf("SaveAttempts() error: %v", err)
+	}
+
+	attempts, err = LoadAttempts()
+	if err != nil {
+		t.Fatalf("LoadAttempts() error: %v", err)
+	}
+	if len(attempts) != 1 || attempts[0].Key != key {
+		t.Fatalf("expected only the recent attempt, got %+v", attempts)
+	}
+
+	if got := FindAttempt(attempts, "app", "Add export"); got == nil || got.Key != key {
+		t.Errorf("FindAttempt() = %+v, want the recent attempt", got)
+	}
+	if got := FindAttempt(attempts, "other", "Add export"); got != nil {
+		t.Errorf("FindAttempt() in another repo = %+v, want nil", got)
+	}
+	if got := RemoveAttempt(attempts, key); len(got) != 0 {
+		t.Errorf("RemoveAttempt() = %+v, want none left", got)
+	}
+}
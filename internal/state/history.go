@@ -0,0 +1,88 @@
+package state
+
+import (
+	"bufio"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// HistoryEntry records an issue created by tix, so branches and worktrees can
+// be traced back to their tickets
+type HistoryEntry struct {
+	CreatedAt   time.Time `json:"created_at"`
+	Repo        string    `json:"repo"`                // Config name of the repository the issue is in
+	CodeRepo    string    `json:"code_repo,omitempty"` // Config name of the repository the branch is in
+	Provider    string    `json:"provider"`            // "github" or "gitlab"
+	IssueNumber int       `json:"issue_number"`
+	Title       string    `json:"title"`
+	URL         string    `json:"url,omitempty"`
+	Branch      string    `json:"branch,omitempty"`   // Empty for tix create --no-branch
+	Worktree    string    `json:"worktree,omitempty"` // Worktree directory, when one was created
+}
+
+// HistoryPath returns the default path of the history log
+func HistoryPath() (string, error) {
+	dir, err := Dir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "history.jsonl"), nil
+}
+
+// AppendHistory adds an entry to the end of the history log at path, one
+// JSON object per line
+func AppendHistory(path string, entry HistoryEntry) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0700); err != nil {
+		return fmt.Errorf("failed to create history directory: %w", err)
+	}
+
+	data, err := json.Marshal(entry)
+	if err != nil {
+		return fmt.Errorf("failed to encode history entry: %w", err)
+	}
+
+	f, err := os.OpenFile(path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0600)
+	if err != nil {
+		return fmt.Errorf("failed to open history log: %w", err)
+	}
+	defer f.Close()
+
+	if _, err := f.Write(append(data, '\n')); err != nil {
+		return fmt.Errorf("failed to write history log: %w", err)
+	}
+	return nil
+}
+
+// LoadHistory returns the entries of the history log at path, oldest first
+// (empty if there is no log yet)
+func LoadHistory(path string) ([]HistoryEntry, error) {
+	f, err := os.Open(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return []HistoryEntry{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read history log: %w", err)
+	}
+	defer f.Close()
+
+	entries := []HistoryEntry{}
+	scanner := bufio.NewScanner(f)
+	for line := 1; scanner.Scan(); line++ {
+		if len(scanner.Bytes()) == 0 {
+			continue
+		}
+		var entry HistoryEntry
+		if err := json.Unmarshal(scanner.Bytes(), &entry); err != nil {
+			return nil, fmt.Errorf("failed to parse history log %s line %d: %w", path, line, err)
+		}
+		entries = append(entries, entry)
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, fmt.Errorf("failed to read history log: %w", err)
+	}
+	return entries, nil
+}
@@ -0,0 +1,55 @@
+package state
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestHistoryRoundTrip(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "nested", "history.jsonl")
+
+	entries, err := LoadHistory(path)
+	if err != nil {
+		t.Fatalf("LoadHistory() without a log error: %v", err)
+	}
+	if len(entries) != 0 {
+		t.Fatalf("expected no entries, got %d", len(entries))
+	}
+
+	first := HistoryEntry{CreatedAt: time.Now().UTC(), Repo: "app", Provider: "github", IssueNumber: 12, Title: "Add export", Branch: "12-add-export"}
+	second := HistoryEntry{Repo: "issues", CodeRepo: "app", Provider: "gitlab", IssueNumber: 7, Title: "Fix login"}
+	for _, entry := range []HistoryEntry{first, second} {
+		if err := AppendHistory(path, entry); err != nil {
+			t.Fatalf("AppendHistory() error: %v", err)
+		}
+	}
+
+	entries, err = LoadHistory(path)
+	if err != nil {
+		t.Fatalf("LoadHistory() error: %v", err)
+	}
+	if len(entries) != 2 || entries[0].Branch != first.Branch || entries[1].IssueNumber != 7 {
+		t.Errorf("history entries not preserved: %+v", entries)
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if lines := strings.Count(string(data), "\n"); lines != 2 {
+		t.Errorf("expected one line per entry, got %d lines", lines)
+	}
+}
+
+func TestLoadHistoryInvalidLine(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "history.jsonl")
+	if err := os.WriteFile(path, []byte("{\"repo\":\"app\"}\nnot json\n"), 0600); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := LoadHistory(path); err == nil || !strings.Contains(err.Error(), "line 2") {
+		t.Errorf("expected a parse error for line 2, got %v", err)
+	}
+}
@@ -0,0 +1,102 @@
+package state
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// PendingIssue is an issue recorded by `tix create --offline`, waiting for
+// `tix sync` to file it and rename its placeholder branch
+type PendingIssue struct {
+	Repo         string   `json:"repo"`      // Config name of the repository the issue is filed in
+	CodeRepo     string   `json:"code_repo"` // Config name of the repository the branch lives in
+	Title        string   `json:"title"`
+	Labels       string   `json:"labels,omitempty"`
+	Milestone    string   `json:"milestone,omitempty"`
+	SelfAssign   bool     `json:"self_assign"`
+	Assignees    []string `json:"assignees,omitempty"`
+	Project      string   `json:"project,omitempty"` // Project board to add the issue to
+	Body         string   `json:"body,omitempty"`
+	QuickActions []string `json:"quick_actions,omitempty"`
+	Confidential bool     `json:"confidential,omitempty"`
+	Weight       *int     `json:"weight,omitempty"`      // GitLab issue weight from --estimate
+	Attachments  []string `json:"attachments,omitempty"` // Absolute paths of --attach files, uploaded when the issue is filed
+	Closed       bool     `json:"closed,omitempty"`      // Close the issue once it's filed (--closed)
+	Locked       bool     `json:"locked,omitempty"`      // Lock the issue once it's filed (--lock)
+	Branch       string   `json:"branch"`                // Placeholder branch, e.g. tmp-20250102150405-add-export
+	// PlaceholderID is the issue ID used in the placeholder branch, e.g. tmp-20250102150405
+	PlaceholderID  string    `json:"placeholder_id"`
+	BranchType     string    `json:"branch_type,omitempty"`
+	CreatedAt      time.Time `json:"created_at"`
+	GroupMilestone bool      `json:"group_milestone,omitempty"` // Milestone is a GitLab group milestone from --group-milestone
+	// IssueNumber is set once the issue has been filed, so a sync that fails
+	// while renaming the branch doesn't file the issue twice
+	IssueNumber int `json:"issue_number,omitempty"`
+	// IssueID is GitLab's instance-wide id of the filed issue, for
+	// gitlab_branch_id: id
+	IssueID int `json:"issue_id,omitempty"`
+}
+
+// PendingPath returns the path of the pending issue queue file
+func PendingPath() (string, error) {
+	dir, err := Dir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "pending.json"), nil
+}
+
+// LoadPending returns the queued pending issues (empty if there are none)
+func LoadPending() ([]PendingIssue, error) {
+	path, err := PendingPath()
+	if err != nil {
+		return nil, err
+	}
+
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return []PendingIssue{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read pending issues: %w", err)
+	}
+
+	var pending []PendingIssue
+	if err := json.Unmarshal(data, &pending); err != nil {
+		return nil, fmt.Errorf("failed to parse pending issues in %s: %w", path, err)
+	}
+	return pending, nil
+}
+
+// SavePending replaces the pending issue queue
+func SavePending(pending []PendingIssue) error {
+	path, err := PendingPath()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0700); err != nil {
+		return fmt.Errorf("failed to create state directory: %w", err)
+	}
+
+	data, err := json.MarshalIndent(pending, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode pending issues: %w", err)
+	}
+	if err := os.WriteFile(path, data, 0600); err != nil {
+		return fmt.Errorf("failed to write pending issues: %w", err)
+	}
+	return nil
+}
+
+// AddPending appends an issue to the pending issue queue
+func AddPending(issue PendingIssue) error {
+	pending, err := LoadPending()
+	if err != nil {
+		return err
+	}
+	return SavePending(append(pending, issue))
+}
@@ -0,0 +1,52 @@
+package state
+
+import (
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestPendingRoundTrip(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+
+	pending, err := LoadPending()
+	if err != nil {
+		t.Fatalf("LoadPending() on empty state error: %v", err)
+	}
+	if len(pending) != 0 {
+		t.Fatalf("expected no pending issues, got %d", len(pending))
+	}
+
+	first := PendingIssue{Repo: "issues", CodeRepo: "app", Title: "Add export", Branch: "tmp-20250102150405-add-export", CreatedAt: time.Now().UTC()}
+	second := PendingIssue{Repo: "app", CodeRepo: "app", Title: "Fix login", Labels: "bug", Branch: "tmp-20250102150406-fix-login"}
+	if err := AddPending(first); err != nil {
+		t.Fatalf("AddPending() error: %v", err)
+	}
+	if err := AddPending(second); err != nil {
+		t.Fatalf("AddPending() error: %v", err)
+	}
+
+	pending, err = LoadPending()
+	if err != nil {
+		t.Fatalf("LoadPending() error: %v", err)
+	}
+	if len(pending) != 2 {
+		t.Fatalf("expected 2 pending issues, got %d", len(pending))
+	}
+	if pending[0].Branch != first.Branch || pending[1].Labels != "bug" {
+		t.Errorf("pending issues not preserved: %+v", pending)
+	}
+}
+
+func TestDirUsesXDGStateHome(t *testing.T) {
+	base := t.TempDir()
+	t.Setenv("XDG_STATE_HOME", base)
+
+	got, err := Dir()
+	if err != nil {
+		t.Fatalf("Dir() error: %v", err)
+	}
+	if want := filepath.Join(base, "tix"); got != want {
+		t.Errorf("Dir() = %q, want %q", got, want)
+	}
+}
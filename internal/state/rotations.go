@@ -0,0 +1,84 @@
+package state
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+)
+
+// RotationsPath returns the path of the file recording whose turn it is in
+// each assignee rotation
+func RotationsPath() (string, error) {
+	dir, err := Dir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(dir, "rotations.json"), nil
+}
+
+// LoadRotations returns how many issues each round-robin rotation has
+// assigned so far, by rotation name (empty if there are none)
+func LoadRotations() (map[string]int, error) {
+	path, err := RotationsPath()
+	if err != nil {
+		return nil, err
+	}
+
+	data, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return map[string]int{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read rotations: %w", err)
+	}
+
+	turns := map[string]int{}
+	if err := json.Unmarshal(data, &turns); err != nil {
+		return nil, fmt.Errorf("failed to parse rotations in %s: %w", path, err)
+	}
+	return turns, nil
+}
+
+// SaveRotations replaces the recorded rotation turns
+func SaveRotations(turns map[string]int) error {
+	path, err := RotationsPath()
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0700); err != nil {
+		return fmt.Errorf("failed to create state directory: %w", err)
+	}
+
+	data, err := json.MarshalIndent(turns, "", "  ")
+	if err != nil {
+		return fmt.Errorf("failed to encode rotations: %w", err)
+	}
+	if err := os.WriteFile(path, data, 0600); err != nil {
+		return fmt.Errorf("failed to write rotations: %w", err)
+	}
+	return nil
+}
+
+// NextInRotation returns the index of the member whose turn it is in the
+// named rotation of size members. With advance, the turn is recorded so the
+// next call moves on to the following member.
+func NextInRotation(name string, size int, advance bool) (int, error) {
+	if size <= 0 {
+		return 0, fmt.Errorf("rotation %s has no members", name)
+	}
+	turns, err := LoadRotations()
+	if err != nil {
+		return 0, err
+	}
+	index := turns[name] % size
+	if !advance {
+		return index, nil
+	}
+	turns[name] = index + 1
+	if err := SaveRotations(turns); err != nil {
+		return 0, err
+	}
+	return index, nil
+}
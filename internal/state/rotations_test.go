@@ -0,0 +1,37 @@
+package state
+
+import "testing"
+
+func TestNextInRotation(t *testing.T) {
+	t.Setenv("XDG_STATE_HOME", t.TempDir())
+
+	var got []int
+	for i := 0; i < 4; i++ {
+		index, err := NextInRotation("oncall", 3, true)
+		if err != nil {
+			t.Fatalf("NextInRotation() error: %v", err)
+		}
+		got = append(got, index)
+	}
+	if got[0] != 0 || got[1] != 1 || got[2] != 2 || got[3] != 0 {
+		t.Errorf("turns = %v, want 0 1 2 0", got)
+	}
+
+	// Peeking doesn't use up a turn, and other rotations keep their own
+	for i := 0; i < 2; i++ {
+		if index, err := NextInRotation("oncall", 3, false); err != nil || index != 1 {
+			t.Errorf("NextInRotation() without advancing = %d, %v, want 1", index, err)
+		}
+	}
+	if index, err := NextInRotation("triage", 2, true); err != nil || index != 0 {
+		t.Errorf("NextInRotation(triage) = %d, %v, want 0", index, err)
+	}
+
+	// A shrunk rotation wraps around instead of going past its end
+	if index, err := NextInRotation("oncall", 1, true); err != nil || index != 0 {
+		t.Errorf("NextInRotation() after shrinking = %d, %v, want 0", index, err)
+	}
+	if _, err := NextInRotation("empty", 0, true); err == nil {
+		t.Error("NextInRotation() with no members succeeded, want an error")
+	}
+}
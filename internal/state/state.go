@@ -0,0 +1,22 @@
+// Package state stores tix's local working data (such as issues waiting to
+// be filed) under the XDG state directory.
+package state
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+)
+
+// Dir returns the directory tix keeps its state in:
+// $XDG_STATE_HOME/tix, or ~/.local/state/tix when XDG_STATE_HOME is unset
+func Dir() (string, error) {
+	if dir := os.Getenv("XDG_STATE_HOME"); dir != "" {
+		return filepath.Join(dir, "tix"), nil
+	}
+	home, err := os.UserHomeDir()
+	if err != nil {
+		return "", fmt.Errorf("failed to determine home directory: %w", err)
+	}
+	return filepath.Join(home, ".local", "state", "tix"), nil
+}
@@ -0,0 +1,107 @@
+package utils
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strings"
+
+	"github.com/tedkulp/tix/internal/logger"
+)
+
+// clipboardCommands returns the candidate clipboard commands for the current
+// platform, in order of preference
+func clipboardCommands() [][]string {
+	switch runtime.GOOS {
+	case "darwin":
+		return [][]string{{"pbcopy"}}
+	case "windows":
+		return [][]string{{"clip"}}
+	default:
+		var cmds [][]string
+		if os.Getenv("WAYLAND_DISPLAY") != "" {
+			cmds = append(cmds, []string{"wl-copy"})
+		}
+		return append(cmds,
+			[]string{"xclip", "-selection", "clipboard"},
+			[]string{"xsel", "--clipboard", "--input"},
+		)
+	}
+}
+
+// CopyToClipboard copies text to the system clipboard. It returns an error
+// when no clipboard is available (e.g. headless sessions) so callers can warn
+// instead of failing.
+func CopyToClipboard(text string) error {
+	for _, args := range clipboardCommands() {
+		path, err := exec.LookPath(args[0])
+		if err != nil {
+			continue
+		}
+
+		cmd := exec.Command(path, args[1:]...)
+		cmd.Stdin = strings.NewReader(text)
+		if err := cmd.Run(); err != nil {
+			logger.Debug("Clipboard command failed", map[string]interface{}{
+				"command": args[0],
+				"error":   err.Error(),
+			})
+			continue
+		}
+		return nil
+	}
+
+	return fmt.Errorf("no clipboard available")
+}
+
+// pasteCommands returns the candidate commands that print the clipboard for
+// the current platform, in order of preference
+func pasteCommands() [][]string {
+	switch runtime.GOOS {
+	case "darwin":
+		return [][]string{{"pbpaste"}}
+	case "windows":
+		return [][]string{{"powershell", "-NoProfile", "-Command", "Get-Clipboard"}}
+	default:
+		var cmds [][]string
+		if os.Getenv("WAYLAND_DISPLAY") != "" {
+			cmds = append(cmds, []string{"wl-paste", "--no-newline"})
+		}
+		return append(cmds,
+			[]string{"xclip", "-selection", "clipboard", "-o"},
+			[]string{"xsel", "--clipboard", "--output"},
+		)
+	}
+}
+
+// ReadClipboard returns the text on the system clipboard. Headless sessions,
+// and systems without a clipboard tool, get an error saying what's missing.
+func ReadClipboard() (string, error) {
+	found := false
+	for _, args := range pasteCommands() {
+		path, err := exec.LookPath(args[0])
+		if err != nil {
+			continue
+		}
+		found = true
+
+		output, err := exec.Command(path, args[1:]...).Output()
+		if err != nil {
+			logger.Debug("Clipboard command failed", map[string]interface{}{
+				"command": args[0],
+				"error":   err.Error(),
+			})
+			continue
+		}
+		return string(output), nil
+	}
+
+	if runtime.GOOS != "darwin" && runtime.GOOS != "windows" && os.Getenv("DISPLAY") == "" && os.Getenv("WAYLAND_DISPLAY") == "" {
+		return "", fmt.Errorf("no clipboard available in a headless session (neither DISPLAY nor WAYLAND_DISPLAY is set)")
+	}
+	if !found {
+		return "", fmt.Errorf("no clipboard available - install wl-clipboard, xclip or xsel")
+	}
+	return "", fmt.Errorf("couldn't read the clipboard")
+}
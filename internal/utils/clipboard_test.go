@@ -0,0 +1,39 @@
+package utils
+
+import (
+	"runtime"
+	"strings"
+	"testing"
+)
+
+func TestClipboardCommandsPrefersWaylandWhenAvailable(t *testing.T) {
+	if runtime.GOOS == "darwin" || runtime.GOOS == "windows" {
+		t.Skip("Wayland detection only applies on Linux/BSD")
+	}
+
+	t.Setenv("WAYLAND_DISPLAY", "wayland-0")
+	cmds := clipboardCommands()
+	if len(cmds) == 0 || cmds[0][0] != "wl-copy" {
+		t.Errorf("expected wl-copy first under Wayland, got %v", cmds)
+	}
+
+	t.Setenv("WAYLAND_DISPLAY", "")
+	cmds = clipboardCommands()
+	if len(cmds) == 0 || cmds[0][0] != "xclip" {
+		t.Errorf("expected xclip first without Wayland, got %v", cmds)
+	}
+}
+
+func TestReadClipboardHeadless(t *testing.T) {
+	if runtime.GOOS == "darwin" || runtime.GOOS == "windows" {
+		t.Skip("headless sessions only apply on Linux/BSD")
+	}
+
+	t.Setenv("DISPLAY", "")
+	t.Setenv("WAYLAND_DISPLAY", "")
+	t.Setenv("PATH", t.TempDir())
+	_, err := ReadClipboard()
+	if err == nil || !strings.Contains(err.Error(), "headless") {
+		t.Errorf("ReadClipboard() error = %v, want a headless session error", err)
+	}
+}
@@ -1,9 +1,9 @@
 package utils
 
 import (
+	"errors"
 	"fmt"
 	"os"
-	"path/filepath"
 	"strings"
 
 	"github.com/pterm/pterm"
@@ -23,9 +23,199 @@ type SharedRepoInfo struct {
 	Branch      string
 }
 
+// LoadConfig loads the configuration and passes the API tokens it resolves
+// (explicit token > token file > environment variable) to the SCM clients
+func LoadConfig() (*config.Settings, error) {
+	cfg, err := config.Load()
+	if err != nil {
+		return nil, err
+	}
+	return prepareConfig(cfg)
+}
+
+// LoadOptionalConfig is LoadConfig for commands that can run without a
+// config file: a missing file gives empty settings, with API tokens from the
+// environment
+func LoadOptionalConfig() (*config.Settings, error) {
+	cfg, err := config.Load()
+	if errors.Is(err, config.ErrConfigNotFound) {
+		logger.Debug("No config file, continuing with empty settings", map[string]interface{}{
+			"error": err.Error(),
+		})
+		cfg, err = &config.Settings{}, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	return prepareConfig(cfg)
+}
+
+// prepareConfig applies loaded settings to the API clients and resolves the
+// repositories' remote slugs and providers
+func prepareConfig(cfg *config.Settings) (*config.Settings, error) {
+	githubToken, err := cfg.ResolveGithubToken()
+	if err != nil {
+		return nil, err
+	}
+	gitlabToken, err := cfg.ResolveGitlabToken()
+	if err != nil {
+		return nil, err
+	}
+	services.SetGithubToken(githubToken)
+	services.SetGitlabToken(gitlabToken)
+	if err := services.SetCACertFile(cfg.ResolveCACertPath()); err != nil {
+		return nil, err
+	}
+	services.SetRequestHeaders(cfg.UserAgent, cfg.HTTPHeaders)
+	resolveRemoteSlugs(cfg)
+	if err := applyProviderChoice(cfg); err != nil {
+		return nil, err
+	}
+
+	return cfg, nil
+}
+
+// providerFlag is the --provider value for repositories configured on both
+// GitHub and GitLab; see SetProvider
+var providerFlag string
+
+// SetProvider picks the provider (github or gitlab) that repositories
+// configured on both use for this run, taking precedence over
+// primary_provider. Single-provider repositories are unaffected.
+func SetProvider(provider string) error {
+	if provider == "" {
+		providerFlag = ""
+		return nil
+	}
+	provider, err := config.NormalizeProvider(provider)
+	if err != nil {
+		return fmt.Errorf("--provider: %w", err)
+	}
+	providerFlag = provider
+	return nil
+}
+
+// applyProviderChoice narrows each repository configured on both GitHub and
+// GitLab to the provider from --provider or primary_provider. Repositories
+// with neither stay mirrored until ChooseProvider asks.
+func applyProviderChoice(cfg *config.Settings) error {
+	for i := range cfg.Repositories {
+		repo := &cfg.Repositories[i]
+		if !repo.IsMirrored() {
+			continue
+		}
+		provider := providerFlag
+		if provider == "" {
+			var err error
+			if provider, err = cfg.ResolvePrimaryProvider(repo); err != nil {
+				return fmt.Errorf("primary_provider for %s: %w", repo.Name, err)
+			}
+		}
+		if provider != "" {
+			if err := repo.UseProvider(provider); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
+
+// ChooseProvider asks which provider to use for a repository configured on
+// both GitHub and GitLab when neither --provider nor primary_provider picked
+// one. Without prompts that's an error; other repositories are left as they
+// are.
+func ChooseProvider(repo *config.Repository, interactive bool) error {
+	if !repo.IsMirrored() {
+		return nil
+	}
+	if !interactive {
+		return fmt.Errorf("%s is on both GitHub and GitLab - pass --provider github|gitlab or set primary_provider", repo.Name)
+	}
+
+	github := fmt.Sprintf("GitHub (%s)", repo.GithubRepo)
+	gitlab := fmt.Sprintf("GitLab (%s)", repo.GitlabRepo)
+	selected, err := pterm.DefaultInteractiveSelect.
+		WithOptions([]string{github, gitlab}).
+		WithDefaultText(fmt.Sprintf("%s is on both GitHub and GitLab - which one should tix use?", repo.Name)).
+		Show()
+	if err != nil {
+		return fmt.Errorf("provider selection cancelled")
+	}
+	if selected == github {
+		return repo.UseProvider(config.ProviderGitHub)
+	}
+	return repo.UseProvider(config.ProviderGitLab)
+}
+
+// MatchProvider narrows a repository configured on both GitHub and GitLab to
+// the provider of other, the issue or code repository it's paired with, as
+// both have to be on the same one
+func MatchProvider(repo, other *config.Repository) error {
+	if repo == other || !repo.IsMirrored() || other.IsMirrored() {
+		return nil
+	}
+	return repo.UseProvider(other.Provider())
+}
+
+// ChoosePairedProvider settles the provider of repo and other, the issue and
+// code repositories of one command: a mirrored one takes the provider of the
+// other, and ChooseProvider asks about repo only when both are mirrored
+func ChoosePairedProvider(repo, other *config.Repository, interactive bool) error {
+	if err := MatchProvider(repo, other); err != nil {
+		return err
+	}
+	if err := ChooseProvider(repo, interactive); err != nil {
+		return err
+	}
+	return MatchProvider(other, repo)
+}
+
+// resolveRemoteSlugs replaces "@remote:<name>" github_repo and gitlab_repo
+// values with the project path of that remote in the repository's
+// directory. A slug that can't be resolved is left as it is with a warning,
+// so only the commands that use that repository fail.
+func resolveRemoteSlugs(cfg *config.Settings) {
+	for i := range cfg.Repositories {
+		repo := &cfg.Repositories[i]
+		for _, slug := range []*string{&repo.GithubRepo, &repo.GitlabRepo} {
+			remote, ok := config.RemoteAlias(*slug)
+			if !ok {
+				continue
+			}
+			path, err := remoteProjectPath(repo.Directory, remote)
+			if err != nil {
+				logger.Warn("Couldn't read the repository slug from its git remote", map[string]interface{}{
+					"repo":   repo.Name,
+					"remote": remote,
+					"error":  err.Error(),
+				})
+				continue
+			}
+			*slug = path
+		}
+	}
+}
+
+// remoteProjectPath returns the project path of remote in the git
+// repository at dir
+func remoteProjectPath(dir, remote string) (string, error) {
+	if dir == "" {
+		return "", fmt.Errorf("%s%s needs the repository's directory to be set", config.RemoteSlugPrefix, remote)
+	}
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		return "", err
+	}
+	remoteURL, err := gitRepo.RemoteURL(remote)
+	if err != nil {
+		return "", err
+	}
+	return git.RemoteProjectPath(remoteURL)
+}
+
 // SelectSharedRepository determines which repository to work with using shared logic
 func SelectSharedRepository() (*SharedRepoInfo, error) {
-	cfg, err := config.Load()
+	cfg, err := LoadConfig()
 	if err != nil {
 		return nil, fmt.Errorf("failed to load config: %w", err)
 	}
@@ -44,32 +234,9 @@ func SelectSharedRepository() (*SharedRepoInfo, error) {
 		"directory": wd,
 	})
 
-	// Find repo that matches the current directory, or the best candidate
-	// Only consider code repos (repos with directory)
-	var matchingRepo *config.Repository
-	var repoName string
-	var bestMatchLength = 0
-
+	// Find the code repo the current directory belongs to, if any
 	repoNames := cfg.GetRepoNames()
-	for i, repo := range cfg.Repositories {
-		if !repo.IsCodeRepo() {
-			continue
-		}
-		absRepoDir, err := filepath.Abs(repo.Directory)
-		if err != nil {
-			continue
-		}
-
-		// Check if current directory is within the repo directory
-		if strings.HasPrefix(wd, absRepoDir) {
-			// If we found a better match (longer path prefix), use it
-			if len(absRepoDir) > bestMatchLength {
-				matchingRepo = &cfg.Repositories[i]
-				repoName = cfg.GetRepoNames()[i]
-				bestMatchLength = len(absRepoDir)
-			}
-		}
-	}
+	matchingRepo, repoName := MatchRepository(cfg, wd)
 
 	// If we found a match, we'll offer it as the default option
 	if matchingRepo != nil {
@@ -126,7 +293,7 @@ func SelectSharedRepository() (*SharedRepoInfo, error) {
 	})
 
 	// Extract issue info from branch name (may include project name for cross-repo)
-	projectName, issueNumber, err := ExtractIssueInfo(currentBranch)
+	projectName, issueNumber, err := BranchIssueInfo(wd, currentBranch)
 	if err != nil {
 		return nil, fmt.Errorf("failed to extract issue number from branch '%s': %w", currentBranch, err)
 	}
@@ -149,6 +316,9 @@ func SelectSharedRepository() (*SharedRepoInfo, error) {
 		issueRepoName = projectName
 
 		// Validate providers match
+		if err := ChoosePairedProvider(selectedRepo, issueRepo, true); err != nil {
+			return nil, err
+		}
 		if (selectedRepo.GithubRepo != "" && issueRepo.GitlabRepo != "") ||
 			(selectedRepo.GitlabRepo != "" && issueRepo.GithubRepo != "") {
 			return nil, fmt.Errorf("issue repo '%s' and code repo '%s' must use the same provider", projectName, selectedRepoName)
@@ -160,6 +330,11 @@ func SelectSharedRepository() (*SharedRepoInfo, error) {
 		})
 	}
 
+	// The issue is in the code repo itself (a no-op when cross-repo settled it)
+	if err := ChooseProvider(selectedRepo, true); err != nil {
+		return nil, err
+	}
+
 	// Determine if this is GitLab or GitHub (use issue repo for provider)
 	isGitLab := issueRepo.GitlabRepo != ""
 
@@ -304,7 +479,12 @@ func HandleLabelAndStatusOperationWithUnready(operation LabelOperation, override
 	// Find and select repository
 	repoInfo, err := SelectSharedRepository()
 	if err != nil {
-		// Provide cleaner error messages for common setup issues
+		// Provide cleaner error messages for common setup issues; type errors
+		// in the config already name the bad settings
+		var decodeErr *config.DecodeError
+		if errors.As(err, &decodeErr) {
+			return decodeErr
+		}
 		if strings.Contains(err.Error(), "failed to load config") {
 			return fmt.Errorf("couldn't load configuration file. Run with --verbose for details")
 		}
@@ -318,7 +498,7 @@ func HandleLabelAndStatusOperationWithUnready(operation LabelOperation, override
 	}
 
 	// Load config to get label and status configuration
-	cfg, err := config.Load()
+	cfg, err := LoadConfig()
 	if err != nil {
 		return fmt.Errorf("failed to load config: %w", err)
 	}
@@ -0,0 +1,29 @@
+package utils
+
+import (
+	"reflect"
+	"runtime"
+	"testing"
+)
+
+func TestEditorCommand(t *testing.T) {
+	t.Setenv("VISUAL", "")
+	t.Setenv("EDITOR", "")
+	fallback := []string{"vi"}
+	if runtime.GOOS == "windows" {
+		fallback = []string{"notepad"}
+	}
+	if got := EditorCommand(); !reflect.DeepEqual(got, fallback) {
+		t.Errorf("EditorCommand() without VISUAL or EDITOR = %q, want %q", got, fallback)
+	}
+
+	t.Setenv("EDITOR", "code --wait")
+	if got := EditorCommand(); !reflect.DeepEqual(got, []string{"code", "--wait"}) {
+		t.Errorf("EditorCommand() with EDITOR = %q, want code --wait", got)
+	}
+
+	t.Setenv("VISUAL", "nvim")
+	if got := EditorCommand(); !reflect.DeepEqual(got, []string{"nvim"}) {
+		t.Errorf("EditorCommand() with VISUAL = %q, want VISUAL to win", got)
+	}
+}
@@ -0,0 +1,153 @@
+package utils
+
+import (
+	"path/filepath"
+	"strings"
+
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
+	"github.com/tedkulp/tix/internal/logger"
+)
+
+// MatchRepository returns the configured code repository dir belongs to: the
+// one whose directory or worktree base path contains it, the most specific
+// (longest) one winning, so a repository nested in another's tree is picked
+// over the outer one. Paths are compared with symlinks resolved. Repositories
+// tied on the same tree - sharing a directory, or a global worktree.path - are
+// told apart by the git repository dir is a checkout or worktree of, and then
+// by the project paths of its remotes; that check also finds worktrees
+// created outside the configured paths. It returns nil when no repository
+// matches or the match stays ambiguous, leaving the caller to prompt.
+func MatchRepository(cfg *config.Settings, dir string) (*config.Repository, string) {
+	dir = canonicalPath(dir)
+
+	var candidates []*config.Repository
+	bestLength := -1
+	for i := range cfg.Repositories {
+		repo := &cfg.Repositories[i]
+		if !repo.IsCodeRepo() {
+			continue
+		}
+		for _, root := range []string{repo.Directory, cfg.ResolveWorktreePath(repo)} {
+			root = canonicalPath(root)
+			if !isWithinDir(dir, root) || len(root) < bestLength {
+				continue
+			}
+			if len(root) > bestLength {
+				candidates, bestLength = nil, len(root)
+			}
+			if !containsRepo(candidates, repo) {
+				candidates = append(candidates, repo)
+			}
+		}
+	}
+
+	if len(candidates) != 1 {
+		candidates = sameCheckout(cfg, dir, candidates)
+	}
+	if len(candidates) > 1 {
+		candidates = matchingRemotes(dir, candidates)
+	}
+	if len(candidates) != 1 {
+		if len(candidates) > 1 {
+			logger.Debug("Directory matches several repositories", map[string]interface{}{
+				"directory": dir,
+				"count":     len(candidates),
+			})
+		}
+		return nil, ""
+	}
+	return candidates[0], candidates[0].Name
+}
+
+// sameCheckout narrows candidates (all code repositories when there are none)
+// to those whose directory is the git repository dir is in, or a worktree of
+// it. Candidates are returned unchanged when dir isn't in a git repository or
+// none of them is.
+func sameCheckout(cfg *config.Settings, dir string, candidates []*config.Repository) []*config.Repository {
+	common, err := git.CommonDir(dir)
+	if err != nil {
+		return candidates
+	}
+
+	pool := candidates
+	if len(pool) == 0 {
+		for i := range cfg.Repositories {
+			if cfg.Repositories[i].IsCodeRepo() {
+				pool = append(pool, &cfg.Repositories[i])
+			}
+		}
+	}
+	var matched []*config.Repository
+	for _, repo := range pool {
+		if repoCommon, err := git.CommonDir(repo.Directory); err == nil && repoCommon == common {
+			matched = append(matched, repo)
+		}
+	}
+	if len(matched) == 0 {
+		return candidates
+	}
+	return matched
+}
+
+// matchingRemotes narrows candidates to those whose github_repo or
+// gitlab_repo is the project path of one of the remotes of the git repository
+// at dir, returning them unchanged when none is
+func matchingRemotes(dir string, candidates []*config.Repository) []*config.Repository {
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		return candidates
+	}
+	remotes, err := gitRepo.Remotes()
+	if err != nil {
+		return candidates
+	}
+
+	paths := make(map[string]bool)
+	for _, remote := range remotes {
+		for _, url := range remote.Config().URLs {
+			if path, err := git.RemoteProjectPath(url); err == nil {
+				paths[strings.ToLower(path)] = true
+			}
+		}
+	}
+	var matched []*config.Repository
+	for _, repo := range candidates {
+		if paths[strings.ToLower(repo.GithubRepo)] || paths[strings.ToLower(repo.GitlabRepo)] {
+			matched = append(matched, repo)
+		}
+	}
+	if len(matched) == 0 {
+		return candidates
+	}
+	return matched
+}
+
+// canonicalPath returns the absolute form of path with symlinks resolved, or
+// just the absolute form when it doesn't exist
+func canonicalPath(path string) string {
+	abs, err := filepath.Abs(path)
+	if err != nil {
+		return filepath.Clean(path)
+	}
+	if resolved, err := filepath.EvalSymlinks(abs); err == nil {
+		return resolved
+	}
+	return abs
+}
+
+// isWithinDir reports whether path is root or inside it. Unlike a plain
+// prefix check, /src/app-old isn't within /src/app.
+func isWithinDir(path, root string) bool {
+	rel, err := filepath.Rel(root, path)
+	return err == nil && rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator))
+}
+
+func containsRepo(repos []*config.Repository, repo *config.Repository) bool {
+	for _, r := range repos {
+		if r == repo {
+			return true
+		}
+	}
+	return false
+}
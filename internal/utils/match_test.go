@@ -0,0 +1,88 @@
+package utils
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/tedkulp/tix/internal/config"
+)
+
+func runGit(t *testing.T, dir string, args ...string) {
+	t.Helper()
+	gitCmd := exec.Command("git", args...)
+	gitCmd.Dir = dir
+	if out, err := gitCmd.CombinedOutput(); err != nil {
+		t.Fatalf("git %v: %v\n%s", args, err, out)
+	}
+}
+
+func newGitRepo(t *testing.T, dir string) {
+	t.Helper()
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		t.Fatal(err)
+	}
+	runGit(t, dir, "init")
+	runGit(t, dir, "-c", "user.email=test@test.com", "-c", "user.name=Test", "commit", "--allow-empty", "-m", "initial commit")
+}
+
+func TestMatchRepository(t *testing.T) {
+	root := t.TempDir()
+	outer := filepath.Join(root, "outer")
+	inner := filepath.Join(outer, "vendor", "inner")
+	shared := filepath.Join(root, "shared")
+	other := filepath.Join(root, "other")
+	worktrees := filepath.Join(root, "worktrees")
+	newGitRepo(t, outer)
+	newGitRepo(t, inner)
+	newGitRepo(t, shared)
+	newGitRepo(t, other)
+	runGit(t, shared, "remote", "add", "origin", "git@github.com:acme/app.git")
+	runGit(t, other, "worktree", "add", "-b", "42-fix-login", filepath.Join(worktrees, "42-fix-login"))
+	for _, dir := range []string{filepath.Join(outer, "src"), filepath.Join(root, "outer-old")} {
+		if err := os.MkdirAll(dir, 0755); err != nil {
+			t.Fatal(err)
+		}
+	}
+	link := filepath.Join(root, "link")
+	if err := os.Symlink(outer, link); err != nil {
+		t.Fatal(err)
+	}
+
+	cfg := &config.Settings{
+		Worktree: config.WorktreeConfig{Path: worktrees},
+		Repositories: []config.Repository{
+			{Name: "outer", Directory: outer, GithubRepo: "acme/outer"},
+			{Name: "inner", Directory: inner, GithubRepo: "acme/inner"},
+			{Name: "issues", GithubRepo: "acme/issues"},
+			{Name: "app-gitlab", Directory: shared, GitlabRepo: "group/app"},
+			{Name: "app", Directory: shared, GithubRepo: "acme/app"},
+			{Name: "other", Directory: other, GithubRepo: "acme/other"},
+		},
+	}
+
+	tests := []struct {
+		name string
+		dir  string
+		want string
+	}{
+		{name: "repository directory", dir: outer, want: "outer"},
+		{name: "subdirectory", dir: filepath.Join(outer, "src"), want: "outer"},
+		{name: "nested repository wins", dir: inner, want: "inner"},
+		{name: "through a symlink", dir: filepath.Join(link, "src"), want: "outer"},
+		{name: "sibling with a common prefix", dir: filepath.Join(root, "outer-old"), want: ""},
+		{name: "shared directory told apart by remote", dir: shared, want: "app"},
+		{name: "worktree in a shared worktree path", dir: filepath.Join(worktrees, "42-fix-login"), want: "other"},
+		{name: "outside every repository", dir: root, want: ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			repo, name := MatchRepository(cfg, tt.dir)
+			if name != tt.want || (repo == nil) != (tt.want == "") {
+				t.Errorf("MatchRepository(%s) = %q, want %q", tt.dir, name, tt.want)
+			}
+		})
+	}
+}
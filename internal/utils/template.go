@@ -0,0 +1,381 @@
+package utils
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+
+	"github.com/tedkulp/tix/internal/config"
+	"gopkg.in/yaml.v3"
+)
+
+// Directories searched for issue templates given by bare file name
+const (
+	GithubIssueTemplateDir = ".github/ISSUE_TEMPLATE"
+	GitlabIssueTemplateDir = ".gitlab/issue_templates"
+)
+
+// LoadIssueTemplate reads an issue template from a repository checkout and
+// returns its body with any YAML front matter removed. A bare name (e.g.
+// "security.md") is looked up in the provider's template directory; a name
+// containing a slash is taken relative to repoDir.
+func LoadIssueTemplate(repoDir, name string, isGitLab bool) (string, error) {
+	path := name
+	if !strings.Contains(name, "/") {
+		templateDir := GithubIssueTemplateDir
+		if isGitLab {
+			templateDir = GitlabIssueTemplateDir
+		}
+		path = filepath.Join(templateDir, name)
+	}
+	if !filepath.IsAbs(path) {
+		path = filepath.Join(repoDir, path)
+	}
+
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return "", fmt.Errorf("failed to read issue template %s: %w", path, err)
+	}
+
+	return strings.TrimSpace(stripFrontMatter(string(data))), nil
+}
+
+// IssueTemplate is an issue template found in a repository checkout
+type IssueTemplate struct {
+	Name        string // File name, as passed to --template
+	Description string // The front matter's about or description, or else the first line of the body
+}
+
+// issueTemplateFrontMatter is the front matter of a GitHub issue template
+type issueTemplateFrontMatter struct {
+	Name        string `yaml:"name"`
+	About       string `yaml:"about"`
+	Description string `yaml:"description"`
+}
+
+// ListIssueTemplates returns the markdown issue templates in the provider's
+// template directory of repoDir, sorted by name. A missing directory means
+// there are none.
+func ListIssueTemplates(repoDir string, isGitLab bool) ([]IssueTemplate, error) {
+	templateDir := GithubIssueTemplateDir
+	if isGitLab {
+		templateDir = GitlabIssueTemplateDir
+	}
+	dir := filepath.Join(repoDir, templateDir)
+
+	entries, err := os.ReadDir(dir)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read issue templates in %s: %w", dir, err)
+	}
+
+	var templates []IssueTemplate
+	for _, entry := range entries {
+		if entry.IsDir() || !strings.EqualFold(filepath.Ext(entry.Name()), ".md") {
+			continue
+		}
+		data, err := os.ReadFile(filepath.Join(dir, entry.Name()))
+		if err != nil {
+			return nil, fmt.Errorf("failed to read issue template %s: %w", entry.Name(), err)
+		}
+		templates = append(templates, IssueTemplate{
+			Name:        entry.Name(),
+			Description: templateDescription(string(data)),
+		})
+	}
+	return templates, nil
+}
+
+// templateDescription returns a one-line description of an issue template:
+// its front matter's about (or description, or name), or else the first
+// non-empty line of its body without any heading marks
+func templateDescription(content string) string {
+	frontMatter, body := splitFrontMatter(content)
+	var meta issueTemplateFrontMatter
+	if frontMatter != "" && yaml.Unmarshal([]byte(frontMatter), &meta) == nil {
+		for _, text := range []string{meta.About, meta.Description, meta.Name} {
+			if text = strings.TrimSpace(text); text != "" {
+				return text
+			}
+		}
+	}
+	for _, line := range strings.Split(body, "\n") {
+		if line = strings.TrimSpace(strings.TrimLeft(line, "#")); line != "" {
+			return line
+		}
+	}
+	return ""
+}
+
+// templatePlaceholder matches a {{name}} placeholder, allowing spaces inside
+// the braces
+var templatePlaceholder = regexp.MustCompile(`\{\{\s*([A-Za-z0-9_.-]+)\s*\}\}`)
+
+// ParseTemplateVars parses --template-var values of the form name=value.
+// The value may contain spaces or further '=' signs.
+func ParseTemplateVars(pairs []string) (map[string]string, error) {
+	vars := make(map[string]string, len(pairs))
+	for _, pair := range pairs {
+		name, value, ok := strings.Cut(pair, "=")
+		name = strings.TrimSpace(name)
+		if !ok || !templatePlaceholder.MatchString("{{"+name+"}}") {
+			return nil, fmt.Errorf("invalid --template-var '%s' - use name=value", pair)
+		}
+		vars[name] = value
+	}
+	return vars, nil
+}
+
+// RenderTemplateVars replaces {{name}} placeholders in body with vars and
+// returns the result along with the names of placeholders left unfilled
+func RenderTemplateVars(body string, vars map[string]string) (string, []string) {
+	var missing []string
+	seen := make(map[string]bool)
+	rendered := templatePlaceholder.ReplaceAllStringFunc(body, func(placeholder string) string {
+		name := templatePlaceholder.FindStringSubmatch(placeholder)[1]
+		if value, ok := vars[name]; ok {
+			return value
+		}
+		if !seen[name] {
+			seen[name] = true
+			missing = append(missing, name)
+		}
+		return placeholder
+	})
+	return rendered, missing
+}
+
+// stripFrontMatter removes a leading "---" delimited YAML block, as used by
+// GitHub issue templates for their name/about/labels metadata
+func stripFrontMatter(s string) string {
+	_, body := splitFrontMatter(s)
+	return body
+}
+
+// splitFrontMatter separates a leading "---" delimited YAML block from the
+// rest of s. Without (terminated) front matter it returns "" and s unchanged.
+func splitFrontMatter(s string) (string, string) {
+	s = strings.TrimPrefix(s, "\ufeff")
+	if !strings.HasPrefix(s, "---\n") && !strings.HasPrefix(s, "---\r\n") {
+		return "", s
+	}
+
+	lines := strings.SplitAfter(s, "\n")
+	for i := 1; i < len(lines); i++ {
+		if strings.TrimRight(lines[i], "\r\n") == "---" {
+			return strings.Join(lines[1:i], ""), strings.Join(lines[i+1:], "")
+		}
+	}
+
+	// Unterminated front matter: leave the template as-is
+	return "", s
+}
+
+// IssueFile is an issue drafted in a markdown file for `tix create --from-file`
+type IssueFile struct {
+	Title     string
+	Labels    []string
+	Assignees []string
+	Body      string
+}
+
+// issueFileFrontMatter is the front matter read from an issue file; labels
+// and assignees may be a YAML list or a comma separated string
+type issueFileFrontMatter struct {
+	Title     string     `yaml:"title"`
+	Labels    stringList `yaml:"labels"`
+	Assignees stringList `yaml:"assignees"`
+}
+
+// stringList decodes a YAML list or a comma separated string
+type stringList []string
+
+func (l *stringList) UnmarshalYAML(value *yaml.Node) error {
+	if value.Kind == yaml.ScalarNode {
+		*l = config.NormalizeLabels(strings.Split(value.Value, ","))
+		return nil
+	}
+	var items []string
+	if err := value.Decode(&items); err != nil {
+		return err
+	}
+	*l = config.NormalizeLabels(items)
+	return nil
+}
+
+// markdownHeading matches an ATX heading line such as "# Add export"
+var markdownHeading = regexp.MustCompile(`^#{1,6}[ \t]+(.+?)[ \t#]*$`)
+
+// LoadIssueFile reads an issue drafted in markdown. The title comes from the
+// front-matter title or else the first heading, which is then left out of the
+// body; front-matter labels and assignees are returned as lists.
+func LoadIssueFile(path string) (*IssueFile, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read issue file %s: %w", path, err)
+	}
+
+	issue, err := ParseIssueFile(string(data))
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", path, err)
+	}
+	return issue, nil
+}
+
+// ParseIssueFile parses the contents of an issue file; see LoadIssueFile
+func ParseIssueFile(content string) (*IssueFile, error) {
+	frontMatter, body := splitFrontMatter(content)
+
+	var meta issueFileFrontMatter
+	if frontMatter != "" {
+		if err := yaml.Unmarshal([]byte(frontMatter), &meta); err != nil {
+			return nil, fmt.Errorf("invalid front matter: %w", err)
+		}
+	}
+
+	issue := &IssueFile{
+		Title:     strings.TrimSpace(meta.Title),
+		Labels:    meta.Labels,
+		Assignees: meta.Assignees,
+	}
+
+	if issue.Title == "" {
+		lines := strings.Split(body, "\n")
+		for i, line := range lines {
+			if match := markdownHeading.FindStringSubmatch(strings.TrimRight(line, "\r")); match != nil {
+				issue.Title = match[1]
+				body = strings.Join(append(lines[:i:i], lines[i+1:]...), "\n")
+				break
+			}
+		}
+	}
+	if issue.Title == "" {
+		return nil, fmt.Errorf("no issue title found - add a front-matter title or a '# Heading'")
+	}
+
+	issue.Body = strings.TrimSpace(body)
+	return issue, nil
+}
+
+// BatchIssue is one issue in a `tix create --batch` file
+type BatchIssue struct {
+	Title     string     `yaml:"title"`
+	Labels    stringList `yaml:"labels"`
+	Assignees stringList `yaml:"assignees"`
+	Body      string     `yaml:"body"`
+	Branch    bool       `yaml:"branch"` // Also create a branch for the issue
+}
+
+// UnmarshalYAML also accepts a bare string as an issue with only a title
+func (b *BatchIssue) UnmarshalYAML(value *yaml.Node) error {
+	if value.Kind == yaml.ScalarNode {
+		*b = BatchIssue{Title: value.Value}
+		return nil
+	}
+	type plain BatchIssue
+	return value.Decode((*plain)(b))
+}
+
+// LoadBatchFile reads the issues of a `tix create --batch` file; see
+// ParseBatchFile for the format
+func LoadBatchFile(path string) ([]BatchIssue, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read batch file %s: %w", path, err)
+	}
+
+	issues, err := ParseBatchFile(string(data))
+	if err != nil {
+		return nil, fmt.Errorf("%s: %w", path, err)
+	}
+	return issues, nil
+}
+
+// ParseBatchFile parses a batch file: either a YAML list of issues with
+// title, labels, assignees, body and branch keys (or just titles), or one
+// issue per line as
+// "title | labels | assignees", where labels and assignees are optional and
+// comma separated. Blank lines and lines starting with '#' are skipped.
+func ParseBatchFile(content string) ([]BatchIssue, error) {
+	var issues []BatchIssue
+	if strings.HasPrefix(strings.TrimSpace(stripComments(content)), "-") {
+		if err := yaml.Unmarshal([]byte(content), &issues); err != nil {
+			return nil, fmt.Errorf("invalid batch file: %w", err)
+		}
+		for i := range issues {
+			issues[i].Title = strings.TrimSpace(issues[i].Title)
+			if issues[i].Title == "" {
+				return nil, fmt.Errorf("issue %d has no title", i+1)
+			}
+		}
+	} else {
+		for _, line := range strings.Split(content, "\n") {
+			line = strings.TrimSpace(line)
+			if line == "" || strings.HasPrefix(line, "#") {
+				continue
+			}
+			fields := strings.SplitN(line, "|", 3)
+			issue := BatchIssue{Title: strings.TrimSpace(fields[0])}
+			if issue.Title == "" {
+				return nil, fmt.Errorf("line %q has no title", line)
+			}
+			if len(fields) > 1 {
+				issue.Labels = config.NormalizeLabels(strings.Split(fields[1], ","))
+			}
+			if len(fields) > 2 {
+				issue.Assignees = config.NormalizeLabels(strings.Split(fields[2], ","))
+			}
+			issues = append(issues, issue)
+		}
+	}
+
+	if len(issues) == 0 {
+		return nil, fmt.Errorf("no issues found")
+	}
+	return issues, nil
+}
+
+// stripComments drops the blank and '#' comment lines of content
+func stripComments(content string) string {
+	var lines []string
+	for _, line := range strings.Split(content, "\n") {
+		if trimmed := strings.TrimSpace(line); trimmed != "" && !strings.HasPrefix(trimmed, "#") {
+			lines = append(lines, line)
+		}
+	}
+	return strings.Join(lines, "\n")
+}
+
+// MaxAttachmentSize is the largest file --attach uploads, matching GitLab's
+// default maximum attachment size
+const MaxAttachmentSize = 100 << 20
+
+// ValidateAttachments checks that each path is a readable regular file no
+// larger than MaxAttachmentSize and returns the paths made absolute, so they
+// still resolve when `tix sync` files an offline issue from another directory
+func ValidateAttachments(paths []string) ([]string, error) {
+	result := make([]string, 0, len(paths))
+	for _, path := range paths {
+		info, err := os.Stat(path)
+		if err != nil {
+			return nil, fmt.Errorf("attachment %s: %w", path, err)
+		}
+		if !info.Mode().IsRegular() {
+			return nil, fmt.Errorf("attachment %s is not a regular file", path)
+		}
+		if info.Size() > MaxAttachmentSize {
+			return nil, fmt.Errorf("attachment %s is %d MB - the limit is %d MB", path, info.Size()>>20, MaxAttachmentSize>>20)
+		}
+
+		abs, err := filepath.Abs(path)
+		if err != nil {
+			return nil, fmt.Errorf("attachment %s: %w", path, err)
+		}
+		result = append(result, abs)
+	}
+	return result, nil
+}
@@ -0,0 +1,292 @@
+package utils
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"testing"
+)
+
+func TestLoadIssueTemplate(t *testing.T) {
+	repoDir := t.TempDir()
+	write := func(rel, content string) {
+		t.Helper()
+		path := filepath.Join(repoDir, rel)
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+	write(".github/ISSUE_TEMPLATE/security.md", "---\nname: Security\nlabels: security\n---\n\n## Impact\n")
+	write(".gitlab/issue_templates/bug.md", "## Steps to reproduce\n")
+	write("docs/issue.md", "Custom template\n")
+
+	tests := []struct {
+		name     string
+		template string
+		isGitLab bool
+		want     string
+		wantErr  bool
+	}{
+		{name: "github template with front matter", template: "security.md", want: "## Impact"},
+		{name: "gitlab template", template: "bug.md", isGitLab: true, want: "## Steps to reproduce"},
+		{name: "path relative to repo", template: "docs/issue.md", want: "Custom template"},
+		{name: "missing template", template: "nope.md", wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := LoadIssueTemplate(repoDir, tt.template, tt.isGitLab)
+			if tt.wantErr {
+				if err == nil {
+					t.Error("LoadIssueTemplate() error = nil, wantErr true")
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("LoadIssueTemplate() error = %v", err)
+			}
+			if got != tt.want {
+				t.Errorf("LoadIssueTemplate() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestListIssueTemplates(t *testing.T) {
+	repoDir := t.TempDir()
+	write := func(rel, content string) {
+		t.Helper()
+		path := filepath.Join(repoDir, rel)
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(path, []byte(content), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+	write(".github/ISSUE_TEMPLATE/security.md", "---\nname: Security\nabout: Report a vulnerability privately\n---\n\n## Impact\n")
+	write(".github/ISSUE_TEMPLATE/bug.md", "\n## Steps to reproduce\n\n1. ...\n")
+	write(".github/ISSUE_TEMPLATE/config.yml", "blank_issues_enabled: false\n")
+	write(".gitlab/issue_templates/feature.md", "---\nname: Feature\n---\n")
+
+	got, err := ListIssueTemplates(repoDir, false)
+	if err != nil {
+		t.Fatalf("ListIssueTemplates() error: %v", err)
+	}
+	want := []IssueTemplate{
+		{Name: "bug.md", Description: "Steps to reproduce"},
+		{Name: "security.md", Description: "Report a vulnerability privately"},
+	}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("ListIssueTemplates() = %+v, want %+v", got, want)
+	}
+
+	got, err = ListIssueTemplates(repoDir, true)
+	if err != nil {
+		t.Fatalf("ListIssueTemplates() for GitLab error: %v", err)
+	}
+	if want := []IssueTemplate{{Name: "feature.md", Description: "Feature"}}; !reflect.DeepEqual(got, want) {
+		t.Errorf("ListIssueTemplates() for GitLab = %+v, want %+v", got, want)
+	}
+
+	if got, err := ListIssueTemplates(t.TempDir(), false); err != nil || len(got) != 0 {
+		t.Errorf("ListIssueTemplates() without a template directory = %+v, %v, want none", got, err)
+	}
+}
+
+func TestParseIssueFile(t *testing.T) {
+	tests := []struct {
+		name    string
+		content string
+		want    IssueFile
+		wantErr bool
+	}{
+		{
+			name:    "heading becomes the title",
+			content: "# Add CSV export\n\nUsers want to export reports.\n",
+			want:    IssueFile{Title: "Add CSV export", Body: "Users want to export reports."},
+		},
+		{
+			name:    "front matter title and lists",
+			content: "---\ntitle: Fix login\nlabels: [bug, auth]\nassignees: alice, bob\n---\n# Details\n\nSteps to reproduce.\n",
+			want:    IssueFile{Title: "Fix login", Labels: []string{"bug", "auth"}, Assignees: []string{"alice", "bob"}, Body: "# Details\n\nSteps to reproduce."},
+		},
+		{
+			name:    "front matter without title uses the heading",
+			content: "---\nlabels: docs\n---\nIntro\n## Update README ##\nBody\n",
+			want:    IssueFile{Title: "Update README", Labels: []string{"docs"}, Body: "Intro\nBody"},
+		},
+		{
+			name:    "no title",
+			content: "Just some text\n",
+			wantErr: true,
+		},
+		{
+			name:    "invalid front matter",
+			content: "---\ntitle: [unclosed\n---\nbody\n",
+			wantErr: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ParseIssueFile(tt.content)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("ParseIssueFile() = %+v, want error", got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("ParseIssueFile() error: %v", err)
+			}
+			if !reflect.DeepEqual(*got, tt.want) {
+				t.Errorf("ParseIssueFile() = %+v, want %+v", *got, tt.want)
+			}
+		})
+	}
+}
+
+func TestParseBatchFile(t *testing.T) {
+	tests := []struct {
+		name    string
+		content string
+		want    []BatchIssue
+		wantErr bool
+	}{
+		{
+			name:    "one issue per line",
+			content: "# planning\nFix login | bug, auth | alice\n\nAdd CSV export\nUpdate docs | docs\n",
+			want: []BatchIssue{
+				{Title: "Fix login", Labels: []string{"bug", "auth"}, Assignees: []string{"alice"}},
+				{Title: "Add CSV export"},
+				{Title: "Update docs", Labels: []string{"docs"}},
+			},
+		},
+		{
+			name:    "yaml list",
+			content: "# planning\n- title: Fix login\n  labels: [bug, auth]\n  assignees: alice, bob\n  branch: true\n- title: Add CSV export\n  body: Users want reports.\n- Update docs\n",
+			want: []BatchIssue{
+				{Title: "Fix login", Labels: []string{"bug", "auth"}, Assignees: []string{"alice", "bob"}, Branch: true},
+				{Title: "Add CSV export", Body: "Users want reports."},
+				{Title: "Update docs"},
+			},
+		},
+		{
+			name:    "yaml issue without title",
+			content: "- labels: bug\n",
+			wantErr: true,
+		},
+		{
+			name:    "line without title",
+			content: "Fix login\n | bug\n",
+			wantErr: true,
+		},
+		{
+			name:    "no issues",
+			content: "# nothing yet\n\n",
+			wantErr: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := ParseBatchFile(tt.content)
+			if tt.wantErr {
+				if err == nil {
+					t.Errorf("ParseBatchFile() = %+v, want error", got)
+				}
+				return
+			}
+			if err != nil {
+				t.Fatalf("ParseBatchFile() error: %v", err)
+			}
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ParseBatchFile() = %+v, want %+v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestParseTemplateVars(t *testing.T) {
+	got, err := ParseTemplateVars([]string{"component=auth service", "query=a=b", " env =prod"})
+	if err != nil {
+		t.Fatalf("ParseTemplateVars() error: %v", err)
+	}
+	want := map[string]string{"component": "auth service", "query": "a=b", "env": "prod"}
+	if !reflect.DeepEqual(got, want) {
+		t.Errorf("ParseTemplateVars() = %v, want %v", got, want)
+	}
+
+	for _, bad := range []string{"severity", "=high", "bad name=x"} {
+		if _, err := ParseTemplateVars([]string{bad}); err == nil {
+			t.Errorf("ParseTemplateVars(%q) expected an error", bad)
+		}
+	}
+}
+
+func TestRenderTemplateVars(t *testing.T) {
+	body := "Component: {{component}}\nEnv: {{ env }}\nSeverity: {{severity}} ({{severity}})"
+	got, missing := RenderTemplateVars(body, map[string]string{"component": "auth", "env": "prod"})
+	want := "Component: auth\nEnv: prod\nSeverity: {{severity}} ({{severity}})"
+	if got != want {
+		t.Errorf("RenderTemplateVars() = %q, want %q", got, want)
+	}
+	if !reflect.DeepEqual(missing, []string{"severity"}) {
+		t.Errorf("RenderTemplateVars() missing = %v, want [severity]", missing)
+	}
+}
+
+func TestStripFrontMatter(t *testing.T) {
+	tests := []struct {
+		name  string
+		input string
+		want  string
+	}{
+		{name: "no front matter", input: "body\n", want: "body\n"},
+		{name: "front matter", input: "---\nname: x\n---\nbody\n", want: "body\n"},
+		{name: "unterminated", input: "---\nname: x\n", want: "---\nname: x\n"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := stripFrontMatter(tt.input); got != tt.want {
+				t.Errorf("stripFrontMatter() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestValidateAttachments(t *testing.T) {
+	dir := t.TempDir()
+	screenshot := filepath.Join(dir, "screenshot.png")
+	if err := os.WriteFile(screenshot, []byte("png"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	large := filepath.Join(dir, "huge.log")
+	f, err := os.Create(large)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := f.Truncate(MaxAttachmentSize + 1); err != nil {
+		t.Fatal(err)
+	}
+	f.Close()
+
+	got, err := ValidateAttachments([]string{screenshot})
+	if err != nil {
+		t.Fatalf("ValidateAttachments() error: %v", err)
+	}
+	if !reflect.DeepEqual(got, []string{screenshot}) {
+		t.Errorf("ValidateAttachments() = %v, want %v", got, []string{screenshot})
+	}
+
+	for _, path := range []string{filepath.Join(dir, "missing.txt"), dir, large} {
+		if _, err := ValidateAttachments([]string{screenshot, path}); err == nil {
+			t.Errorf("ValidateAttachments(%s) expected error", path)
+		}
+	}
+}
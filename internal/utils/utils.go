@@ -6,8 +6,102 @@ import (
 	"strings"
 	"time"
 	"unicode"
+	"unicode/utf8"
+
+	"github.com/tedkulp/tix/internal/config"
+	"github.com/tedkulp/tix/internal/git"
+	"golang.org/x/text/runes"
+	"golang.org/x/text/transform"
+	"golang.org/x/text/unicode/norm"
+)
+
+// asciiFallbacks are transliterations for letters that don't decompose into
+// an ASCII letter plus combining marks
+var asciiFallbacks = strings.NewReplacer(
+	"ß", "ss", "æ", "ae", "Æ", "AE", "œ", "oe", "Œ", "OE", "ø", "o", "Ø", "O",
+	"ł", "l", "Ł", "L", "đ", "d", "Đ", "D", "ð", "d", "Ð", "D", "þ", "th", "Þ", "TH",
+	"ı", "i",
 )
 
+// Transliterate rewrites s in ASCII for slugs: accents are stripped
+// ("naïve café" -> "naive cafe"), a few letters are spelled out ("ß" -> "ss"),
+// and anything else outside ASCII, such as emoji or CJK, is dropped.
+func Transliterate(s string) string {
+	stripMarks := transform.Chain(norm.NFD, runes.Remove(runes.In(unicode.Mn)), norm.NFC)
+	result, _, err := transform.String(stripMarks, asciiFallbacks.Replace(s))
+	if err != nil {
+		result = s
+	}
+	return strings.Map(func(r rune) rune {
+		if r > unicode.MaxASCII {
+			return ' '
+		}
+		return r
+	}, result)
+}
+
+// SanitizeTitle makes a pasted multi-line title a single line: each line
+// break, with the whitespace around it, becomes one space, and other control
+// characters are dropped. A single-line title is returned as it is.
+func SanitizeTitle(title string) string {
+	title = strings.Map(func(r rune) rune {
+		if unicode.IsControl(r) && r != '\t' && !isLineBreak(r) {
+			return -1
+		}
+		return r
+	}, title)
+	if !strings.ContainsFunc(title, isLineBreak) {
+		return title
+	}
+
+	var parts []string
+	for _, line := range strings.FieldsFunc(title, isLineBreak) {
+		if line = strings.TrimSpace(line); line != "" {
+			parts = append(parts, line)
+		}
+	}
+	return strings.Join(parts, " ")
+}
+
+func isLineBreak(r rune) bool {
+	switch r {
+	case '\n', '\r', '\v', '\f', '\u0085', '\u2028', '\u2029':
+		return true
+	}
+	return false
+}
+
+// commitScissors is the line below which git drops the rest of a commit
+// message, e.g. the diff shown by git commit --verbose
+const commitScissors = "# ------------------------ >8 ------------------------"
+
+// AppendIssueRef adds ref (e.g. "Refs #12") as the last paragraph of a commit
+// message, above git's trailing comment lines and any scissors line. A
+// message that already mentions ref, or that is empty, is returned unchanged.
+func AppendIssueRef(message, ref string) string {
+	lines := strings.Split(message, "\n")
+	cut := len(lines)
+	for i, line := range lines {
+		if strings.HasPrefix(line, commitScissors) {
+			cut = i
+			break
+		}
+	}
+	end := cut
+	for end > 0 && (strings.TrimSpace(lines[end-1]) == "" || strings.HasPrefix(lines[end-1], "#")) {
+		end--
+	}
+	if end == 0 || strings.Contains(strings.Join(lines[:end], "\n"), ref) {
+		return message
+	}
+
+	result := append(append([]string{}, lines[:end]...), "", ref)
+	if end == len(lines) {
+		return strings.Join(result, "\n") + "\n"
+	}
+	return strings.Join(append(result, lines[end:]...), "\n")
+}
+
 // TruncateAndDashCase converts a string to dash-case and truncates it to the specified length
 // Keeps consecutive uppercase letters together (e.g., "IRSA" stays as "irsa", not "i-r-s-a")
 func TruncateAndDashCase(s string, maxLen int) string {
@@ -71,20 +165,164 @@ func TruncateAndDashCase(s string, maxLen int) string {
 		resultStr = resultStr[:len(resultStr)-1]
 	}
 
-	if len(resultStr) > maxLen {
-		return resultStr[:maxLen]
-	}
+	return truncateSlug(resultStr, maxLen)
+}
 
-	return resultStr
+// truncateSlug shortens a dash-cased slug to at most maxLen bytes without
+// cutting a character in half or leaving a trailing dash
+func truncateSlug(slug string, maxLen int) string {
+	if len(slug) <= maxLen {
+		return slug
+	}
+	if maxLen <= 0 {
+		return ""
+	}
+	end := maxLen
+	for end > 0 && !utf8.RuneStart(slug[end]) {
+		end--
+	}
+	return strings.TrimRight(slug[:end], "-")
 }
 
-// SplitOnCommaAndWhitespace splits a string on commas and trims whitespace
+// SplitOnCommaAndWhitespace splits a string on commas and trims whitespace,
+// dropping empty segments. GitHub and GitLab labels are split the same way.
 func SplitOnCommaAndWhitespace(s string) []string {
-	parts := strings.Split(s, ",")
-	for i, part := range parts {
-		parts[i] = strings.TrimSpace(part)
+	return config.SplitLabels(s)
+}
+
+// MergeLabels appends extra labels to a comma-separated label string and
+// returns the normalized, comma-separated result
+func MergeLabels(labels string, extra ...string) string {
+	return strings.Join(config.NormalizeLabels(append(SplitOnCommaAndWhitespace(labels), extra...)), ",")
+}
+
+// RemoveLabels drops the removed labels, matched case-insensitively, from a
+// comma-separated label string and returns the normalized result
+func RemoveLabels(labels string, removed ...string) string {
+	drop := make(map[string]bool)
+	for _, label := range config.NormalizeLabels(removed) {
+		drop[strings.ToLower(label)] = true
+	}
+	var kept []string
+	for _, label := range SplitOnCommaAndWhitespace(labels) {
+		if !drop[strings.ToLower(label)] {
+			kept = append(kept, label)
+		}
+	}
+	return strings.Join(config.NormalizeLabels(kept), ",")
+}
+
+// SuggestLabels returns the labels mapped to keywords that appear as words in
+// title, matched case-insensitively, in the order they first appear.
+// keywords must have lowercase keys, as from Settings.ResolveKeywordLabels.
+func SuggestLabels(title string, keywords map[string]string) []string {
+	words := strings.FieldsFunc(strings.ToLower(title), func(r rune) bool {
+		return !unicode.IsLetter(r) && !unicode.IsDigit(r)
+	})
+
+	var labels []string
+	for _, word := range words {
+		if label, ok := keywords[word]; ok {
+			labels = append(labels, label)
+		}
+	}
+	return config.NormalizeLabels(labels)
+}
+
+// BuildBranchName builds the branch name for an issue with the default template.
+// The format is [type/][project-]123-dash-cased-title, where the type
+// (e.g. feature, bug) and project prefix are optional. The issue ID is
+// normally the issue number, or a placeholder for issues not yet filed.
+func BuildBranchName(branchType, projectPrefix, issueID, issueTitle string) string {
+	return RenderBranchName(config.DefaultBranchTemplate, BranchNameVars{
+		Type:   branchType,
+		Prefix: projectPrefix,
+		ID:     issueID,
+		Title:  issueTitle,
+	})
+}
+
+// BranchNameVars holds the values substituted into a branch template
+type BranchNameVars struct {
+	Type   string // {type}, e.g. "feature"
+	Prefix string // {prefix}, the issue repo name for cross-repo issues
+	ID     string // {id}
+	Title  string // Issue title; {slug} is its dash-cased form
+	User   string // {user}, already sanitized with SanitizeBranchUser
+	Suffix string // Appended after a dash, e.g. "spike" for 123-slug-spike
+
+	MaxLength int // Longest name to build, in bytes; the slug is shortened to fit. 0 means no limit.
+}
+
+// RenderBranchName fills in a branch template such as "{user}/{id}-{slug}".
+// Empty placeholders drop out together with the separators around them, so
+// "{type}/{prefix}-{id}-{slug}" without a type or prefix gives "123-slug".
+// A name longer than vars.MaxLength, suffix included, loses the end of its
+// slug; the other placeholders and the suffix are never shortened, so the
+// name can still be too long when they alone exceed the limit.
+func RenderBranchName(tmpl string, vars BranchNameVars) string {
+	slug := TruncateAndDashCase(vars.Title, 50)
+	name := renderBranchTemplate(tmpl, vars, slug)
+	if over := len(name) - vars.MaxLength; vars.MaxLength > 0 && over > 0 {
+		name = renderBranchTemplate(tmpl, vars, truncateSlug(slug, len(slug)-over))
+	}
+	return name
+}
+
+// renderBranchTemplate fills in tmpl with slug as {slug}, followed by the
+// suffix
+func renderBranchTemplate(tmpl string, vars BranchNameVars, slug string) string {
+	rendered := strings.NewReplacer(
+		"{type}", vars.Type,
+		"{prefix}", vars.Prefix,
+		"{id}", vars.ID,
+		"{slug}", slug,
+		"{user}", vars.User,
+	).Replace(tmpl)
+	if vars.Suffix != "" {
+		rendered += "-" + vars.Suffix
+	}
+
+	var segments []string
+	for _, segment := range strings.Split(rendered, "/") {
+		for strings.Contains(segment, "--") {
+			segment = strings.ReplaceAll(segment, "--", "-")
+		}
+		if segment = strings.Trim(segment, "-"); segment != "" {
+			segments = append(segments, segment)
+		}
+	}
+	return strings.Join(segments, "/")
+}
+
+// SanitizeBranchUser turns a git user name or email into a ref-safe branch
+// component: "Ted Kulp" -> "ted-kulp", "tk@example.com" -> "tk"
+func SanitizeBranchUser(user string) string {
+	if at := strings.Index(user, "@"); at >= 0 {
+		user = user[:at]
+	}
+	return TruncateAndDashCase(user, 30)
+}
+
+// ParseSince parses a --since value: a date (2006-01-02), an RFC 3339
+// timestamp, or a relative age such as "7d", "12h" or "30m" counted back from now
+func ParseSince(value string, now time.Time) (time.Time, error) {
+	value = strings.TrimSpace(value)
+	if t, err := time.ParseInLocation("2006-01-02", value, now.Location()); err == nil {
+		return t, nil
+	}
+	if t, err := time.Parse(time.RFC3339, value); err == nil {
+		return t, nil
+	}
+	if days, ok := strings.CutSuffix(value, "d"); ok {
+		if n, err := strconv.Atoi(days); err == nil && n >= 0 {
+			return now.AddDate(0, 0, -n), nil
+		}
+	}
+	if d, err := time.ParseDuration(value); err == nil && d >= 0 {
+		return now.Add(-d), nil
 	}
-	return parts
+	return time.Time{}, fmt.Errorf("invalid --since value %q - use a date (2006-01-02), an RFC 3339 time, or an age like 7d or 12h", value)
 }
 
 // GenerateMilestone creates a milestone string in the format YYYY.QN based on the provided time
@@ -108,27 +346,46 @@ func GenerateMilestone(t time.Time) string {
 	return fmt.Sprintf("%d.Q%d", year, quarter)
 }
 
+// PendingIssuePrefix starts the placeholder ID of branches created with
+// `tix create --offline`, whose issue is filed later by `tix sync`
+const PendingIssuePrefix = "tmp-"
+
+// PendingIssueID returns a placeholder issue ID such as tmp-20250102150405
+func PendingIssueID(t time.Time) string {
+	return PendingIssuePrefix + t.Format("20060102150405")
+}
+
 // ExtractIssueInfo extracts the project name (optional) and issue number from a branch name.
 // Branch names can be in the format:
 //   - 123-branch-name (same repo)
 //   - project-123-branch-name (cross-repo)
+//   - 123 or project-123 (branch_include_slug: false)
+//
+// A leading type component (e.g. feature/123-branch-name) is ignored.
 //
 // Returns: (projectName, issueNumber, error)
 // If projectName is empty, the issue is in the same repo as the branch.
 func ExtractIssueInfo(branchName string) (string, int, error) {
-	// Split the branch name by dash
-	parts := strings.Split(branchName, "-")
-	if len(parts) < 2 {
-		return "", 0, fmt.Errorf("invalid branch name format: %s", branchName)
+	// Strip a branch type prefix such as "feature/"
+	name := branchName
+	if idx := strings.LastIndex(name, "/"); idx >= 0 {
+		name = name[idx+1:]
+	}
+
+	if strings.HasPrefix(name, PendingIssuePrefix) {
+		return "", 0, fmt.Errorf("branch %s has no issue yet - run 'tix sync' to file it", branchName)
 	}
 
+	// Split the branch name by dash
+	parts := strings.Split(name, "-")
+
 	// Check if first part is numeric (same-repo format: 123-foo)
 	if issueNumber, err := strconv.Atoi(parts[0]); err == nil {
 		return "", issueNumber, nil
 	}
 
 	// Check if second part is numeric (cross-repo format: project-123-foo)
-	if len(parts) < 3 {
+	if len(parts) < 2 {
 		return "", 0, fmt.Errorf("invalid branch name format: %s", branchName)
 	}
 
@@ -140,6 +397,33 @@ func ExtractIssueInfo(branchName string) (string, int, error) {
 	return parts[0], issueNumber, nil
 }
 
+// BranchIssueConfigKey is the git config key that holds the issue number of
+// branch when its name carries another id (GitLab's instance-wide id, with
+// gitlab_branch_id: id)
+func BranchIssueConfigKey(branch string) string {
+	return "branch." + branch + ".tixIssue"
+}
+
+// BranchIssueInfo is ExtractIssueInfo for a branch of the git repository at
+// dir: the issue number recorded under BranchIssueConfigKey, when there is
+// one, replaces the id in the branch name.
+func BranchIssueInfo(dir, branch string) (string, int, error) {
+	projectName, issueNumber, err := ExtractIssueInfo(branch)
+	if err != nil {
+		return "", 0, err
+	}
+	gitRepo, err := git.Open(dir)
+	if err != nil {
+		return projectName, issueNumber, nil
+	}
+	if value, err := gitRepo.ConfigValue(BranchIssueConfigKey(branch)); err == nil && value != "" {
+		if number, err := strconv.Atoi(value); err == nil {
+			issueNumber = number
+		}
+	}
+	return projectName, issueNumber, nil
+}
+
 // ExtractIssueNumber extracts the issue number from a branch name.
 // Branch names are typically in the format 123-branch-name or project-123-branch-name.
 // This function is kept for backward compatibility and uses ExtractIssueInfo internally.
@@ -1,10 +1,13 @@
 package utils
 
 import (
+	"os/exec"
 	"reflect"
 	"strings"
 	"testing"
 	"time"
+
+	"github.com/tedkulp/tix/internal/config"
 )
 
 func TestTruncateAndDashCase(t *testing.T) {
@@ -104,6 +107,30 @@ func TestTruncateAndDashCase(t *testing.T) {
 	}
 }
 
+func TestTransliterateSlug(t *testing.T) {
+	tests := []struct {
+		input      string
+		stripped   string
+		translated string
+	}{
+		{input: "naïve café", stripped: "naïve-café", translated: "naive-cafe"},
+		{input: "Straße öffnen", stripped: "straße-öffnen", translated: "strasse-offnen"},
+		{input: "Fix 🐛 in Łódź export", stripped: "fix-in-łódź-export", translated: "fix-in-lodz-export"},
+		{input: "plain ascii", stripped: "plain-ascii", translated: "plain-ascii"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.input, func(t *testing.T) {
+			if got := TruncateAndDashCase(tt.input, 50); got != tt.stripped {
+				t.Errorf("TruncateAndDashCase(%q) = %q, want %q", tt.input, got, tt.stripped)
+			}
+			if got := TruncateAndDashCase(Transliterate(tt.input), 50); got != tt.translated {
+				t.Errorf("TruncateAndDashCase(Transliterate(%q)) = %q, want %q", tt.input, got, tt.translated)
+			}
+		})
+	}
+}
+
 func TestSplitOnCommaAndWhitespace(t *testing.T) {
 	tests := []struct {
 		name  string
@@ -128,17 +155,32 @@ func TestSplitOnCommaAndWhitespace(t *testing.T) {
 		{
 			name:  "empty parts",
 			input: "a,,c",
-			want:  []string{"a", "", "c"},
+			want:  []string{"a", "c"},
 		},
 		{
 			name:  "empty string",
 			input: "",
-			want:  []string{""},
+			want:  nil,
 		},
 		{
 			name:  "only whitespace",
 			input: "  ,  ,  ",
-			want:  []string{"", "", ""},
+			want:  nil,
+		},
+		{
+			name:  "leading and trailing commas",
+			input: ",a, b,",
+			want:  []string{"a", "b"},
+		},
+		{
+			name:  "spaces inside a label are kept",
+			input: "good first issue, help wanted",
+			want:  []string{"good first issue", "help wanted"},
+		},
+		{
+			name:  "tabs and newlines",
+			input: "bug\t,\n ui",
+			want:  []string{"bug", "ui"},
 		},
 	}
 
@@ -152,6 +194,225 @@ func TestSplitOnCommaAndWhitespace(t *testing.T) {
 	}
 }
 
+func TestSuggestLabels(t *testing.T) {
+	keywords := map[string]string{"bug": "bug", "perf": "performance", "crash": "bug", "docs": "documentation"}
+	tests := []struct {
+		title string
+		want  []string
+	}{
+		{title: "Perf: slow dashboard crash bug", want: []string{"performance", "bug"}},
+		{title: "Update docs/README", want: []string{"documentation"}},
+		{title: "Debugging output", want: []string{}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.title, func(t *testing.T) {
+			if got := SuggestLabels(tt.title, keywords); !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("SuggestLabels(%q) = %v, want %v", tt.title, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestMergeLabels(t *testing.T) {
+	tests := []struct {
+		name   string
+		labels string
+		extra  []string
+		want   string
+	}{
+		{name: "appends extra label", labels: "bug,backend", extra: []string{"enhancement"}, want: "bug,backend,enhancement"},
+		{name: "skips duplicate", labels: "bug, backend", extra: []string{"bug"}, want: "bug,backend"},
+		{name: "empty base", labels: "", extra: []string{"bug"}, want: "bug"},
+		{name: "empty extra", labels: "bug", extra: []string{""}, want: "bug"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := MergeLabels(tt.labels, tt.extra...)
+			if got != tt.want {
+				t.Errorf("MergeLabels() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestSanitizeTitle(t *testing.T) {
+	tests := []struct {
+		name  string
+		input string
+		want  string
+	}{
+		{name: "single line unchanged", input: "Fix  login\ttimeout ", want: "Fix  login\ttimeout "},
+		{name: "pasted lines", input: "Fix login timeout\n  on slow networks\n", want: "Fix login timeout on slow networks"},
+		{name: "windows line endings", input: "Fix login\r\n\r\ntimeout", want: "Fix login timeout"},
+		{name: "leading line break", input: "\nFix login", want: "Fix login"},
+		{name: "control characters", input: "Fix\x00 login\x1b", want: "Fix login"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := SanitizeTitle(tt.input); got != tt.want {
+				t.Errorf("SanitizeTitle(%q) = %q, want %q", tt.input, got, tt.want)
+			}
+		})
+	}
+
+	// The slug of a multi-line title is the slug of the single-line one
+	if got := TruncateAndDashCase(SanitizeTitle("Fix login\n\ntimeout"), 50); got != "fix-login-timeout" {
+		t.Errorf("slug = %q, want fix-login-timeout", got)
+	}
+}
+
+func TestAppendIssueRef(t *testing.T) {
+	tests := []struct {
+		name    string
+		message string
+		want    string
+	}{
+		{name: "plain message", message: "Fix login\n", want: "Fix login\n\nRefs #12\n"},
+		{name: "no trailing newline", message: "Fix login", want: "Fix login\n\nRefs #12\n"},
+		{name: "above comments", message: "Fix login\n\n# Please enter the commit message\n#\n", want: "Fix login\n\nRefs #12\n\n# Please enter the commit message\n#\n"},
+		{name: "above scissors", message: "Fix login\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n", want: "Fix login\n\nRefs #12\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n"},
+		{name: "already referenced", message: "Fix login\n\nRefs #12\n", want: "Fix login\n\nRefs #12\n"},
+		{name: "empty message", message: "\n# Please enter the commit message\n", want: "\n# Please enter the commit message\n"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := AppendIssueRef(tt.message, "Refs #12"); got != tt.want {
+				t.Errorf("AppendIssueRef(%q) = %q, want %q", tt.message, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestRemoveLabels(t *testing.T) {
+	tests := []struct {
+		name    string
+		labels  string
+		removed []string
+		want    string
+	}{
+		{name: "removes label", labels: "bug,needs-triage,backend", removed: []string{"needs-triage"}, want: "bug,backend"},
+		{name: "case-insensitive", labels: "bug, Needs-Triage", removed: []string{"needs-triage"}, want: "bug"},
+		{name: "label not present", labels: "bug", removed: []string{"wontfix"}, want: "bug"},
+		{name: "nothing removed", labels: "bug,backend", want: "bug,backend"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := RemoveLabels(tt.labels, tt.removed...)
+			if got != tt.want {
+				t.Errorf("RemoveLabels() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestBuildBranchName(t *testing.T) {
+	tests := []struct {
+		name          string
+		branchType    string
+		projectPrefix string
+		issueID       string
+		issueTitle    string
+		want          string
+	}{
+		{name: "same repo", issueID: "42", issueTitle: "Fix login bug", want: "42-fix-login-bug"},
+		{name: "cross repo", projectPrefix: "issues", issueID: "42", issueTitle: "Fix login bug", want: "issues-42-fix-login-bug"},
+		{name: "with type", branchType: "bug", issueID: "42", issueTitle: "Fix login bug", want: "bug/42-fix-login-bug"},
+		{name: "with type and prefix", branchType: "feature", projectPrefix: "issues", issueID: "7", issueTitle: "Add export", want: "feature/issues-7-add-export"},
+		{name: "pending placeholder", issueID: "tmp-20250102150405", issueTitle: "Add export", want: "tmp-20250102150405-add-export"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := BuildBranchName(tt.branchType, tt.projectPrefix, tt.issueID, tt.issueTitle)
+			if got != tt.want {
+				t.Errorf("BuildBranchName() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestRenderBranchName(t *testing.T) {
+	tests := []struct {
+		name string
+		tmpl string
+		vars BranchNameVars
+		want string
+	}{
+		{name: "default without type or prefix", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{ID: "42", Title: "Fix login"}, want: "42-fix-login"},
+		{name: "user prefix", tmpl: "{user}/{id}-{slug}", vars: BranchNameVars{ID: "123", Title: "Add export", User: "tk"}, want: "tk/123-add-export"},
+		{name: "user with type", tmpl: "{user}/{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{Type: "bug", Prefix: "issues", ID: "7", Title: "Crash", User: "tk"}, want: "tk/bug/issues-7-crash"},
+		{name: "missing user drops out", tmpl: "{user}/{id}-{slug}", vars: BranchNameVars{ID: "7", Title: "Crash"}, want: "7-crash"},
+		{name: "issue number only", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{Type: "feature", ID: "123"}, want: "feature/123"},
+		{name: "slug shortened to fit a long prefix", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{Type: "feature", Prefix: "platform-infrastructure", ID: "12345", Title: "Add export of billing reports to CSV", MaxLength: 50}, want: "feature/platform-infrastructure-12345-add-export-o"},
+		{name: "slug within the limit", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{ID: "42", Title: "Fix login", MaxLength: 50}, want: "42-fix-login"},
+		{name: "slug cut between characters", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{ID: "42", Title: "Café crème brûlée", MaxLength: 12}, want: "42-café-cr"},
+		{name: "suffix counted in the limit", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{ID: "42", Title: "Fix login redirect loop", Suffix: "spike", MaxLength: 20}, want: "42-fix-login-r-spike"},
+		{name: "suffix without a slug", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{ID: "42", Suffix: "spike"}, want: "42-spike"},
+		{name: "id never shortened", tmpl: "{type}/{prefix}-{id}-{slug}", vars: BranchNameVars{Type: "feature", ID: "tmp-20250102150405", Title: "Fix login", MaxLength: 20}, want: "feature/tmp-20250102150405"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := RenderBranchName(tt.tmpl, tt.vars); got != tt.want {
+				t.Errorf("RenderBranchName(%q) = %q, want %q", tt.tmpl, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestSanitizeBranchUser(t *testing.T) {
+	tests := map[string]string{
+		"tk@example.com":         "tk",
+		"Ted Kulp":               "ted-kulp",
+		"first.last@example.com": "first-last",
+		"":                       "",
+	}
+	for input, want := range tests {
+		if got := SanitizeBranchUser(input); got != want {
+			t.Errorf("SanitizeBranchUser(%q) = %q, want %q", input, got, want)
+		}
+	}
+}
+
+func TestParseSince(t *testing.T) {
+	now := time.Date(2025, time.March, 10, 12, 0, 0, 0, time.UTC)
+	tests := map[string]time.Time{
+		"2025-03-01":           time.Date(2025, time.March, 1, 0, 0, 0, 0, time.UTC),
+		"2025-03-01T08:30:00Z": time.Date(2025, time.March, 1, 8, 30, 0, 0, time.UTC),
+		"7d":                   now.AddDate(0, 0, -7),
+		"12h":                  now.Add(-12 * time.Hour),
+		" 30m ":                now.Add(-30 * time.Minute),
+	}
+	for input, want := range tests {
+		got, err := ParseSince(input, now)
+		if err != nil {
+			t.Errorf("ParseSince(%q) unexpected error: %v", input, err)
+			continue
+		}
+		if !got.Equal(want) {
+			t.Errorf("ParseSince(%q) = %v, want %v", input, got, want)
+		}
+	}
+
+	for _, input := range []string{"", "yesterday", "-3d", "3w"} {
+		if _, err := ParseSince(input, now); err == nil {
+			t.Errorf("ParseSince(%q) expected an error", input)
+		}
+	}
+}
+
+func TestPendingIssueID(t *testing.T) {
+	got := PendingIssueID(time.Date(2025, time.January, 2, 15, 4, 5, 0, time.UTC))
+	if got != "tmp-20250102150405" {
+		t.Errorf("PendingIssueID() = %q, want %q", got, "tmp-20250102150405")
+	}
+}
+
 func TestGenerateMilestone(t *testing.T) {
 	tests := []struct {
 		name string
@@ -240,10 +501,34 @@ func TestExtractIssueNumber(t *testing.T) {
 			errContains: "invalid branch name format",
 		},
 		{
-			name:        "branch name with only issue number",
-			branchName:  "123",
+			name:       "branch name with type prefix",
+			branchName: "feature/123-add-feature",
+			wantNumber: 123,
+			wantErr:    false,
+		},
+		{
+			name:        "pending offline branch",
+			branchName:  "tmp-20250102150405-add-feature",
 			wantErr:     true,
-			errContains: "invalid branch name format",
+			errContains: "tix sync",
+		},
+		{
+			name:       "branch name with only issue number",
+			branchName: "123",
+			wantNumber: 123,
+			wantErr:    false,
+		},
+		{
+			name:       "issue number only with type prefix",
+			branchName: "feature/123",
+			wantNumber: 123,
+			wantErr:    false,
+		},
+		{
+			name:       "cross-repo issue number only",
+			branchName: "backend-45",
+			wantNumber: 45,
+			wantErr:    false,
 		},
 	}
 
@@ -269,3 +554,109 @@ func TestExtractIssueNumber(t *testing.T) {
 		})
 	}
 }
+
+func TestResolveRemoteSlugs(t *testing.T) {
+	dir := t.TempDir()
+	for _, args := range [][]string{
+		{"init"},
+		{"remote", "add", "upstream", "git@gitlab.com:acme/platform/app.git"},
+	} {
+		gitCmd := exec.Command("git", args...)
+		gitCmd.Dir = dir
+		if out, err := gitCmd.CombinedOutput(); err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, out)
+		}
+	}
+
+	cfg := &config.Settings{Repositories: []config.Repository{
+		{Name: "app", Directory: dir, GitlabRepo: "@remote:upstream"},
+		{Name: "docs", Directory: dir, GithubRepo: "acme/docs"},
+		{Name: "missing", Directory: dir, GithubRepo: "@remote:origin"},
+	}}
+	resolveRemoteSlugs(cfg)
+
+	want := []string{"acme/platform/app", "acme/docs", "@remote:origin"}
+	for i, repo := range cfg.Repositories {
+		slug := repo.GithubRepo + repo.GitlabRepo
+		if slug != want[i] {
+			t.Errorf("%s slug = %q, want %q", repo.Name, slug, want[i])
+		}
+	}
+}
+
+func TestApplyProviderChoice(t *testing.T) {
+	t.Cleanup(func() { SetProvider("") })
+	newConfig := func() *config.Settings {
+		return &config.Settings{PrimaryProvider: "gitlab", Repositories: []config.Repository{
+			{Name: "app", GithubRepo: "acme/app", GitlabRepo: "acme/app"},
+			{Name: "docs", GithubRepo: "acme/docs", GitlabRepo: "acme/docs", PrimaryProvider: "GitHub"},
+			{Name: "web", GithubRepo: "acme/web"},
+		}}
+	}
+
+	cfg := newConfig()
+	if err := applyProviderChoice(cfg); err != nil {
+		t.Fatalf("applyProviderChoice() error: %v", err)
+	}
+	want := []string{"gitlab", "github", "github"}
+	for i, repo := range cfg.Repositories {
+		if repo.IsMirrored() || repo.Provider() != want[i] {
+			t.Errorf("%s provider = %s (mirrored %v), want %s", repo.Name, repo.Provider(), repo.IsMirrored(), want[i])
+		}
+	}
+
+	// --provider wins over primary_provider but leaves single-provider repos alone
+	if err := SetProvider("github"); err != nil {
+		t.Fatalf("SetProvider() error: %v", err)
+	}
+	cfg = newConfig()
+	if err := applyProviderChoice(cfg); err != nil {
+		t.Fatalf("applyProviderChoice() error: %v", err)
+	}
+	for _, repo := range cfg.Repositories {
+		if repo.Provider() != "github" || repo.GithubRepo == "" {
+			t.Errorf("%s = %+v, want the GitHub slug kept", repo.Name, repo)
+		}
+	}
+	if err := SetProvider("bitbucket"); err == nil || !strings.Contains(err.Error(), "must be github or gitlab") {
+		t.Errorf("SetProvider(bitbucket) error = %v, want it rejected", err)
+	}
+
+	// Without either the repo stays mirrored and can't be used without a prompt
+	SetProvider("")
+	cfg = newConfig()
+	cfg.PrimaryProvider = ""
+	if err := applyProviderChoice(cfg); err != nil {
+		t.Fatalf("applyProviderChoice() error: %v", err)
+	}
+	app := &cfg.Repositories[0]
+	if !app.IsMirrored() {
+		t.Fatalf("app = %+v, want both slugs kept", app)
+	}
+	if err := ChooseProvider(app, false); err == nil || !strings.Contains(err.Error(), "pass --provider github|gitlab") {
+		t.Errorf("ChooseProvider() error = %v, want a hint about --provider", err)
+	}
+	if err := MatchProvider(app, &cfg.Repositories[2]); err != nil || app.Provider() != "github" || app.IsMirrored() {
+		t.Errorf("MatchProvider() = %v, app = %+v, want it narrowed to GitHub", err, app)
+	}
+}
+
+func TestChoosePairedProvider(t *testing.T) {
+	// A mirrored repo paired with a single-provider one needs no prompt
+	issues := &config.Repository{Name: "issues", GithubRepo: "acme/issues", GitlabRepo: "acme/issues"}
+	web := &config.Repository{Name: "web", GitlabRepo: "acme/web"}
+	if err := ChoosePairedProvider(issues, web, false); err != nil || issues.IsMirrored() || issues.Provider() != "gitlab" {
+		t.Errorf("ChoosePairedProvider(issues, web) = %v, issues = %+v, want it narrowed to GitLab", err, issues)
+	}
+	issues = &config.Repository{Name: "issues", GithubRepo: "acme/issues", GitlabRepo: "acme/issues"}
+	if err := ChoosePairedProvider(web, issues, false); err != nil || issues.IsMirrored() || issues.Provider() != "gitlab" {
+		t.Errorf("ChoosePairedProvider(web, issues) = %v, issues = %+v, want it narrowed to GitLab", err, issues)
+	}
+
+	// Only two mirrored repos leave a choice, which needs a prompt
+	issues = &config.Repository{Name: "issues", GithubRepo: "acme/issues", GitlabRepo: "acme/issues"}
+	app := &config.Repository{Name: "app", GithubRepo: "acme/app", GitlabRepo: "acme/app"}
+	if err := ChoosePairedProvider(app, issues, false); err == nil || !strings.Contains(err.Error(), "pass --provider") {
+		t.Errorf("ChoosePairedProvider() of two mirrored repos error = %v, want a hint about --provider", err)
+	}
+}
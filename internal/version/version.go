@@ -3,6 +3,7 @@ package version
 import (
 	"fmt"
 	"runtime"
+	"runtime/debug"
 )
 
 // Version information
@@ -30,3 +31,47 @@ func GetFullVersion() string {
 	return fmt.Sprintf("tix version %s (commit: %s, built: %s, %s/%s)",
 		Version, Commit, Date, runtime.GOOS, runtime.GOARCH)
 }
+
+// ClientLibraries are the modules tix talks to git, GitHub and GitLab with,
+// listed by tix version --verbose so bug reports show what a build uses
+var ClientLibraries = []string{
+	"github.com/go-git/go-git/v5",
+	"github.com/google/go-github/v62",
+	"gitlab.com/gitlab-org/api/client-go",
+}
+
+// Dependency is a module tix was built against
+type Dependency struct {
+	Path    string
+	Version string // "unknown" when the build has no module information
+}
+
+// ClientLibraryVersions returns the versions of ClientLibraries in this build
+func ClientLibraryVersions() []Dependency {
+	info, _ := debug.ReadBuildInfo()
+	return moduleVersions(info, ClientLibraries)
+}
+
+// moduleVersions looks up paths in info's dependencies, following replace
+// directives to the module actually built
+func moduleVersions(info *debug.BuildInfo, paths []string) []Dependency {
+	versions := map[string]string{}
+	if info != nil {
+		for _, dep := range info.Deps {
+			if dep.Replace != nil {
+				dep = dep.Replace
+			}
+			versions[dep.Path] = dep.Version
+		}
+	}
+
+	deps := make([]Dependency, 0, len(paths))
+	for _, path := range paths {
+		version, ok := versions[path]
+		if !ok {
+			version = "unknown"
+		}
+		deps = append(deps, Dependency{Path: path, Version: version})
+	}
+	return deps
+}
@@ -0,0 +1,27 @@
+package version
+
+import (
+	"reflect"
+	"runtime/debug"
+	"testing"
+)
+
+func TestModuleVersions(t *testing.T) {
+	info := &debug.BuildInfo{Deps: []*debug.Module{
+		{Path: "github.com/google/go-github/v62", Version: "v62.0.0"},
+		{Path: "github.com/xanzy/go-gitlab", Version: "v0.100.0", Replace: &debug.Module{Path: "gitlab.com/gitlab-org/api/client-go", Version: "v0.115.0"}},
+	}}
+	paths := []string{"github.com/google/go-github/v62", "gitlab.com/gitlab-org/api/client-go", "github.com/go-git/go-git/v5"}
+
+	want := []Dependency{
+		{Path: "github.com/google/go-github/v62", Version: "v62.0.0"},
+		{Path: "gitlab.com/gitlab-org/api/client-go", Version: "v0.115.0"},
+		{Path: "github.com/go-git/go-git/v5", Version: "unknown"},
+	}
+	if got := moduleVersions(info, paths); !reflect.DeepEqual(got, want) {
+		t.Errorf("moduleVersions() = %+v, want %+v", got, want)
+	}
+	if got := moduleVersions(nil, paths[:1]); got[0].Version != "unknown" {
+		t.Errorf("moduleVersions(nil) = %+v, want unknown", got)
+	}
+}